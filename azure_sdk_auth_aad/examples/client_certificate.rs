@@ -0,0 +1,30 @@
+use azure_sdk_auth_aad::*;
+use oauth2::ClientId;
+use std::env;
+use std::error::Error;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client_id =
+        ClientId::new(env::var("CLIENT_ID").expect("Missing CLIENT_ID environment variable."));
+    let tenant_id = env::var("TENANT_ID").expect("Missing TENANT_ID environment variable.");
+    let pem_file = env::args()
+        .nth(1)
+        .expect("please specify the PEM file with the private key and the certificate");
+
+    let pem = std::fs::read(pem_file)?;
+    let credential = ClientCertificateCredential::new(client_id, &tenant_id, &pem, &pem)?;
+
+    let client = Arc::new(reqwest::Client::new());
+    let token = credential
+        .authorize(client.clone(), "https://management.azure.com/")
+        .await?;
+    println!("v1 certificate authorization == {:?}", token);
+
+    let token = credential
+        .authorize_v2(client, "https://management.azure.com/.default")
+        .await?;
+    println!("v2 certificate authorization == {:?}", token);
+    Ok(())
+}
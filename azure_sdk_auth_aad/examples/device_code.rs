@@ -0,0 +1,25 @@
+use azure_sdk_auth_aad::*;
+use oauth2::ClientId;
+use std::env;
+use std::error::Error;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client_id =
+        ClientId::new(env::var("CLIENT_ID").expect("Missing CLIENT_ID environment variable."));
+    let tenant_id = env::var("TENANT_ID").expect("Missing TENANT_ID environment variable.");
+
+    // The application registration must allow public client flows.
+    let client = Arc::new(reqwest::Client::new());
+    let token = authorize_device_code(
+        client,
+        &client_id,
+        "https://management.azure.com/",
+        &tenant_id,
+        |device_code| println!("{}", device_code.message),
+    )
+    .await?;
+    println!("Device code authorization == {:?}", token);
+    Ok(())
+}
@@ -1,11 +1,10 @@
 use azure_sdk_auth_aad::*;
 use oauth2::{ClientId, ClientSecret, TokenResponse};
 use std::env;
-use std::error::Error;
 use url::Url;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
+async fn main() -> Result<(), failure::Error> {
     let client_id =
         ClientId::new(env::var("CLIENT_ID").expect("Missing CLIENT_ID environment variable."));
     let client_secret = ClientSecret::new(
@@ -15,22 +14,25 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let subscription_id =
         env::var("SUBSCRIPTION_ID").expect("Missing SUBSCRIPTION_ID environment variable.");
 
+    // Start a loopback server to receive the redirect
+    // with the code. It listens on a random port so
+    // its URL must be known before creating the
+    // authorization URL.
+    let server = RedirectServer::bind().await?;
+
     // Create URL to browse for initial authorization
     let c = authorize_delegate(
         client_id,
         Some(client_secret),
         &tenant_id,
-        Url::parse("http://localhost:3003/redirect").unwrap(),
+        server.redirect_url().clone(),
         "https://management.azure.com/",
     );
 
     println!("c == {:?}", c);
     println!("\nbrowse this url:\n{}", c.authorize_url);
 
-    // Start a naive server to receive the redirect
-    // with the token. This naive server is blocking
-    // so you should use something better.
-    let code = naive_server(&c, 3003).unwrap();
+    let code = server.receive(&c.csrf_state).await.unwrap();
 
     println!("code received: {:?}", code);
 
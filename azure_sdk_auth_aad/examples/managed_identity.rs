@@ -0,0 +1,27 @@
+use azure_sdk_auth_aad::*;
+use std::error::Error;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // Run this on an Azure VM, AKS pod, App Service or Function with a
+    // managed identity assigned. Pass a client id to pick a user-assigned
+    // identity, otherwise the system-assigned one is used.
+    let identity = match std::env::args().nth(1) {
+        Some(client_id) => ManagedIdentity::ClientId(client_id),
+        None => ManagedIdentity::SystemAssigned,
+    };
+
+    let credential = ManagedIdentityCredential::new(Arc::new(reqwest::Client::new()), identity)?;
+    let token = credential
+        .get_token("https://management.azure.com/")
+        .await?;
+    println!("Managed identity token expires on {}", token.expires_on);
+
+    // The second call is served from the cache.
+    let token = credential
+        .get_token("https://management.azure.com/")
+        .await?;
+    println!("Cached token expires on {}", token.expires_on);
+    Ok(())
+}
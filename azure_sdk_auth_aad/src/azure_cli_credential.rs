@@ -0,0 +1,170 @@
+use crate::token_credential::{scopes_to_resource, CredentialToken, TokenCredential, TokenFuture};
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use oauth2::AccessToken;
+use std::env;
+use std::path::{Path, PathBuf};
+
+// The Azure CLI writes the expiry in local time.
+const EXPIRES_ON_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenCacheEntry {
+    resource: String,
+    access_token: String,
+    expires_on: String,
+}
+
+/// Reuses the tokens the Azure CLI keeps in its token cache
+/// (`accessTokens.json` in `AZURE_CONFIG_DIR`, `~/.azure` by default), so
+/// that developers logged in with `az login` need no other configuration.
+/// Expired tokens are not refreshed: run any `az` command to renew them.
+#[derive(Debug, Clone)]
+pub struct AzureCliCredential {
+    token_cache: PathBuf,
+}
+
+impl Default for AzureCliCredential {
+    fn default() -> Self {
+        let config_dir = match env::var_os("AZURE_CONFIG_DIR") {
+            Some(config_dir) => PathBuf::from(config_dir),
+            None => env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(".azure"),
+        };
+
+        Self::with_token_cache(config_dir.join("accessTokens.json"))
+    }
+}
+
+impl AzureCliCredential {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_token_cache<P: Into<PathBuf>>(token_cache: P) -> Self {
+        Self {
+            token_cache: token_cache.into(),
+        }
+    }
+
+    pub fn token_cache(&self) -> &Path {
+        &self.token_cache
+    }
+
+    fn find_token(&self, resource: &str) -> Result<CredentialToken, AzureError> {
+        let token_cache = std::fs::read_to_string(&self.token_cache).map_err(|e| {
+            AzureError::GenericErrorWithText(format!(
+                "Cannot read the Azure CLI token cache {}: {}",
+                self.token_cache.display(),
+                e
+            ))
+        })?;
+        let entries: Vec<TokenCacheEntry> = serde_json::from_str(&token_cache)?;
+
+        let now = Utc::now();
+        entries
+            .into_iter()
+            .filter(|entry| entry.resource.trim_end_matches('/') == resource.trim_end_matches('/'))
+            .filter_map(|entry| {
+                let expires_on = parse_expires_on(&entry.expires_on)?;
+                Some(CredentialToken::new(
+                    AccessToken::new(entry.access_token),
+                    expires_on,
+                ))
+            })
+            .filter(|token| token.expires_on > now)
+            .max_by_key(|token| token.expires_on)
+            .ok_or_else(|| {
+                AzureError::GenericErrorWithText(format!(
+                    "No valid Azure CLI token for {} in {}, run az login",
+                    resource,
+                    self.token_cache.display()
+                ))
+            })
+    }
+}
+
+fn parse_expires_on(expires_on: &str) -> Option<DateTime<Utc>> {
+    let expires_on = NaiveDateTime::parse_from_str(expires_on, EXPIRES_ON_FORMAT).ok()?;
+    Local
+        .from_local_datetime(&expires_on)
+        .single()
+        .map(|expires_on| expires_on.with_timezone(&Utc))
+}
+
+impl TokenCredential for AzureCliCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move { self.find_token(scopes_to_resource(scopes)?) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn picks_the_latest_valid_token_for_the_resource() {
+        let expires_on = |hours| {
+            (Local::now() + chrono::Duration::hours(hours))
+                .format(EXPIRES_ON_FORMAT)
+                .to_string()
+        };
+        let token_cache = env::temp_dir().join(format!(
+            "azure_sdk_auth_aad_{}_accessTokens.json",
+            std::process::id()
+        ));
+        std::fs::write(
+            &token_cache,
+            serde_json::json!([
+                {
+                    "tokenType": "Bearer",
+                    "expiresOn": expires_on(-1),
+                    "resource": "https://management.core.windows.net/",
+                    "accessToken": "expired",
+                    "_clientId": "04b07795-8ddb-461a-bbee-02f9e1bf7b46"
+                },
+                {
+                    "tokenType": "Bearer",
+                    "expiresOn": expires_on(1),
+                    "resource": "https://management.core.windows.net/",
+                    "accessToken": "management",
+                    "_clientId": "04b07795-8ddb-461a-bbee-02f9e1bf7b46"
+                },
+                {
+                    "tokenType": "Bearer",
+                    "expiresOn": expires_on(2),
+                    "resource": "https://storage.azure.com",
+                    "accessToken": "storage",
+                    "_clientId": "04b07795-8ddb-461a-bbee-02f9e1bf7b46"
+                }
+            ])
+            .to_string(),
+        )
+        .unwrap();
+
+        let credential = AzureCliCredential::with_token_cache(&token_cache);
+        let token = credential
+            .get_token(&["https://management.core.windows.net/.default"])
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "management");
+        assert!(token.expires_on > Utc::now());
+
+        let token = credential
+            .get_token(&["https://storage.azure.com/.default"])
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "storage");
+
+        assert!(credential
+            .get_token(&["https://vault.azure.net/.default"])
+            .await
+            .is_err());
+
+        std::fs::remove_file(token_cache).unwrap();
+    }
+}
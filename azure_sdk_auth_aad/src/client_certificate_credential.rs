@@ -0,0 +1,298 @@
+use crate::token_request::{error_from_body, post_form};
+use crate::LoginResponse;
+use azure_sdk_core::errors::AzureError;
+use chrono::Utc;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use oauth2::ClientId;
+use ring::digest::{digest, SHA1_FOR_LEGACY_USE_ONLY};
+use std::sync::Arc;
+use url::form_urlencoded;
+use url::Url;
+
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+const CLIENT_ASSERTION_LIFETIME_SECONDS: i64 = 10 * 60;
+
+#[derive(Debug, Serialize)]
+struct ClientAssertionClaims<'a> {
+    aud: &'a str,
+    iss: &'a str,
+    sub: &'a str,
+    jti: String,
+    nbf: i64,
+    exp: i64,
+}
+
+/// Authenticates a service principal with a certificate instead of a
+/// secret: every token request carries a short lived RS256 assertion
+/// signed with the certificate private key.
+#[derive(Clone)]
+pub struct ClientCertificateCredential {
+    client_id: ClientId,
+    authority: Url,
+    encoding_key: EncodingKey,
+    thumbprint: String,
+}
+
+impl std::fmt::Debug for ClientCertificateCredential {
+    //! We provide a custom implementation to hide the private key.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ClientCertificateCredential")
+            .field("client_id", &self.client_id)
+            .field("authority", &self.authority)
+            .field("encoding_key", &"***hidden***")
+            .field("thumbprint", &self.thumbprint)
+            .finish()
+    }
+}
+
+impl ClientCertificateCredential {
+    /// `private_key` is a PEM encoded RSA key, either PKCS#1 or PKCS#8, and
+    /// `certificate` the PEM encoded certificate registered on the
+    /// application. Both can come from the same file.
+    pub fn new(
+        client_id: ClientId,
+        tenant_id: &str,
+        private_key: &[u8],
+        certificate: &[u8],
+    ) -> Result<Self, AzureError> {
+        let private_key = find_pem(private_key, &["PRIVATE KEY", "RSA PRIVATE KEY"])?;
+        let encoding_key = EncodingKey::from_rsa_pem(pem::encode(&private_key).as_bytes())
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+
+        // The x5t header is the base64url SHA-1 thumbprint of the DER
+        // certificate, the service uses it to pick the key to verify with.
+        let certificate = find_pem(certificate, &["CERTIFICATE"])?;
+        let thumbprint = base64::encode_config(
+            digest(&SHA1_FOR_LEGACY_USE_ONLY, &certificate.contents),
+            base64::URL_SAFE_NO_PAD,
+        );
+
+        let authority = Url::parse(&format!("https://login.microsoftonline.com/{}/", tenant_id))
+            .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+
+        Ok(Self {
+            client_id,
+            authority,
+            encoding_key,
+            thumbprint,
+        })
+    }
+
+    /// Uses another authority than the public cloud one, for example
+    /// `https://login.microsoftonline.us/<tenant_id>/`.
+    pub fn with_authority(self, authority: Url) -> Self {
+        Self { authority, ..self }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn thumbprint(&self) -> &str {
+        &self.thumbprint
+    }
+
+    /// Builds and signs a client assertion for the token endpoint
+    /// `audience`.
+    pub fn client_assertion(&self, audience: &str) -> Result<String, AzureError> {
+        let mut header = Header::new(Algorithm::RS256);
+        header.x5t = Some(self.thumbprint.clone());
+
+        let now = Utc::now().timestamp();
+        let claims = ClientAssertionClaims {
+            aud: audience,
+            iss: self.client_id.as_str(),
+            sub: self.client_id.as_str(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            nbf: now,
+            exp: now + CLIENT_ASSERTION_LIFETIME_SECONDS,
+        };
+
+        jsonwebtoken::encode(&header, &claims, &self.encoding_key)
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))
+    }
+
+    /// Requests a token for `resource` from the v1 endpoint.
+    pub async fn authorize(
+        &self,
+        client: Arc<reqwest::Client>,
+        resource: &str,
+    ) -> Result<LoginResponse, AzureError> {
+        self.request_token(client, "oauth2/token", ("resource", resource))
+            .await
+    }
+
+    /// Requests a token for `scope` (for example
+    /// `https://management.azure.com/.default`) from the v2 endpoint.
+    pub async fn authorize_v2(
+        &self,
+        client: Arc<reqwest::Client>,
+        scope: &str,
+    ) -> Result<LoginResponse, AzureError> {
+        self.request_token(client, "oauth2/v2.0/token", ("scope", scope))
+            .await
+    }
+
+    async fn request_token(
+        &self,
+        client: Arc<reqwest::Client>,
+        path: &str,
+        target: (&str, &str),
+    ) -> Result<LoginResponse, AzureError> {
+        let token_url = self
+            .authority
+            .join(path)
+            .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+
+        let encoded: String = form_urlencoded::Serializer::new(String::new())
+            .append_pair("grant_type", "client_credentials")
+            .append_pair("client_id", self.client_id.as_str())
+            .append_pair("client_assertion_type", CLIENT_ASSERTION_TYPE)
+            .append_pair(
+                "client_assertion",
+                &self.client_assertion(token_url.as_str())?,
+            )
+            .append_pair(target.0, target.1)
+            .finish();
+
+        let (status, body) = post_form(&client, token_url, encoded).await?;
+        if !status.is_success() {
+            return Err(error_from_body(status, &body));
+        }
+        body.parse()
+    }
+}
+
+fn find_pem(pem: &[u8], tags: &[&str]) -> Result<pem::Pem, AzureError> {
+    pem::parse_many(pem)
+        .into_iter()
+        .find(|pem| tags.contains(&pem.tag.as_str()))
+        .ok_or_else(|| {
+            AzureError::InputParametersError(format!("No {} found in the PEM file", tags[0]))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::fake_server;
+    use jsonwebtoken::{DecodingKey, Validation};
+
+    const PRIVATE_KEY: &[u8] = include_bytes!("../tests/data/client_certificate_key.pem");
+    const CERTIFICATE: &[u8] = include_bytes!("../tests/data/client_certificate.pem");
+    const PUBLIC_KEY: &[u8] = include_bytes!("../tests/data/client_certificate_public_key.pem");
+
+    #[derive(Debug, Deserialize)]
+    struct Claims {
+        aud: String,
+        iss: String,
+        sub: String,
+        jti: String,
+        nbf: i64,
+        exp: i64,
+    }
+
+    fn credential() -> ClientCertificateCredential {
+        ClientCertificateCredential::new(
+            ClientId::new("cid".to_owned()),
+            "tenant",
+            PRIVATE_KEY,
+            CERTIFICATE,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn client_assertion_is_signed_with_the_certificate() {
+        let credential = credential();
+        // openssl x509 -in client_certificate.pem -noout -fingerprint -sha1
+        assert_eq!(credential.thumbprint(), "zL_1DSDjUtTKQsFEMIEtlaQWV4Q");
+
+        let assertion = credential
+            .client_assertion("https://login.microsoftonline.com/tenant/oauth2/token")
+            .unwrap();
+
+        let header = jsonwebtoken::decode_header(&assertion).unwrap();
+        assert_eq!(header.alg, Algorithm::RS256);
+        assert_eq!(header.x5t.as_deref(), Some("zL_1DSDjUtTKQsFEMIEtlaQWV4Q"));
+
+        let claims = jsonwebtoken::decode::<Claims>(
+            &assertion,
+            &DecodingKey::from_rsa_pem(PUBLIC_KEY).unwrap(),
+            &Validation::new(Algorithm::RS256),
+        )
+        .unwrap()
+        .claims;
+        assert_eq!(
+            claims.aud,
+            "https://login.microsoftonline.com/tenant/oauth2/token"
+        );
+        assert_eq!(claims.iss, "cid");
+        assert_eq!(claims.sub, "cid");
+        assert!(!claims.jti.is_empty());
+        assert_eq!(claims.exp - claims.nbf, CLIENT_ASSERTION_LIFETIME_SECONDS);
+    }
+
+    #[test]
+    fn key_and_certificate_can_share_a_file() {
+        let mut combined = CERTIFICATE.to_vec();
+        combined.extend_from_slice(PRIVATE_KEY);
+
+        let credential = ClientCertificateCredential::new(
+            ClientId::new("cid".to_owned()),
+            "tenant",
+            &combined,
+            &combined,
+        )
+        .unwrap();
+        assert_eq!(credential.thumbprint(), "zL_1DSDjUtTKQsFEMIEtlaQWV4Q");
+
+        assert!(ClientCertificateCredential::new(
+            ClientId::new("cid".to_owned()),
+            "tenant",
+            CERTIFICATE,
+            CERTIFICATE,
+        )
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn exchanges_the_assertion_at_both_endpoints() {
+        let (url, handle) = fake_server(vec![
+            (
+                200,
+                r#"{"token_type":"Bearer","expires_in":"3599","ext_expires_in":"3599","expires_on":"1590000000","not_before":"1589996100","resource":"https://management.azure.com/","access_token":"token0"}"#
+                    .to_owned(),
+            ),
+            (
+                200,
+                r#"{"token_type":"Bearer","expires_in":3599,"ext_expires_in":3599,"access_token":"token1"}"#
+                    .to_owned(),
+            ),
+        ]);
+
+        let credential = credential().with_authority(url.join("tenant/").unwrap());
+        let client = Arc::new(reqwest::Client::new());
+
+        let token = credential
+            .authorize(client.clone(), "https://management.azure.com/")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token0");
+        assert_eq!(token.resource, "https://management.azure.com/");
+
+        let token = credential
+            .authorize_v2(client, "https://management.azure.com/.default")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token1");
+        assert_eq!(token.expires_in, 3599);
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with("POST /tenant/oauth2/token "));
+        assert!(requests[0].contains("grant_type=client_credentials&client_id=cid&client_assertion_type=urn%3Aietf%3Aparams%3Aoauth%3Aclient-assertion-type%3Ajwt-bearer&client_assertion="));
+        assert!(requests[0].ends_with("&resource=https%3A%2F%2Fmanagement.azure.com%2F"));
+        assert!(requests[1].starts_with("POST /tenant/oauth2/v2.0/token "));
+        assert!(requests[1].ends_with("&scope=https%3A%2F%2Fmanagement.azure.com%2F.default"));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::token_credential::{TokenCredential, TokenFuture};
+use crate::{
+    AzureCliCredential, EnvironmentCredential, ManagedIdentity, ManagedIdentityCredential,
+};
+use azure_sdk_core::errors::AzureError;
+use log::debug;
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Outside Azure the instance metadata endpoint does not answer at all, do
+// not wait for the operating system to give up on it.
+const MANAGED_IDENTITY_CONNECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Tries a list of credentials in order and returns the first token one of
+/// them gets. The default chain is:
+///
+/// 1. `EnvironmentCredential`, if `AZURE_TENANT_ID` and `AZURE_CLIENT_ID`
+///    are set;
+/// 2. `ManagedIdentityCredential`, user-assigned if `AZURE_CLIENT_ID` is
+///    set, system-assigned otherwise;
+/// 3. `AzureCliCredential`.
+///
+/// Wrap it in a `CachedTokenCredential` to avoid walking the chain on
+/// every request.
+#[derive(Debug)]
+pub struct DefaultCredential {
+    sources: Vec<Box<dyn TokenCredential>>,
+}
+
+impl DefaultCredential {
+    pub fn new(client: Arc<reqwest::Client>) -> Result<Self, AzureError> {
+        let mut sources: Vec<Box<dyn TokenCredential>> = Vec::new();
+
+        match EnvironmentCredential::new(client) {
+            Ok(credential) => sources.push(Box::new(credential)),
+            Err(error) => debug!("skipping environment credential: {}", error),
+        }
+
+        let managed_identity_client = reqwest::Client::builder()
+            .connect_timeout(MANAGED_IDENTITY_CONNECT_TIMEOUT)
+            .build()
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+        let identity = match env::var("AZURE_CLIENT_ID") {
+            Ok(client_id) => ManagedIdentity::ClientId(client_id),
+            Err(_) => ManagedIdentity::SystemAssigned,
+        };
+        sources.push(Box::new(ManagedIdentityCredential::new(
+            Arc::new(managed_identity_client),
+            identity,
+        )?));
+
+        sources.push(Box::new(AzureCliCredential::new()));
+
+        Ok(Self::with_sources(sources))
+    }
+
+    pub fn with_sources(sources: Vec<Box<dyn TokenCredential>>) -> Self {
+        Self { sources }
+    }
+}
+
+impl TokenCredential for DefaultCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let mut errors = Vec::new();
+            for source in &self.sources {
+                match source.get_token(scopes).await {
+                    Ok(token) => return Ok(token),
+                    Err(error) => {
+                        debug!("{:?} could not get a token: {}", source, error);
+                        errors.push(error.to_string());
+                    }
+                }
+            }
+
+            Err(AzureError::GenericErrorWithText(format!(
+                "No credential could get a token:\n{}",
+                errors.join("\n")
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CredentialToken;
+    use chrono::Utc;
+    use oauth2::AccessToken;
+
+    #[derive(Debug)]
+    struct StubCredential(Option<&'static str>);
+
+    impl TokenCredential for StubCredential {
+        fn get_token<'a>(&'a self, _scopes: &'a [&'a str]) -> TokenFuture<'a> {
+            Box::pin(async move {
+                match self.0 {
+                    Some(token) => Ok(CredentialToken::new(
+                        AccessToken::new(token.to_owned()),
+                        Utc::now(),
+                    )),
+                    None => Err(AzureError::GenericErrorWithText("stub failure".to_owned())),
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_the_first_token() {
+        let credential = DefaultCredential::with_sources(vec![
+            Box::new(StubCredential(None)),
+            Box::new(StubCredential(Some("token1"))),
+            Box::new(StubCredential(Some("token2"))),
+        ]);
+        let token = credential
+            .get_token(&["https://storage.azure.com/.default"])
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token1");
+
+        let credential = DefaultCredential::with_sources(vec![
+            Box::new(StubCredential(None)),
+            Box::new(StubCredential(None)),
+        ]);
+        let error = credential
+            .get_token(&["https://storage.azure.com/.default"])
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string().matches("stub failure").count(), 2);
+    }
+}
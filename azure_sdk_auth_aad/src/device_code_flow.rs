@@ -0,0 +1,220 @@
+use crate::string_or_number::StringOrNumber;
+use crate::token_request::{error_from_body, post_form};
+use crate::LoginResponse;
+use azure_sdk_core::errors::AzureError;
+use log::debug;
+use oauth2::ClientId;
+use serde::de::{self, Deserialize, Deserializer};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use url::form_urlencoded;
+use url::Url;
+
+// RFC 8628: the polling interval grows by 5 seconds on every `slow_down`.
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Deserialize)]
+struct _DeviceCodeResponse {
+    user_code: String,
+    device_code: String,
+    #[serde(alias = "verification_url")]
+    verification_uri: String,
+    expires_in: StringOrNumber,
+    interval: StringOrNumber,
+    message: String,
+}
+
+/// The device code issued to the user. Show them `message` (or
+/// `user_code` and `verification_uri`) so they can sign in from another
+/// device.
+#[derive(Debug, Clone)]
+pub struct DeviceCodeResponse {
+    pub user_code: String,
+    pub device_code: String,
+    pub verification_uri: String,
+    pub expires_in: u64,
+    pub interval: u64,
+    pub message: String,
+}
+
+impl<'de> Deserialize<'de> for DeviceCodeResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let r = _DeviceCodeResponse::deserialize(deserializer)?;
+
+        Ok(DeviceCodeResponse {
+            user_code: r.user_code,
+            device_code: r.device_code,
+            verification_uri: r.verification_uri,
+            expires_in: r.expires_in.parse().map_err(de::Error::custom)? as u64,
+            interval: r.interval.parse().map_err(de::Error::custom)? as u64,
+            message: r.message,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct DeviceCodeErrorResponse {
+    error: String,
+}
+
+/// Logs in with the device code flow, for machines without a browser: the
+/// user signs in on another device with the code passed to
+/// `on_device_code`, while this function polls the token endpoint until
+/// they do, decline, or the code expires.
+pub async fn authorize_device_code<F>(
+    client: Arc<reqwest::Client>,
+    client_id: &ClientId,
+    resource: &str,
+    tenant_id: &str,
+    on_device_code: F,
+) -> Result<LoginResponse, AzureError>
+where
+    F: FnOnce(&DeviceCodeResponse),
+{
+    let authority = Url::parse(&format!(
+        "https://login.microsoftonline.com/{}/oauth2/",
+        tenant_id
+    ))
+    .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+
+    device_code_flow(client, &authority, client_id, resource, on_device_code).await
+}
+
+async fn device_code_flow<F>(
+    client: Arc<reqwest::Client>,
+    authority: &Url,
+    client_id: &ClientId,
+    resource: &str,
+    on_device_code: F,
+) -> Result<LoginResponse, AzureError>
+where
+    F: FnOnce(&DeviceCodeResponse),
+{
+    let join = |path| {
+        authority
+            .join(path)
+            .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))
+    };
+
+    let encoded: String = form_urlencoded::Serializer::new(String::new())
+        .append_pair("client_id", client_id.as_str())
+        .append_pair("resource", resource)
+        .finish();
+
+    let (status, body) = post_form(&client, join("devicecode")?, encoded).await?;
+    if !status.is_success() {
+        return Err(error_from_body(status, &body));
+    }
+    let device_code: DeviceCodeResponse = serde_json::from_str(&body).map_err(|e| {
+        AzureError::GenericErrorWithText(format!("Failed to parse device code response: {}", e))
+    })?;
+
+    on_device_code(&device_code);
+
+    let token_url = join("token")?;
+    let encoded: String = form_urlencoded::Serializer::new(String::new())
+        .append_pair("grant_type", "device_code")
+        .append_pair("client_id", client_id.as_str())
+        .append_pair("resource", resource)
+        .append_pair("code", &device_code.device_code)
+        .finish();
+
+    let started = Instant::now();
+    let expires_in = Duration::from_secs(device_code.expires_in);
+    let mut interval = Duration::from_secs(device_code.interval);
+
+    loop {
+        if started.elapsed() + interval >= expires_in {
+            return Err(AzureError::GenericErrorWithText(
+                "The device code expired before the user signed in".to_owned(),
+            ));
+        }
+        tokio::time::delay_for(interval).await;
+
+        let (status, body) = post_form(&client, token_url.clone(), encoded.clone()).await?;
+        if status.is_success() {
+            return body.parse();
+        }
+
+        match serde_json::from_str::<DeviceCodeErrorResponse>(&body) {
+            Ok(error) if error.error == "authorization_pending" => {
+                debug!("waiting for the user to enter the device code");
+            }
+            Ok(error) if error.error == "slow_down" => {
+                interval += SLOW_DOWN_INCREMENT;
+                debug!("slowing down device code polling to {:?}", interval);
+            }
+            _ => return Err(error_from_body(status, &body)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::fake_server;
+
+    const DEVICE_CODE: &str = r#"{"user_code":"ABCD1234","device_code":"DC0","verification_url":"https://microsoft.com/devicelogin","expires_in":"900","interval":"0","message":"To sign in, use a web browser to open the page https://microsoft.com/devicelogin and enter the code ABCD1234 to authenticate."}"#;
+    const PENDING: &str = r#"{"error":"authorization_pending","error_description":"AADSTS70016: OAuth 2.0 device flow error. Authorization is pending."}"#;
+    const TOKEN: &str = r#"{"token_type":"Bearer","scope":"user_impersonation","expires_in":"3599","ext_expires_in":"3599","expires_on":"1590000000","not_before":"1589996100","resource":"https://management.azure.com/","access_token":"token0","refresh_token":"refresh0"}"#;
+
+    #[tokio::test]
+    async fn polls_until_the_user_signs_in() {
+        let (url, handle) = fake_server(vec![
+            (200, DEVICE_CODE.to_owned()),
+            (400, PENDING.to_owned()),
+            (400, PENDING.to_owned()),
+            (200, TOKEN.to_owned()),
+        ]);
+
+        let mut user_code = None;
+        let login_response = device_code_flow(
+            Arc::new(reqwest::Client::new()),
+            &url.join("tenant/oauth2/").unwrap(),
+            &ClientId::new("cid".to_owned()),
+            "https://management.azure.com/",
+            |device_code| user_code = Some(device_code.user_code.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(user_code.as_deref(), Some("ABCD1234"));
+        assert_eq!(login_response.access_token().secret(), "token0");
+
+        let requests = handle.join().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].starts_with("POST /tenant/oauth2/devicecode "));
+        assert!(requests[3].starts_with("POST /tenant/oauth2/token "));
+        assert!(requests[3].ends_with(
+            "grant_type=device_code&client_id=cid&resource=https%3A%2F%2Fmanagement.azure.com%2F&code=DC0"
+        ));
+    }
+
+    #[tokio::test]
+    async fn fails_when_the_user_declines() {
+        let (url, handle) = fake_server(vec![
+            (200, DEVICE_CODE.to_owned()),
+            (
+                400,
+                r#"{"error":"authorization_declined","error_description":"AADSTS70000: The user declined."}"#
+                    .to_owned(),
+            ),
+        ]);
+
+        let error = device_code_flow(
+            Arc::new(reqwest::Client::new()),
+            &url.join("tenant/oauth2/").unwrap(),
+            &ClientId::new("cid".to_owned()),
+            "https://management.azure.com/",
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+
+        assert!(error.to_string().contains("AADSTS70000"));
+        handle.join().unwrap();
+    }
+}
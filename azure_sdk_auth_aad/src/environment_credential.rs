@@ -0,0 +1,93 @@
+use crate::token_credential::{scopes_to_resource, TokenCredential, TokenFuture};
+use crate::{authorize_non_interactive, ClientCertificateCredential};
+use azure_sdk_core::errors::AzureError;
+use oauth2::{ClientId, ClientSecret};
+use std::env;
+use std::sync::Arc;
+
+#[derive(Debug, Clone)]
+enum ServicePrincipal {
+    ClientSecret {
+        client_id: ClientId,
+        client_secret: ClientSecret,
+        tenant_id: String,
+    },
+    ClientCertificate(ClientCertificateCredential),
+}
+
+/// A service principal configured through the environment:
+/// `AZURE_TENANT_ID` and `AZURE_CLIENT_ID`, plus either
+/// `AZURE_CLIENT_SECRET` or `AZURE_CLIENT_CERTIFICATE_PATH` (a PEM file
+/// holding both the private key and the certificate).
+#[derive(Debug, Clone)]
+pub struct EnvironmentCredential {
+    client: Arc<reqwest::Client>,
+    service_principal: ServicePrincipal,
+}
+
+impl EnvironmentCredential {
+    pub fn new(client: Arc<reqwest::Client>) -> Result<Self, AzureError> {
+        let tenant_id = var("AZURE_TENANT_ID")?;
+        let client_id = ClientId::new(var("AZURE_CLIENT_ID")?);
+
+        let service_principal = match env::var("AZURE_CLIENT_SECRET") {
+            Ok(client_secret) => ServicePrincipal::ClientSecret {
+                client_id,
+                client_secret: ClientSecret::new(client_secret),
+                tenant_id,
+            },
+            Err(_) => {
+                let certificate_path = var("AZURE_CLIENT_CERTIFICATE_PATH")?;
+                let pem = std::fs::read(&certificate_path).map_err(|e| {
+                    AzureError::GenericErrorWithText(format!(
+                        "Cannot read {}: {}",
+                        certificate_path, e
+                    ))
+                })?;
+                ServicePrincipal::ClientCertificate(ClientCertificateCredential::new(
+                    client_id, &tenant_id, &pem, &pem,
+                )?)
+            }
+        };
+
+        Ok(Self {
+            client,
+            service_principal,
+        })
+    }
+}
+
+fn var(name: &str) -> Result<String, AzureError> {
+    env::var(name).map_err(|_| {
+        AzureError::InputParametersError(format!("Missing {} environment variable", name))
+    })
+}
+
+impl TokenCredential for EnvironmentCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let login_response = match &self.service_principal {
+                ServicePrincipal::ClientSecret {
+                    client_id,
+                    client_secret,
+                    tenant_id,
+                } => {
+                    authorize_non_interactive(
+                        self.client.clone(),
+                        client_id,
+                        client_secret,
+                        scopes_to_resource(scopes)?,
+                        tenant_id,
+                    )
+                    .await?
+                }
+                ServicePrincipal::ClientCertificate(credential) => {
+                    credential
+                        .authorize_v2(self.client.clone(), &scopes.join(" "))
+                        .await?
+                }
+            };
+            Ok(login_response.into())
+        })
+    }
+}
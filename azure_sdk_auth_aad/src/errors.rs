@@ -1,3 +1,5 @@
+use azure_sdk_core::errors::AzureError;
+
 #[derive(Debug, Fail)]
 pub enum ServerReceiveError {
     #[fail(display = "unexpected redirect url: {}", url)]
@@ -12,14 +14,90 @@ pub enum ServerReceiveError {
         expected_state_secret: String,
         received_state_secret: String,
     },
+    #[fail(display = "{}: {}", error, error_description)]
+    AuthorizationFailed {
+        error: String,
+        error_description: String,
+    },
+    #[fail(display = "cannot listen on {}: {}", address, reason)]
+    BindFailed { address: String, reason: String },
+    #[fail(display = "no redirect received within {:?}", timeout)]
+    TimedOut { timeout: std::time::Duration },
+}
+
+#[derive(Debug, Fail)]
+pub enum TokenValidationError {
+    /// The token must be rejected, for example with a 401 status code.
+    #[fail(display = "invalid token: {}", reason)]
+    InvalidToken { reason: String },
+    /// The signing keys could not be fetched, the token could not be
+    /// checked.
+    #[fail(display = "cannot fetch the signing keys: {}", reason)]
+    SigningKeysUnavailable { reason: String },
 }
 
 #[derive(Debug, Fail, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ErrorResponse {
+    #[fail(
+        display = "Azure Active Directory error {}:\n{}\n",
+        error, error_description
+    )]
+    ServerError {
+        error: String,
+        error_description: String,
+        #[serde(default)]
+        error_codes: Vec<u64>,
+        suberror: Option<String>,
+        /// The claims challenge to pass along when requesting a new token
+        /// interactively, for example to satisfy a conditional access
+        /// policy.
+        claims: Option<String>,
+        correlation_id: Option<String>,
+    },
     #[fail(
         display = "Unrecognized Azure error response:\n{}\n",
         error_description
     )]
     GenericError { error_description: String },
 }
+
+impl ErrorResponse {
+    /// Finds the error response the token endpoint returned, if that is
+    /// why `error` happened.
+    pub fn from_azure_error(error: &AzureError) -> Option<&ErrorResponse> {
+        match error {
+            AzureError::FailureError(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// The OAuth2 error code, for example `invalid_grant`.
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            ErrorResponse::ServerError { error, .. } => Some(error),
+            ErrorResponse::GenericError { .. } => None,
+        }
+    }
+
+    pub fn claims(&self) -> Option<&str> {
+        match self {
+            ErrorResponse::ServerError { claims, .. } => claims.as_deref(),
+            ErrorResponse::GenericError { .. } => None,
+        }
+    }
+
+    /// The refresh token or assertion is invalid, expired or revoked: the
+    /// user has to sign in again.
+    pub fn is_invalid_grant(&self) -> bool {
+        self.error() == Some("invalid_grant")
+    }
+
+    /// The user has to interact with Azure Active Directory, for example to
+    /// consent or to complete multi-factor authentication. When using the
+    /// on-behalf-of flow, pass the error (and its `claims`) back to the
+    /// client application.
+    pub fn is_interaction_required(&self) -> bool {
+        self.error() == Some("interaction_required")
+    }
+}
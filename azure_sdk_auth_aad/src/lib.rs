@@ -15,10 +15,36 @@ use oauth2::{
 pub use oauth2::{ClientId, ClientSecret};
 use url::form_urlencoded;
 use url::Url;
+mod azure_cli_credential;
+pub use azure_cli_credential::*;
+mod client_certificate_credential;
+pub use client_certificate_credential::*;
+mod default_credential;
+pub use default_credential::*;
+mod device_code_flow;
+pub use device_code_flow::*;
+mod environment_credential;
+pub use environment_credential::*;
 mod login_response;
 pub use login_response::*;
+mod managed_identity_credential;
+pub use managed_identity_credential::*;
+mod on_behalf_of_flow;
+pub use on_behalf_of_flow::*;
+mod redirect_server;
+pub use redirect_server::*;
+mod refresh_token_flow;
+pub use refresh_token_flow::*;
+mod string_or_number;
+mod token_credential;
+pub use token_credential::*;
+mod token_request;
+mod token_validator;
+pub use token_validator::*;
 use std::sync::Arc;
 pub mod errors;
+#[cfg(test)]
+mod fake_server;
 mod naive_server;
 use futures::TryFutureExt;
 pub use naive_server::naive_server;
@@ -1,18 +1,23 @@
+use crate::string_or_number::StringOrNumber;
 use azure_sdk_core::errors::AzureError;
 use chrono::{DateTime, TimeZone, Utc};
-use oauth2::AccessToken;
+use oauth2::{AccessToken, RefreshToken};
 use serde::de::{self, Deserialize, Deserializer};
 use std::str::FromStr;
 
+// The v1 endpoints send every field as a string, the v2 endpoints send
+// numbers and omit `expires_on`, `not_before` and `resource`.
 #[derive(Debug, Clone, Deserialize)]
 struct _LoginResponse {
     token_type: String,
-    expires_in: String,
-    ext_expires_in: String,
-    expires_on: String,
-    not_before: String,
+    expires_in: StringOrNumber,
+    ext_expires_in: Option<StringOrNumber>,
+    expires_on: Option<StringOrNumber>,
+    not_before: Option<StringOrNumber>,
+    #[serde(default)]
     resource: String,
     access_token: String,
+    refresh_token: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +29,9 @@ pub struct LoginResponse {
     pub not_before: DateTime<Utc>,
     pub resource: String,
     pub access_token: AccessToken,
+    /// Only issued by delegated flows, and by the v2 endpoints only when
+    /// the `offline_access` scope is requested.
+    pub refresh_token: Option<RefreshToken>,
 }
 
 impl FromStr for LoginResponse {
@@ -50,21 +58,38 @@ impl LoginResponse {
         &self.access_token
     }
 
+    pub fn refresh_token(&self) -> Option<&RefreshToken> {
+        self.refresh_token.as_ref()
+    }
+
     fn from_base_response(r: _LoginResponse) -> Result<LoginResponse, AzureError> {
-        let expires_on: i64 = r.expires_on.parse()?;
-        let expires_on: DateTime<Utc> = Utc.timestamp(expires_on, 0);
+        let now = Utc::now();
+        let expires_in = r.expires_in.parse()?;
+
+        let expires_on: DateTime<Utc> = match r.expires_on {
+            Some(expires_on) => Utc.timestamp(expires_on.parse()?, 0),
+            None => now + chrono::Duration::seconds(expires_in),
+        };
+
+        let not_before: DateTime<Utc> = match r.not_before {
+            Some(not_before) => Utc.timestamp(not_before.parse()?, 0),
+            None => now,
+        };
 
-        let not_before: i64 = r.not_before.parse()?;
-        let not_before: DateTime<Utc> = Utc.timestamp(not_before, 0);
+        let ext_expires_in = match r.ext_expires_in {
+            Some(ext_expires_in) => ext_expires_in.parse()?,
+            None => expires_in,
+        };
 
         Ok(LoginResponse {
             token_type: r.token_type,
-            expires_in: r.expires_in.parse()?,
-            ext_expires_in: r.ext_expires_in.parse()?,
+            expires_in: expires_in as u64,
+            ext_expires_in: ext_expires_in as u64,
             expires_on,
             not_before,
             resource: r.resource,
             access_token: AccessToken::new(r.access_token),
+            refresh_token: r.refresh_token.map(RefreshToken::new),
         })
     }
 }
@@ -0,0 +1,428 @@
+use crate::string_or_number::StringOrNumber;
+use crate::token_credential::{scopes_to_resource, TokenCredential, TokenFuture};
+use crate::token_request::error_from_body;
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, TimeZone, Utc};
+use log::debug;
+use oauth2::AccessToken;
+use serde::de::{self, Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use url::Url;
+
+const IMDS_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+const IMDS_API_VERSION: &str = "2018-02-01";
+const APP_SERVICE_API_VERSION: &str = "2019-08-01";
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+/// The managed identity to request tokens for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManagedIdentity {
+    SystemAssigned,
+    /// A user-assigned identity, by client (application) id.
+    ClientId(String),
+    /// A user-assigned identity, by object (principal) id.
+    ObjectId(String),
+    /// A user-assigned identity, by ARM resource id.
+    ResourceId(String),
+}
+
+/// Where the tokens are requested from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManagedIdentityEndpoint {
+    /// The Azure Instance Metadata Service, available on VMs, VM scale sets
+    /// and AKS pods.
+    Imds(Url),
+    /// The App Service and Functions endpoint, advertised through the
+    /// `IDENTITY_ENDPOINT` and `IDENTITY_HEADER` environment variables.
+    AppService { endpoint: Url, header: String },
+}
+
+impl ManagedIdentityEndpoint {
+    /// Picks the App Service endpoint if the environment advertises one,
+    /// the Instance Metadata Service otherwise.
+    pub fn from_env() -> Result<Self, AzureError> {
+        match (env::var("IDENTITY_ENDPOINT"), env::var("IDENTITY_HEADER")) {
+            (Ok(endpoint), Ok(header)) => Ok(ManagedIdentityEndpoint::AppService {
+                endpoint: Url::parse(&endpoint)
+                    .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?,
+                header,
+            }),
+            _ => Ok(ManagedIdentityEndpoint::Imds(
+                Url::parse(IMDS_ENDPOINT).expect("Invalid IMDS endpoint URL"),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct _ManagedIdentityToken {
+    access_token: String,
+    expires_on: StringOrNumber,
+    resource: String,
+    token_type: String,
+}
+
+/// A token issued to a managed identity.
+#[derive(Debug, Clone)]
+pub struct ManagedIdentityToken {
+    pub token_type: String,
+    pub expires_on: DateTime<Utc>,
+    pub resource: String,
+    pub access_token: AccessToken,
+}
+
+impl<'de> Deserialize<'de> for ManagedIdentityToken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let r = _ManagedIdentityToken::deserialize(deserializer)?;
+
+        // IMDS sends the expiry as a string, some App Service versions as a
+        // number.
+        let expires_on = r.expires_on.parse().map_err(de::Error::custom)?;
+        let expires_on = Utc.timestamp_opt(expires_on, 0).single().ok_or_else(|| {
+            de::Error::custom(format!("expires_on {} is out of range", expires_on))
+        })?;
+
+        Ok(ManagedIdentityToken {
+            token_type: r.token_type,
+            expires_on,
+            resource: r.resource,
+            access_token: AccessToken::new(r.access_token),
+        })
+    }
+}
+
+impl ManagedIdentityToken {
+    pub fn access_token(&self) -> &AccessToken {
+        &self.access_token
+    }
+
+    fn expires_within(&self, margin: Duration) -> bool {
+        let margin =
+            chrono::Duration::from_std(margin).unwrap_or_else(|_| chrono::Duration::zero());
+        Utc::now() + margin >= self.expires_on
+    }
+}
+
+/// Requests tokens for a managed identity, caching them per resource until
+/// they get within `refresh_margin` of their expiry. Clones share the
+/// same cache.
+#[derive(Debug, Clone)]
+pub struct ManagedIdentityCredential {
+    client: Arc<reqwest::Client>,
+    identity: ManagedIdentity,
+    endpoint: ManagedIdentityEndpoint,
+    refresh_margin: Duration,
+    tokens: Arc<Mutex<HashMap<String, ManagedIdentityToken>>>,
+}
+
+impl ManagedIdentityCredential {
+    /// Creates a credential for `identity`, using the endpoint advertised
+    /// by the environment (see `ManagedIdentityEndpoint::from_env`).
+    pub fn new(
+        client: Arc<reqwest::Client>,
+        identity: ManagedIdentity,
+    ) -> Result<Self, AzureError> {
+        Ok(Self::with_endpoint(
+            client,
+            identity,
+            ManagedIdentityEndpoint::from_env()?,
+        ))
+    }
+
+    pub fn with_endpoint(
+        client: Arc<reqwest::Client>,
+        identity: ManagedIdentity,
+        endpoint: ManagedIdentityEndpoint,
+    ) -> Self {
+        Self {
+            client,
+            identity,
+            endpoint,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            tokens: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_refresh_margin(self, refresh_margin: Duration) -> Self {
+        Self {
+            refresh_margin,
+            ..self
+        }
+    }
+
+    pub fn identity(&self) -> &ManagedIdentity {
+        &self.identity
+    }
+
+    pub fn endpoint(&self) -> &ManagedIdentityEndpoint {
+        &self.endpoint
+    }
+
+    /// Returns the cached token for `resource`, requesting a new one first
+    /// if it is about to expire.
+    pub async fn get_token(&self, resource: &str) -> Result<ManagedIdentityToken, AzureError> {
+        let cached = self.tokens.lock().unwrap().get(resource).cloned();
+        match cached {
+            Some(token) if !token.expires_within(self.refresh_margin) => Ok(token),
+            _ => self.refresh(resource).await,
+        }
+    }
+
+    /// Requests a new token for `resource` unconditionally and caches it.
+    pub async fn refresh(&self, resource: &str) -> Result<ManagedIdentityToken, AzureError> {
+        debug!(
+            "requesting managed identity token for {} from {:?}",
+            resource, self.endpoint
+        );
+
+        let request = match &self.endpoint {
+            ManagedIdentityEndpoint::Imds(endpoint) => {
+                let mut url = endpoint.clone();
+                url.query_pairs_mut()
+                    .append_pair("api-version", IMDS_API_VERSION)
+                    .append_pair("resource", resource);
+                self.append_identity(&mut url, "object_id", "msi_res_id");
+                self.client.get(url).header("Metadata", "true")
+            }
+            ManagedIdentityEndpoint::AppService { endpoint, header } => {
+                let mut url = endpoint.clone();
+                url.query_pairs_mut()
+                    .append_pair("api-version", APP_SERVICE_API_VERSION)
+                    .append_pair("resource", resource);
+                self.append_identity(&mut url, "principal_id", "mi_res_id");
+                self.client
+                    .get(url)
+                    .header("X-IDENTITY-HEADER", header.as_str())
+            }
+        };
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+
+        if !status.is_success() {
+            return Err(error_from_body(status, &body));
+        }
+
+        let token: ManagedIdentityToken = serde_json::from_str(&body).map_err(|e| {
+            AzureError::GenericErrorWithText(format!(
+                "Failed to parse managed identity response: {}",
+                e
+            ))
+        })?;
+
+        self.tokens
+            .lock()
+            .unwrap()
+            .insert(resource.to_owned(), token.clone());
+        Ok(token)
+    }
+
+    // IMDS and App Service name the object id and resource id query
+    // parameters differently.
+    fn append_identity(
+        &self,
+        url: &mut Url,
+        object_id_parameter: &str,
+        resource_id_parameter: &str,
+    ) {
+        match &self.identity {
+            ManagedIdentity::SystemAssigned => {}
+            ManagedIdentity::ClientId(client_id) => {
+                url.query_pairs_mut().append_pair("client_id", client_id);
+            }
+            ManagedIdentity::ObjectId(object_id) => {
+                url.query_pairs_mut()
+                    .append_pair(object_id_parameter, object_id);
+            }
+            ManagedIdentity::ResourceId(resource_id) => {
+                url.query_pairs_mut()
+                    .append_pair(resource_id_parameter, resource_id);
+            }
+        }
+    }
+}
+
+impl TokenCredential for ManagedIdentityCredential {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let resource = scopes_to_resource(scopes)?;
+            Ok(ManagedIdentityCredential::get_token(self, resource)
+                .await?
+                .into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::fake_server;
+
+    fn token_body(access_token: &str, expires_on: DateTime<Utc>) -> String {
+        format!(
+            r#"{{"access_token":"{}","client_id":"cid","expires_in":"3599","expires_on":"{}","ext_expires_in":"3599","not_before":"0","resource":"https://vault.azure.net","token_type":"Bearer"}}"#,
+            access_token,
+            expires_on.timestamp()
+        )
+    }
+
+    #[test]
+    fn out_of_range_expiry_is_rejected() {
+        let token: ManagedIdentityToken =
+            serde_json::from_str(&token_body("token", Utc.timestamp_opt(0, 0).unwrap())).unwrap();
+        assert_eq!(token.expires_on.timestamp(), 0);
+
+        let body = r#"{"access_token":"token","expires_on":"9223372036854775807","resource":"https://vault.azure.net","token_type":"Bearer"}"#;
+        assert!(serde_json::from_str::<ManagedIdentityToken>(body).is_err());
+    }
+
+    #[tokio::test]
+    async fn imds_tokens_are_cached_until_close_to_expiry() {
+        let (url, handle) = fake_server(vec![
+            (
+                200,
+                token_body("token0", Utc::now() + chrono::Duration::hours(1)),
+            ),
+            (
+                200,
+                token_body("token1", Utc::now() + chrono::Duration::minutes(2)),
+            ),
+            (
+                200,
+                token_body("token2", Utc::now() + chrono::Duration::hours(1)),
+            ),
+        ]);
+
+        let credential = ManagedIdentityCredential::with_endpoint(
+            Arc::new(reqwest::Client::new()),
+            ManagedIdentity::ClientId("00000000-0000-0000-0000-000000000001".to_owned()),
+            ManagedIdentityEndpoint::Imds(url.join("msi/token").unwrap()),
+        );
+
+        let token = credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token0");
+        let token = credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token0");
+
+        // a different resource gets its own token, expiring within the
+        // margin so the next call requests a new one
+        let token = credential
+            .get_token("https://storage.azure.com")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token1");
+        let token = credential
+            .get_token("https://storage.azure.com")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token2");
+
+        let requests = handle.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with(
+            "GET /msi/token?api-version=2018-02-01&resource=https%3A%2F%2Fvault.azure.net&client_id=00000000-0000-0000-0000-000000000001 "
+        ));
+        assert!(requests[0].to_lowercase().contains("metadata: true"));
+    }
+
+    #[tokio::test]
+    async fn app_service_endpoint() {
+        let (url, handle) = fake_server(vec![
+            (
+                200,
+                format!(
+                    r#"{{"access_token":"token0","expires_on":{},"resource":"https://vault.azure.net","token_type":"Bearer","client_id":"cid"}}"#,
+                    (Utc::now() + chrono::Duration::hours(1)).timestamp()
+                ),
+            ),
+            (
+                400,
+                r#"{"error":"invalid_resource","error_description":"AADSTS500011: resource not found"}"#
+                    .to_owned(),
+            ),
+        ]);
+
+        let credential = ManagedIdentityCredential::with_endpoint(
+            Arc::new(reqwest::Client::new()),
+            ManagedIdentity::ObjectId("oid".to_owned()),
+            ManagedIdentityEndpoint::AppService {
+                endpoint: url.join("msi/token").unwrap(),
+                header: "secret".to_owned(),
+            },
+        );
+
+        let token = credential
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+        assert_eq!(token.access_token().secret(), "token0");
+
+        let error = credential.get_token("https://nope").await.unwrap_err();
+        assert!(error.to_string().contains("AADSTS500011"));
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with(
+            "GET /msi/token?api-version=2019-08-01&resource=https%3A%2F%2Fvault.azure.net&principal_id=oid "
+        ));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("x-identity-header: secret"));
+    }
+
+    #[tokio::test]
+    async fn resource_id_parameter_depends_on_the_endpoint() {
+        let resource_id = "/subscriptions/sub/resourcegroups/rg/providers/Microsoft.ManagedIdentity/userAssignedIdentities/id";
+        let body = token_body("token", Utc::now() + chrono::Duration::hours(1));
+        let (url, handle) = fake_server(vec![(200, body.clone()), (200, body)]);
+
+        let imds = ManagedIdentityCredential::with_endpoint(
+            Arc::new(reqwest::Client::new()),
+            ManagedIdentity::ResourceId(resource_id.to_owned()),
+            ManagedIdentityEndpoint::Imds(url.join("msi/token").unwrap()),
+        );
+        imds.get_token("https://vault.azure.net").await.unwrap();
+
+        let app_service = ManagedIdentityCredential::with_endpoint(
+            Arc::new(reqwest::Client::new()),
+            ManagedIdentity::ResourceId(resource_id.to_owned()),
+            ManagedIdentityEndpoint::AppService {
+                endpoint: url.join("msi/token").unwrap(),
+                header: "secret".to_owned(),
+            },
+        );
+        app_service
+            .get_token("https://vault.azure.net")
+            .await
+            .unwrap();
+
+        let encoded = "%2Fsubscriptions%2Fsub%2Fresourcegroups%2Frg%2Fproviders%2FMicrosoft.ManagedIdentity%2FuserAssignedIdentities%2Fid";
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with(&format!(
+            "GET /msi/token?api-version=2018-02-01&resource=https%3A%2F%2Fvault.azure.net&msi_res_id={} ",
+            encoded
+        )));
+        assert!(requests[1].starts_with(&format!(
+            "GET /msi/token?api-version=2019-08-01&resource=https%3A%2F%2Fvault.azure.net&mi_res_id={} ",
+            encoded
+        )));
+    }
+}
@@ -6,6 +6,8 @@ use std::io::{BufRead, BufReader, Write};
 use std::net::TcpListener;
 use url::Url;
 
+/// Blocks until the first request on `port`. Prefer `RedirectServer`, which
+/// does not block, times out and survives stray requests.
 pub fn naive_server(
     auth_obj: &AuthObj,
     port: u32,
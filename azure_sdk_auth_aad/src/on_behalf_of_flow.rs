@@ -0,0 +1,110 @@
+use crate::token_request::{authority, request_v2_token};
+use crate::LoginResponse;
+use azure_sdk_core::errors::AzureError;
+use oauth2::{ClientId, ClientSecret};
+use std::sync::Arc;
+use url::form_urlencoded;
+use url::Url;
+
+/// Exchanges the access token a middle-tier API received (`assertion`) for
+/// a token to call a downstream API with `scopes`, as the same user.
+///
+/// If the downstream API needs more from the user, for example consent or
+/// multi-factor authentication, the error holds an `errors::ErrorResponse`
+/// for which `is_interaction_required` is true: send it back, with its
+/// `claims`, to the client application.
+pub async fn on_behalf_of(
+    client: Arc<reqwest::Client>,
+    client_id: &ClientId,
+    client_secret: &ClientSecret,
+    assertion: &str,
+    tenant_id: &str,
+    scopes: &[&str],
+) -> Result<LoginResponse, AzureError> {
+    on_behalf_of_with_authority(
+        client,
+        &authority(tenant_id)?,
+        client_id,
+        client_secret,
+        assertion,
+        scopes,
+    )
+    .await
+}
+
+async fn on_behalf_of_with_authority(
+    client: Arc<reqwest::Client>,
+    authority: &Url,
+    client_id: &ClientId,
+    client_secret: &ClientSecret,
+    assertion: &str,
+    scopes: &[&str],
+) -> Result<LoginResponse, AzureError> {
+    let encoded: String = form_urlencoded::Serializer::new(String::new())
+        .append_pair("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer")
+        .append_pair("client_id", client_id.as_str())
+        .append_pair("client_secret", client_secret.secret())
+        .append_pair("assertion", assertion)
+        .append_pair("scope", &scopes.join(" "))
+        .append_pair("requested_token_use", "on_behalf_of")
+        .finish();
+
+    request_v2_token(&client, authority, encoded).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorResponse;
+    use crate::fake_server::fake_server;
+
+    #[tokio::test]
+    async fn exchanges_the_incoming_token() {
+        let (url, handle) = fake_server(vec![
+            (
+                200,
+                r#"{"token_type":"Bearer","scope":"https://graph.microsoft.com/User.Read","expires_in":3599,"ext_expires_in":3599,"access_token":"downstream"}"#
+                    .to_owned(),
+            ),
+            (
+                400,
+                r#"{"error":"interaction_required","error_description":"AADSTS50079: Due to a configuration change made by your administrator, you must enroll in multi-factor authentication.","error_codes":[50079],"suberror":"basic_action","claims":"{\"access_token\":{\"capolids\":{\"essential\":true,\"values\":[\"p\"]}}}"}"#
+                    .to_owned(),
+            ),
+        ]);
+        let authority = url.join("tenant/").unwrap();
+        let client = Arc::new(reqwest::Client::new());
+        let client_id = ClientId::new("cid".to_owned());
+        let client_secret = ClientSecret::new("secret".to_owned());
+
+        let login_response = on_behalf_of_with_authority(
+            client.clone(),
+            &authority,
+            &client_id,
+            &client_secret,
+            "incoming",
+            &["https://graph.microsoft.com/User.Read"],
+        )
+        .await
+        .unwrap();
+        assert_eq!(login_response.access_token().secret(), "downstream");
+
+        let error = on_behalf_of_with_authority(
+            client,
+            &authority,
+            &client_id,
+            &client_secret,
+            "incoming",
+            &["https://graph.microsoft.com/User.Read"],
+        )
+        .await
+        .unwrap_err();
+        let error_response = ErrorResponse::from_azure_error(&error).unwrap();
+        assert!(error_response.is_interaction_required());
+        assert!(error_response.claims().unwrap().contains("capolids"));
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with("POST /tenant/oauth2/v2.0/token "));
+        assert!(requests[0].ends_with("grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer&client_id=cid&client_secret=secret&assertion=incoming&scope=https%3A%2F%2Fgraph.microsoft.com%2FUser.Read&requested_token_use=on_behalf_of"));
+    }
+}
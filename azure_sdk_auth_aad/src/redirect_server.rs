@@ -0,0 +1,389 @@
+use crate::errors::ServerReceiveError;
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::FutureExt;
+use log::{debug, warn};
+use oauth2::{AuthorizationCode, CsrfToken};
+use std::net::Ipv4Addr;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+
+const REDIRECT_PATH: &str = "/redirect";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+// Browsers open speculative connections they may never send a request on,
+// do not let them hold a slot for long.
+const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REQUEST_HEADERS: usize = 100;
+
+const DEFAULT_SUCCESS_PAGE: &str = "<!DOCTYPE html><html><head><title>Signed in</title></head>\
+<body><p>Authentication complete. You can close this window now.</p></body></html>";
+const DEFAULT_ERROR_PAGE: &str = "<!DOCTYPE html><html><head><title>Sign in failed</title></head>\
+<body><p>Authentication failed: {error}</p></body></html>";
+
+/// Receives the redirect of the authorization code flow on a loopback
+/// address. Bind it first and pass its `redirect_url` to
+/// `authorize_delegate`, then wait for the code with `receive`:
+///
+/// ```no_run
+/// # use azure_sdk_auth_aad::*;
+/// # async fn sign_in(client_id: ClientId) -> Result<(), failure::Error> {
+/// let server = RedirectServer::bind().await?;
+/// let auth_obj = authorize_delegate(
+///     client_id,
+///     None,
+///     "common",
+///     server.redirect_url().clone(),
+///     "https://management.azure.com/",
+/// );
+/// println!("browse {}", auth_obj.authorize_url);
+/// let code = server.receive(&auth_obj.csrf_state).await?;
+/// let token = exchange(auth_obj, code).await;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The redirect URL is `http://127.0.0.1:<port>/redirect`: register
+/// `http://127.0.0.1/redirect` as a redirect URI of the application. Azure
+/// Active Directory ignores the port of loopback redirect URIs, so it matches
+/// the ephemeral port picked by `bind`, but the host and the path must be
+/// the same.
+#[derive(Debug)]
+pub struct RedirectServer {
+    listener: TcpListener,
+    redirect_url: Url,
+    timeout: Duration,
+    success_page: String,
+    error_page: String,
+}
+
+impl RedirectServer {
+    /// Binds an ephemeral port of `127.0.0.1`.
+    pub async fn bind() -> Result<Self, ServerReceiveError> {
+        Self::bind_port(0).await
+    }
+
+    pub async fn bind_port(port: u16) -> Result<Self, ServerReceiveError> {
+        let address = (Ipv4Addr::LOCALHOST, port);
+        let bind_failed = |error: std::io::Error| ServerReceiveError::BindFailed {
+            address: format!("127.0.0.1:{}", port),
+            reason: error.to_string(),
+        };
+        let listener = TcpListener::bind(address).await.map_err(bind_failed)?;
+        let local_address = listener.local_addr().map_err(bind_failed)?;
+        let redirect_url = Url::parse(&format!("http://{}{}", local_address, REDIRECT_PATH))
+            .expect("Invalid redirect URL");
+
+        Ok(Self {
+            listener,
+            redirect_url,
+            timeout: DEFAULT_TIMEOUT,
+            success_page: DEFAULT_SUCCESS_PAGE.to_owned(),
+            error_page: DEFAULT_ERROR_PAGE.to_owned(),
+        })
+    }
+
+    /// How long `receive` waits for the redirect, 5 minutes by default.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// The HTML page shown in the browser once the code is received.
+    pub fn with_success_page<S: Into<String>>(self, success_page: S) -> Self {
+        Self {
+            success_page: success_page.into(),
+            ..self
+        }
+    }
+
+    /// The HTML page shown in the browser when the sign in failed. `{error}`
+    /// is replaced with the (escaped) reason.
+    pub fn with_error_page<S: Into<String>>(self, error_page: S) -> Self {
+        Self {
+            error_page: error_page.into(),
+            ..self
+        }
+    }
+
+    pub fn redirect_url(&self) -> &Url {
+        &self.redirect_url
+    }
+
+    /// Waits for the redirect carrying the authorization code and checks
+    /// its state against `csrf_state`. Requests for other paths, like the
+    /// favicon, are answered with a 404 and ignored, redirects with another
+    /// state with a 400.
+    pub async fn receive(
+        self,
+        csrf_state: &CsrfToken,
+    ) -> Result<AuthorizationCode, ServerReceiveError> {
+        let RedirectServer {
+            mut listener,
+            timeout,
+            success_page,
+            error_page,
+            ..
+        } = self;
+        let pages = Pages {
+            success: &success_page,
+            error: &error_page,
+        };
+
+        let mut connections = FuturesUnordered::new();
+        let serve = async {
+            loop {
+                futures::select! {
+                    accepted = listener.accept().fuse() => match accepted {
+                        Ok((stream, _)) => connections.push(tokio::time::timeout(
+                            CONNECTION_TIMEOUT,
+                            handle_connection(stream, csrf_state, &pages),
+                        )),
+                        Err(error) => warn!("cannot accept redirect connection: {}", error),
+                    },
+                    handled = connections.select_next_some() => match handled {
+                        Ok(Some(result)) => return result,
+                        Ok(None) => {}
+                        Err(_) => debug!("dropping idle redirect connection"),
+                    },
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, serve)
+            .await
+            .map_err(|_| ServerReceiveError::TimedOut { timeout })?
+    }
+}
+
+struct Pages<'a> {
+    success: &'a str,
+    error: &'a str,
+}
+
+// Returns `None` for stray requests and for connections dropped before a
+// complete request was read.
+async fn handle_connection(
+    mut stream: TcpStream,
+    csrf_state: &CsrfToken,
+    pages: &Pages<'_>,
+) -> Option<Result<AuthorizationCode, ServerReceiveError>> {
+    let target = match read_request_target(&mut stream).await {
+        Ok(Some(target)) => target,
+        Ok(None) => return None,
+        Err(error) => {
+            debug!("cannot read redirect request: {}", error);
+            return None;
+        }
+    };
+    debug!("redirect server received {}", target);
+
+    let result = Url::parse(&format!("http://localhost{}", target))
+        .ok()
+        .filter(|url| url.path() == REDIRECT_PATH)
+        .map(|url| authorization_code(&url, csrf_state));
+
+    let response = match &result {
+        None => response("404 Not Found", "Not found"),
+        Some(Ok(_)) => response("200 OK", pages.success),
+        // anything able to reach the port can send a redirect: do not show
+        // the expected state to it
+        Some(Err(ServerReceiveError::StateSecretMismatch { .. })) => {
+            response("400 Bad Request", "Bad request")
+        }
+        Some(Err(error)) => response(
+            "400 Bad Request",
+            &pages
+                .error
+                .replace("{error}", &escape_html(&error.to_string())),
+        ),
+    };
+    if let Err(error) = stream.write_all(response.as_bytes()).await {
+        debug!("cannot answer redirect request: {}", error);
+    }
+
+    // only the redirect carrying our state may end `receive`
+    match result {
+        Some(Err(ServerReceiveError::StateSecretMismatch { .. })) => {
+            warn!("ignoring redirect with a mismatched state");
+            None
+        }
+        result => result,
+    }
+}
+
+// Reads the request head and returns the target of a GET request.
+async fn read_request_target(stream: &mut TcpStream) -> std::io::Result<Option<String>> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    for _ in 0..MAX_REQUEST_HEADERS {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 || line == "\r\n" || line == "\n" {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    Ok(match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) if target.starts_with('/') => Some(target.to_owned()),
+        _ => None,
+    })
+}
+
+fn authorization_code(
+    url: &Url,
+    csrf_state: &CsrfToken,
+) -> Result<AuthorizationCode, ServerReceiveError> {
+    let query_pair = |key: &str| {
+        url.query_pairs()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    // checked first so that a forged error redirect is ignored too
+    let state = query_pair("state").unwrap_or_default();
+    if state != *csrf_state.secret() {
+        return Err(ServerReceiveError::StateSecretMismatch {
+            expected_state_secret: csrf_state.secret().to_owned(),
+            received_state_secret: state,
+        });
+    }
+
+    if let Some(error) = query_pair("error") {
+        return Err(ServerReceiveError::AuthorizationFailed {
+            error,
+            error_description: query_pair("error_description").unwrap_or_default(),
+        });
+    }
+
+    query_pair("code")
+        .map(AuthorizationCode::new)
+        .ok_or_else(|| ServerReceiveError::QueryPairNotFound {
+            query_pair: "code".to_owned(),
+        })
+}
+
+fn response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: text/html; charset=utf-8\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn get(url: Url) -> (u16, String) {
+        let response = reqwest::get(url).await.unwrap();
+        let status = response.status().as_u16();
+        (status, response.text().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn receives_the_code_and_ignores_stray_requests() {
+        let server = RedirectServer::bind()
+            .await
+            .unwrap()
+            .with_success_page("<p>done</p>");
+        let redirect_url = server.redirect_url().clone();
+        assert_eq!(redirect_url.path(), "/redirect");
+        assert_ne!(redirect_url.port(), Some(0));
+
+        let csrf_state = CsrfToken::new("state".to_owned());
+        let browser = async {
+            // an idle speculative connection must not block the others
+            let _idle = TcpStream::connect(redirect_url.socket_addrs(|| None).unwrap()[0])
+                .await
+                .unwrap();
+            assert_eq!(get(redirect_url.join("/favicon.ico").unwrap()).await.0, 404);
+
+            let mut redirect = redirect_url.clone();
+            redirect.set_query(Some("code=the+code&state=state"));
+            get(redirect).await
+        };
+
+        let (code, (status, page)) = futures::join!(server.receive(&csrf_state), browser);
+        assert_eq!(code.unwrap().secret(), "the code");
+        assert_eq!(status, 200);
+        assert_eq!(page, "<p>done</p>");
+    }
+
+    #[tokio::test]
+    async fn ignores_redirects_with_another_state() {
+        let server = RedirectServer::bind().await.unwrap();
+        let redirect_url = server.redirect_url().clone();
+
+        let csrf_state = CsrfToken::new("the state".to_owned());
+        let browser = async {
+            for query in &[
+                "code=forged&state=forged",
+                "code=forged",
+                "error=access_denied&state=forged",
+            ] {
+                let mut redirect = redirect_url.clone();
+                redirect.set_query(Some(query));
+                let (status, page) = get(redirect).await;
+                assert_eq!(status, 400);
+                assert!(!page.contains("the state"));
+            }
+
+            let mut redirect = redirect_url.clone();
+            redirect.set_query(Some("code=the+code&state=the+state"));
+            get(redirect).await
+        };
+
+        let (code, (status, _)) = futures::join!(server.receive(&csrf_state), browser);
+        assert_eq!(code.unwrap().secret(), "the code");
+        assert_eq!(status, 200);
+    }
+
+    #[tokio::test]
+    async fn reports_failures_to_the_browser() {
+        let csrf_state = CsrfToken::new("state".to_owned());
+        let server = RedirectServer::bind()
+            .await
+            .unwrap()
+            .with_error_page("failed: {error}");
+        let mut redirect = server.redirect_url().clone();
+        redirect.set_query(Some(
+            "error=access_denied&error_description=The+user+declined&state=state",
+        ));
+        let (result, (status, page)) = futures::join!(server.receive(&csrf_state), get(redirect));
+        match result {
+            Err(ServerReceiveError::AuthorizationFailed { error, .. }) => {
+                assert_eq!(error, "access_denied")
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(status, 400);
+        assert_eq!(page, "failed: access_denied: The user declined");
+
+        let server = RedirectServer::bind()
+            .await
+            .unwrap()
+            .with_timeout(Duration::from_millis(50));
+        match server.receive(&csrf_state).await {
+            Err(ServerReceiveError::TimedOut { .. }) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
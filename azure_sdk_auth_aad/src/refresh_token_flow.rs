@@ -0,0 +1,116 @@
+use crate::token_request::{authority, request_v2_token};
+use crate::LoginResponse;
+use azure_sdk_core::errors::AzureError;
+use oauth2::{ClientId, ClientSecret, RefreshToken};
+use std::sync::Arc;
+use url::form_urlencoded;
+use url::Url;
+
+/// Redeems a refresh token, for example the one returned by `exchange`, for
+/// a new access token for `scopes`. `client_secret` is only needed by
+/// confidential clients.
+///
+/// If the refresh token has expired or was revoked the error holds an
+/// `errors::ErrorResponse` for which `is_invalid_grant` is true: the user
+/// has to sign in again.
+pub async fn refresh(
+    client: Arc<reqwest::Client>,
+    client_id: &ClientId,
+    client_secret: Option<&ClientSecret>,
+    refresh_token: &RefreshToken,
+    tenant_id: &str,
+    scopes: &[&str],
+) -> Result<LoginResponse, AzureError> {
+    refresh_with_authority(
+        client,
+        &authority(tenant_id)?,
+        client_id,
+        client_secret,
+        refresh_token,
+        scopes,
+    )
+    .await
+}
+
+async fn refresh_with_authority(
+    client: Arc<reqwest::Client>,
+    authority: &Url,
+    client_id: &ClientId,
+    client_secret: Option<&ClientSecret>,
+    refresh_token: &RefreshToken,
+    scopes: &[&str],
+) -> Result<LoginResponse, AzureError> {
+    let mut encoded = form_urlencoded::Serializer::new(String::new());
+    encoded
+        .append_pair("grant_type", "refresh_token")
+        .append_pair("client_id", client_id.as_str())
+        .append_pair("refresh_token", refresh_token.secret())
+        .append_pair("scope", &scopes.join(" "));
+    if let Some(client_secret) = client_secret {
+        encoded.append_pair("client_secret", client_secret.secret());
+    }
+
+    request_v2_token(&client, authority, encoded.finish()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorResponse;
+    use crate::fake_server::fake_server;
+
+    #[tokio::test]
+    async fn redeems_the_refresh_token() {
+        let (url, handle) = fake_server(vec![
+            (
+                200,
+                r#"{"token_type":"Bearer","scope":"https://graph.microsoft.com/User.Read","expires_in":3599,"ext_expires_in":3599,"access_token":"token1","refresh_token":"refresh1"}"#
+                    .to_owned(),
+            ),
+            (
+                400,
+                r#"{"error":"invalid_grant","error_description":"AADSTS700082: The refresh token has expired due to inactivity.","error_codes":[700082],"timestamp":"2020-05-20 10:00:00Z","trace_id":"t","correlation_id":"c"}"#
+                    .to_owned(),
+            ),
+        ]);
+        let authority = url.join("tenant/").unwrap();
+        let client = Arc::new(reqwest::Client::new());
+
+        let login_response = refresh_with_authority(
+            client.clone(),
+            &authority,
+            &ClientId::new("cid".to_owned()),
+            None,
+            &RefreshToken::new("refresh0".to_owned()),
+            &["https://graph.microsoft.com/User.Read", "offline_access"],
+        )
+        .await
+        .unwrap();
+        assert_eq!(login_response.access_token().secret(), "token1");
+        assert_eq!(
+            login_response
+                .refresh_token()
+                .map(|token| token.secret().as_str()),
+            Some("refresh1")
+        );
+
+        let error = refresh_with_authority(
+            client,
+            &authority,
+            &ClientId::new("cid".to_owned()),
+            Some(&ClientSecret::new("secret".to_owned())),
+            &RefreshToken::new("refresh1".to_owned()),
+            &["https://graph.microsoft.com/User.Read"],
+        )
+        .await
+        .unwrap_err();
+        let error_response = ErrorResponse::from_azure_error(&error).unwrap();
+        assert!(error_response.is_invalid_grant());
+        assert!(!error_response.is_interaction_required());
+
+        let requests = handle.join().unwrap();
+        assert!(requests[0].starts_with("POST /tenant/oauth2/v2.0/token "));
+        assert!(requests[0].ends_with("grant_type=refresh_token&client_id=cid&refresh_token=refresh0&scope=https%3A%2F%2Fgraph.microsoft.com%2FUser.Read+offline_access"));
+        assert!(requests[1].ends_with("&client_secret=secret"));
+    }
+}
@@ -0,0 +1,19 @@
+use std::num::ParseIntError;
+
+/// Azure Active Directory endpoints send some numeric fields as strings,
+/// depending on the endpoint version.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum StringOrNumber {
+    String(String),
+    Number(i64),
+}
+
+impl StringOrNumber {
+    pub(crate) fn parse(self) -> Result<i64, ParseIntError> {
+        match self {
+            StringOrNumber::String(s) => s.parse(),
+            StringOrNumber::Number(n) => Ok(n),
+        }
+    }
+}
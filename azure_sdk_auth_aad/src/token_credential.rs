@@ -0,0 +1,223 @@
+use crate::{LoginResponse, ManagedIdentityToken};
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use futures::lock::Mutex as AsyncMutex;
+use log::debug;
+use oauth2::AccessToken;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+pub type TokenFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<CredentialToken, AzureError>> + Send + 'a>>;
+
+/// An access token along with its expiry, whatever credential issued it.
+#[derive(Debug, Clone)]
+pub struct CredentialToken {
+    pub access_token: AccessToken,
+    pub expires_on: DateTime<Utc>,
+}
+
+impl CredentialToken {
+    pub fn new(access_token: AccessToken, expires_on: DateTime<Utc>) -> Self {
+        Self {
+            access_token,
+            expires_on,
+        }
+    }
+
+    pub fn access_token(&self) -> &AccessToken {
+        &self.access_token
+    }
+
+    fn expires_within(&self, margin: Duration) -> bool {
+        let margin =
+            chrono::Duration::from_std(margin).unwrap_or_else(|_| chrono::Duration::zero());
+        Utc::now() + margin >= self.expires_on
+    }
+}
+
+impl From<LoginResponse> for CredentialToken {
+    fn from(login_response: LoginResponse) -> Self {
+        Self::new(login_response.access_token, login_response.expires_on)
+    }
+}
+
+impl From<ManagedIdentityToken> for CredentialToken {
+    fn from(token: ManagedIdentityToken) -> Self {
+        Self::new(token.access_token, token.expires_on)
+    }
+}
+
+/// Something able to get Azure Active Directory tokens, so that clients
+/// do not have to care how they were obtained. `scopes` are v2 scopes, for
+/// example `https://storage.azure.com/.default`: credentials talking to v1
+/// endpoints turn the single scope into the matching resource.
+pub trait TokenCredential: Debug + Send + Sync {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a>;
+}
+
+impl<T: TokenCredential + ?Sized> TokenCredential for Arc<T> {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        (**self).get_token(scopes)
+    }
+}
+
+impl<T: TokenCredential + ?Sized> TokenCredential for Box<T> {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        (**self).get_token(scopes)
+    }
+}
+
+/// Turns the single `<resource>/.default` scope accepted by the v1
+/// endpoints into its resource.
+pub(crate) fn scopes_to_resource<'a>(scopes: &[&'a str]) -> Result<&'a str, AzureError> {
+    match scopes {
+        [scope] => Ok(scope.trim_end_matches("/.default")),
+        _ => Err(AzureError::InputParametersError(format!(
+            "Exactly one scope is supported by this credential, {} given",
+            scopes.len()
+        ))),
+    }
+}
+
+type TokenSlot = Arc<AsyncMutex<Option<CredentialToken>>>;
+
+/// Caches the tokens of another credential, per set of scopes, until they
+/// get within `refresh_margin` of their expiry. Concurrent requests for the
+/// same scopes share a single refresh. Clones share the same cache.
+#[derive(Clone)]
+pub struct CachedTokenCredential<C> {
+    credential: C,
+    refresh_margin: Duration,
+    tokens: Arc<Mutex<HashMap<String, TokenSlot>>>,
+}
+
+impl<C: Debug> Debug for CachedTokenCredential<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("CachedTokenCredential")
+            .field("credential", &self.credential)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish()
+    }
+}
+
+impl<C: TokenCredential> CachedTokenCredential<C> {
+    pub fn new(credential: C) -> Self {
+        Self {
+            credential,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            tokens: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_refresh_margin(self, refresh_margin: Duration) -> Self {
+        Self {
+            refresh_margin,
+            ..self
+        }
+    }
+
+    pub fn credential(&self) -> &C {
+        &self.credential
+    }
+}
+
+impl<C: TokenCredential> TokenCredential for CachedTokenCredential<C> {
+    fn get_token<'a>(&'a self, scopes: &'a [&'a str]) -> TokenFuture<'a> {
+        Box::pin(async move {
+            let slot = self
+                .tokens
+                .lock()
+                .unwrap()
+                .entry(scopes.join(" "))
+                .or_default()
+                .clone();
+
+            // Holding the slot while refreshing makes concurrent callers
+            // wait for, and then reuse, the new token.
+            let mut slot = slot.lock().await;
+            match slot.as_ref() {
+                Some(token) if !token.expires_within(self.refresh_margin) => Ok(token.clone()),
+                _ => {
+                    debug!("refreshing token for {:?}", scopes);
+                    let token = self.credential.get_token(scopes).await?;
+                    *slot = Some(token.clone());
+                    Ok(token)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct CountingCredential {
+        fetches: AtomicUsize,
+    }
+
+    impl TokenCredential for CountingCredential {
+        fn get_token<'a>(&'a self, _scopes: &'a [&'a str]) -> TokenFuture<'a> {
+            Box::pin(async move {
+                let fetch = self.fetches.fetch_add(1, Ordering::SeqCst);
+                tokio::time::delay_for(Duration::from_millis(20)).await;
+
+                // the second token is about to expire
+                let expires_in = if fetch == 1 {
+                    chrono::Duration::minutes(1)
+                } else {
+                    chrono::Duration::hours(1)
+                };
+                Ok(CredentialToken::new(
+                    AccessToken::new(format!("token{}", fetch)),
+                    Utc::now() + expires_in,
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_a_refresh() {
+        let credential = CachedTokenCredential::new(CountingCredential::default());
+        let scopes = &["https://storage.azure.com/.default"];
+
+        let (token0, token1) =
+            futures::join!(credential.get_token(scopes), credential.get_token(scopes));
+        assert_eq!(token0.unwrap().access_token().secret(), "token0");
+        assert_eq!(token1.unwrap().access_token().secret(), "token0");
+        assert_eq!(credential.credential().fetches.load(Ordering::SeqCst), 1);
+
+        // other scopes get their own token, which expires within the margin
+        let other_scopes = &["https://vault.azure.net/.default"];
+        let token = credential.get_token(other_scopes).await.unwrap();
+        assert_eq!(token.access_token().secret(), "token1");
+        let token = credential.get_token(other_scopes).await.unwrap();
+        assert_eq!(token.access_token().secret(), "token2");
+
+        let token = credential.get_token(scopes).await.unwrap();
+        assert_eq!(token.access_token().secret(), "token0");
+        assert_eq!(credential.credential().fetches.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn scopes_map_to_v1_resources() {
+        assert_eq!(
+            scopes_to_resource(&["https://management.azure.com/.default"]).unwrap(),
+            "https://management.azure.com"
+        );
+        assert_eq!(
+            scopes_to_resource(&["https://vault.azure.net"]).unwrap(),
+            "https://vault.azure.net"
+        );
+        assert!(scopes_to_resource(&[]).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::errors::ErrorResponse;
+use crate::LoginResponse;
+use azure_sdk_core::errors::AzureError;
+use url::Url;
+
+/// Posts a form to an Azure Active Directory endpoint, returning the status
+/// code and the body whatever the status.
+pub(crate) async fn post_form(
+    client: &reqwest::Client,
+    url: Url,
+    encoded: String,
+) -> Result<(reqwest::StatusCode, String), AzureError> {
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body(encoded)
+        .send()
+        .await
+        .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+    Ok((status, body))
+}
+
+/// Wraps the `ErrorResponse` in `body`, if any, so that callers can get it
+/// back with `ErrorResponse::from_azure_error`.
+pub(crate) fn error_from_body(status: reqwest::StatusCode, body: &str) -> AzureError {
+    serde_json::from_str::<ErrorResponse>(body)
+        .map(|er| AzureError::FailureError(er.into()))
+        .unwrap_or_else(|_| {
+            AzureError::GenericErrorWithText(format!("Azure returned {}: {}", status, body))
+        })
+}
+
+pub(crate) fn authority(tenant_id: &str) -> Result<Url, AzureError> {
+    Url::parse(&format!("https://login.microsoftonline.com/{}/", tenant_id))
+        .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))
+}
+
+/// Redeems a grant at the v2 token endpoint of `authority`.
+pub(crate) async fn request_v2_token(
+    client: &reqwest::Client,
+    authority: &Url,
+    encoded: String,
+) -> Result<LoginResponse, AzureError> {
+    let token_url = authority
+        .join("oauth2/v2.0/token")
+        .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+
+    let (status, body) = post_form(client, token_url, encoded).await?;
+    if !status.is_success() {
+        return Err(error_from_body(status, &body));
+    }
+    body.parse()
+}
@@ -0,0 +1,601 @@
+use crate::errors::TokenValidationError;
+use azure_sdk_core::errors::AzureError;
+use futures::lock::Mutex as AsyncMutex;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use log::{debug, warn};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use url::Url;
+
+const DEFAULT_CLOCK_SKEW: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_KEY_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_MAX_KEY_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+const TENANT_ID_PLACEHOLDER: &str = "{tenantid}";
+// The v1 and v2 issuers of the public cloud.
+const DEFAULT_ISSUERS: [&str; 2] = [
+    "https://sts.windows.net/{tenantid}/",
+    "https://login.microsoftonline.com/{tenantid}/v2.0",
+];
+
+pub type KeySetFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<JsonWebKeySet, AzureError>> + Send + 'a>>;
+
+/// A JSON Web Key, as published by Azure Active Directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonWebKey {
+    pub kty: String,
+    pub kid: String,
+    #[serde(rename = "use")]
+    pub key_use: Option<String>,
+    pub n: Option<String>,
+    pub e: Option<String>,
+    pub x5t: Option<String>,
+    #[serde(default)]
+    pub x5c: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonWebKeySet {
+    pub keys: Vec<JsonWebKey>,
+}
+
+impl JsonWebKeySet {
+    // Only RSA signing keys can verify Azure Active Directory tokens.
+    fn decoding_keys(&self) -> HashMap<String, DecodingKey<'static>> {
+        self.keys
+            .iter()
+            .filter(|key| key.kty == "RSA" && key.key_use.as_deref().unwrap_or("sig") == "sig")
+            .filter_map(|key| match (&key.n, &key.e) {
+                (Some(n), Some(e)) => Some((
+                    key.kid.clone(),
+                    DecodingKey::from_rsa_components(n, e).into_static(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Fetches the keys tokens are signed with. `TokenValidator` caches them
+/// and fetches them again when it meets a token signed with an unknown key,
+/// which is how Azure Active Directory key rotation is picked up.
+pub trait KeySetProvider: Debug + Send + Sync {
+    fn fetch_key_set(&self) -> KeySetFuture<'_>;
+}
+
+impl<T: KeySetProvider + ?Sized> KeySetProvider for Arc<T> {
+    fn fetch_key_set(&self) -> KeySetFuture<'_> {
+        (**self).fetch_key_set()
+    }
+}
+
+/// A fixed key set, for tests or for disconnected environments.
+impl KeySetProvider for JsonWebKeySet {
+    fn fetch_key_set(&self) -> KeySetFuture<'_> {
+        Box::pin(async move { Ok(self.clone()) })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct OpenIdMetadata {
+    jwks_uri: String,
+}
+
+/// Fetches the signing keys listed in the OpenID metadata of a tenant. The
+/// metadata is fetched once, the keys every time they are asked for.
+#[derive(Debug, Clone)]
+pub struct OpenIdKeySetProvider {
+    client: Arc<reqwest::Client>,
+    metadata_url: Url,
+    jwks_uri: Arc<Mutex<Option<Url>>>,
+}
+
+impl OpenIdKeySetProvider {
+    /// Uses the v2 metadata of `tenant_id`, which can also be `common` or
+    /// `organizations` for multi-tenant applications: the keys are the same
+    /// for every tenant.
+    pub fn new(client: Arc<reqwest::Client>, tenant_id: &str) -> Result<Self, AzureError> {
+        let metadata_url = Url::parse(&format!(
+            "https://login.microsoftonline.com/{}/v2.0/.well-known/openid-configuration",
+            tenant_id
+        ))
+        .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+
+        Ok(Self::with_metadata_url(client, metadata_url))
+    }
+
+    pub fn with_metadata_url(client: Arc<reqwest::Client>, metadata_url: Url) -> Self {
+        Self {
+            client,
+            metadata_url,
+            jwks_uri: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: Url) -> Result<T, AzureError> {
+        let response = self
+            .client
+            .get(url.clone())
+            .send()
+            .await
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(AzureError::GenericErrorWithText(format!(
+                "{} returned {}",
+                url,
+                response.status()
+            )));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| AzureError::GenericErrorWithText(e.to_string()))
+    }
+}
+
+impl KeySetProvider for OpenIdKeySetProvider {
+    fn fetch_key_set(&self) -> KeySetFuture<'_> {
+        Box::pin(async move {
+            let cached = self.jwks_uri.lock().unwrap().clone();
+            let jwks_uri = match cached {
+                Some(jwks_uri) => jwks_uri,
+                None => {
+                    let metadata: OpenIdMetadata = self.get_json(self.metadata_url.clone()).await?;
+                    let jwks_uri = Url::parse(&metadata.jwks_uri)
+                        .map_err(|error| AzureError::GenericErrorWithText(error.to_string()))?;
+                    *self.jwks_uri.lock().unwrap() = Some(jwks_uri.clone());
+                    jwks_uri
+                }
+            };
+
+            debug!("fetching signing keys from {}", jwks_uri);
+            self.get_json(jwks_uri).await
+        })
+    }
+}
+
+/// The claims of a validated Azure Active Directory access token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessTokenClaims {
+    pub aud: String,
+    pub iss: String,
+    /// The tenant the user or the application signed in to.
+    pub tid: String,
+    /// The object id of the user or the service principal.
+    pub oid: Option<String>,
+    pub sub: String,
+    /// The application roles granted to the caller.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// The delegated scopes granted to the client application, space
+    /// separated. Missing from application-only tokens.
+    pub scp: Option<String>,
+    /// The client application (`appid` in v1 tokens).
+    #[serde(alias = "appid")]
+    pub azp: Option<String>,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+    pub ver: Option<String>,
+    pub exp: i64,
+    pub nbf: Option<i64>,
+    pub iat: Option<i64>,
+}
+
+impl AccessTokenClaims {
+    pub fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scp.iter().flat_map(|scp| scp.split_whitespace())
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes().any(|s| s == scope)
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    /// Whether the token was issued to an application acting as itself,
+    /// rather than on behalf of a user.
+    pub fn is_app_only(&self) -> bool {
+        self.scp.is_none()
+    }
+}
+
+#[derive(Default)]
+struct SigningKeys {
+    keys: HashMap<String, DecodingKey<'static>>,
+    fetched_at: Option<Instant>,
+}
+
+/// Validates the Azure Active Directory access tokens received by an API:
+/// the RS256 signature, the audience, the expiry and not before times (with
+/// some clock skew), the tenant and the issuer matching it, for both v1
+/// and v2 tokens of the public cloud by default. Clones share the same
+/// signing keys.
+#[derive(Clone)]
+pub struct TokenValidator<P> {
+    key_set_provider: P,
+    audiences: Vec<String>,
+    tenants: Option<Vec<String>>,
+    issuers: Option<Vec<String>>,
+    clock_skew: Duration,
+    key_refresh_interval: Duration,
+    max_key_age: Duration,
+    signing_keys: Arc<AsyncMutex<SigningKeys>>,
+}
+
+impl<P: Debug> Debug for TokenValidator<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TokenValidator")
+            .field("key_set_provider", &self.key_set_provider)
+            .field("audiences", &self.audiences)
+            .field("tenants", &self.tenants)
+            .field("issuers", &self.issuers)
+            .field("clock_skew", &self.clock_skew)
+            .field("key_refresh_interval", &self.key_refresh_interval)
+            .field("max_key_age", &self.max_key_age)
+            .finish()
+    }
+}
+
+impl<P: KeySetProvider> TokenValidator<P> {
+    /// Accepts tokens issued for `audience` (the application id or the
+    /// application id URI of the API) by any tenant: call `with_tenant`
+    /// for single tenant APIs.
+    pub fn new(key_set_provider: P, audience: &str) -> Self {
+        Self {
+            key_set_provider,
+            audiences: vec![audience.to_owned()],
+            tenants: None,
+            issuers: None,
+            clock_skew: DEFAULT_CLOCK_SKEW,
+            key_refresh_interval: DEFAULT_KEY_REFRESH_INTERVAL,
+            max_key_age: DEFAULT_MAX_KEY_AGE,
+            signing_keys: Arc::new(AsyncMutex::new(SigningKeys::default())),
+        }
+    }
+
+    /// Accepts tokens issued for `audience` too.
+    pub fn with_audience(mut self, audience: &str) -> Self {
+        self.audiences.push(audience.to_owned());
+        self
+    }
+
+    /// Only accepts tokens issued by `tenant_id`, and the other tenants
+    /// passed to this method.
+    pub fn with_tenant(mut self, tenant_id: &str) -> Self {
+        self.tenants
+            .get_or_insert_with(Vec::new)
+            .push(tenant_id.to_owned());
+        self
+    }
+
+    /// Only accepts tokens issued by `issuer`, and the other issuers passed
+    /// to this method, instead of the public cloud ones. `{tenantid}` stands
+    /// for the tenant of the token, as in the `issuer` of the OpenID
+    /// metadata: national clouds use issuers like
+    /// `https://login.microsoftonline.us/{tenantid}/v2.0`.
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        self.issuers
+            .get_or_insert_with(Vec::new)
+            .push(issuer.to_owned());
+        self
+    }
+
+    pub fn with_clock_skew(self, clock_skew: Duration) -> Self {
+        Self { clock_skew, ..self }
+    }
+
+    /// The minimum time between two key set fetches caused by tokens signed
+    /// with unknown keys, so that forged tokens cannot flood the key
+    /// endpoint.
+    pub fn with_key_refresh_interval(self, key_refresh_interval: Duration) -> Self {
+        Self {
+            key_refresh_interval,
+            ..self
+        }
+    }
+
+    pub fn with_max_key_age(self, max_key_age: Duration) -> Self {
+        Self {
+            max_key_age,
+            ..self
+        }
+    }
+
+    pub async fn validate(&self, token: &str) -> Result<AccessTokenClaims, TokenValidationError> {
+        let header = jsonwebtoken::decode_header(token).map_err(invalid_token)?;
+        if header.alg != Algorithm::RS256 {
+            return Err(TokenValidationError::InvalidToken {
+                reason: format!("unexpected algorithm {:?}", header.alg),
+            });
+        }
+        let kid = header
+            .kid
+            .ok_or_else(|| TokenValidationError::InvalidToken {
+                reason: "missing kid header".to_owned(),
+            })?;
+
+        let decoding_key = self.decoding_key(&kid).await?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.leeway = self.clock_skew.as_secs();
+        validation.validate_nbf = true;
+        validation.set_audience(&self.audiences);
+
+        let claims = jsonwebtoken::decode::<AccessTokenClaims>(token, &decoding_key, &validation)
+            .map_err(invalid_token)?
+            .claims;
+
+        if let Some(tenants) = &self.tenants {
+            if !tenants.contains(&claims.tid) {
+                return Err(TokenValidationError::InvalidToken {
+                    reason: format!("tenant {} is not allowed", claims.tid),
+                });
+            }
+        }
+
+        // The keys are shared by every tenant, so the issuer must be
+        // checked against the tenant the token claims to come from.
+        let issuer_matches =
+            |issuer: &str| issuer.replace(TENANT_ID_PLACEHOLDER, &claims.tid) == claims.iss;
+        let issuer_allowed = match &self.issuers {
+            Some(issuers) => issuers.iter().any(|issuer| issuer_matches(issuer)),
+            None => DEFAULT_ISSUERS.iter().any(|issuer| issuer_matches(issuer)),
+        };
+        if !issuer_allowed {
+            return Err(TokenValidationError::InvalidToken {
+                reason: format!("issuer {} does not match tenant {}", claims.iss, claims.tid),
+            });
+        }
+
+        Ok(claims)
+    }
+
+    async fn decoding_key(&self, kid: &str) -> Result<DecodingKey<'static>, TokenValidationError> {
+        // Holding the lock while fetching makes concurrent validations
+        // share a single fetch.
+        let mut signing_keys = self.signing_keys.lock().await;
+
+        let age = signing_keys
+            .fetched_at
+            .map(|fetched_at| fetched_at.elapsed());
+        let known = signing_keys.keys.contains_key(kid);
+        let should_fetch = match age {
+            None => true,
+            Some(age) => age >= self.max_key_age || (!known && age >= self.key_refresh_interval),
+        };
+
+        if should_fetch {
+            match self.key_set_provider.fetch_key_set().await {
+                Ok(key_set) => {
+                    signing_keys.keys = key_set.decoding_keys();
+                    signing_keys.fetched_at = Some(Instant::now());
+                }
+                // keep using the keys we have until the next attempt
+                Err(error) if known => warn!("cannot refresh the signing keys: {}", error),
+                Err(error) => {
+                    return Err(TokenValidationError::SigningKeysUnavailable {
+                        reason: error.to_string(),
+                    })
+                }
+            }
+        }
+
+        signing_keys
+            .keys
+            .get(kid)
+            .cloned()
+            .ok_or_else(|| TokenValidationError::InvalidToken {
+                reason: format!("unknown signing key {}", kid),
+            })
+    }
+}
+
+fn invalid_token(error: jsonwebtoken::errors::Error) -> TokenValidationError {
+    TokenValidationError::InvalidToken {
+        reason: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake_server::fake_server;
+    use chrono::Utc;
+    use jsonwebtoken::{EncodingKey, Header};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const PRIVATE_KEY: &[u8] = include_bytes!("../tests/data/client_certificate_key.pem");
+    const JWKS: &str = include_str!("../tests/data/client_certificate_jwks.json");
+    const KID: &str = "zL_1DSDjUtTKQsFEMIEtlaQWV4Q";
+    const TENANT: &str = "72f988bf-86f1-41af-91ab-2d7cd011db47";
+    const AUDIENCE: &str = "api://my-api";
+
+    #[derive(Debug)]
+    struct CountingKeySetProvider {
+        key_set: JsonWebKeySet,
+        fetches: AtomicUsize,
+    }
+
+    impl KeySetProvider for CountingKeySetProvider {
+        fn fetch_key_set(&self) -> KeySetFuture<'_> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            self.key_set.fetch_key_set()
+        }
+    }
+
+    fn key_set() -> JsonWebKeySet {
+        serde_json::from_str(JWKS).unwrap()
+    }
+
+    fn token(kid: &str, claims: serde_json::Value) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(kid.to_owned());
+        jsonwebtoken::encode(
+            &header,
+            &claims,
+            &EncodingKey::from_rsa_pem(PRIVATE_KEY).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn claims(iss: &str, exp: i64) -> serde_json::Value {
+        serde_json::json!({
+            "aud": AUDIENCE,
+            "iss": iss,
+            "tid": TENANT,
+            "oid": "f0e1d2c3-0000-0000-0000-000000000000",
+            "sub": "subject",
+            "scp": "user_impersonation Files.Read",
+            "roles": ["Reader"],
+            "azp": "client",
+            "ver": "2.0",
+            "exp": exp,
+            "nbf": Utc::now().timestamp() - 60,
+            "iat": Utc::now().timestamp() - 60,
+        })
+    }
+
+    fn v2_issuer() -> String {
+        format!("https://login.microsoftonline.com/{}/v2.0", TENANT)
+    }
+
+    #[tokio::test]
+    async fn validates_tokens() {
+        let validator = TokenValidator::new(key_set(), AUDIENCE).with_tenant(TENANT);
+        let in_an_hour = Utc::now().timestamp() + 3600;
+
+        let validated = validator
+            .validate(&token(KID, claims(&v2_issuer(), in_an_hour)))
+            .await
+            .unwrap();
+        assert_eq!(validated.tid, TENANT);
+        assert!(validated.has_scope("Files.Read"));
+        assert!(validated.has_role("Reader"));
+        assert!(!validated.is_app_only());
+
+        // v1 tokens
+        let v1_issuer = format!("https://sts.windows.net/{}/", TENANT);
+        assert!(validator
+            .validate(&token(KID, claims(&v1_issuer, in_an_hour)))
+            .await
+            .is_ok());
+
+        // expired, but within the clock skew
+        let a_minute_ago = Utc::now().timestamp() - 60;
+        assert!(validator
+            .validate(&token(KID, claims(&v2_issuer(), a_minute_ago)))
+            .await
+            .is_ok());
+
+        let an_hour_ago = Utc::now().timestamp() - 3600;
+        let invalid_tokens = vec![
+            token(KID, claims(&v2_issuer(), an_hour_ago)),
+            token(
+                KID,
+                claims("https://login.microsoftonline.com/other/v2.0", in_an_hour),
+            ),
+            token(KID, {
+                let mut claims = claims(&v2_issuer(), in_an_hour);
+                claims["aud"] = "api://other-api".into();
+                claims
+            }),
+            token(KID, {
+                let mut claims = claims("https://login.microsoftonline.com/other/v2.0", in_an_hour);
+                claims["tid"] = "other".into();
+                claims
+            }),
+            token(KID, claims(&v2_issuer(), in_an_hour)).replace('.', ".x"),
+        ];
+        for invalid_token in invalid_tokens {
+            match validator.validate(&invalid_token).await {
+                Err(TokenValidationError::InvalidToken { .. }) => {}
+                other => panic!("unexpected validation result {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn validates_configured_issuers() {
+        let validator = TokenValidator::new(key_set(), AUDIENCE)
+            .with_issuer("https://login.microsoftonline.us/{tenantid}/v2.0");
+        let in_an_hour = Utc::now().timestamp() + 3600;
+
+        let us_issuer = format!("https://login.microsoftonline.us/{}/v2.0", TENANT);
+        assert!(validator
+            .validate(&token(KID, claims(&us_issuer, in_an_hour)))
+            .await
+            .is_ok());
+
+        // the public cloud issuers are no longer accepted
+        match validator
+            .validate(&token(KID, claims(&v2_issuer(), in_an_hour)))
+            .await
+        {
+            Err(TokenValidationError::InvalidToken { .. }) => {}
+            other => panic!("unexpected validation result {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_keys_trigger_a_throttled_refresh() {
+        let provider = Arc::new(CountingKeySetProvider {
+            key_set: key_set(),
+            fetches: AtomicUsize::new(0),
+        });
+        let validator = TokenValidator::new(provider.clone(), AUDIENCE);
+        let in_an_hour = Utc::now().timestamp() + 3600;
+
+        let valid_token = token(KID, claims(&v2_issuer(), in_an_hour));
+        validator.validate(&valid_token).await.unwrap();
+        validator.validate(&valid_token).await.unwrap();
+        assert_eq!(provider.fetches.load(Ordering::SeqCst), 1);
+
+        // a rotated key is looked up at most once per refresh interval
+        let rotated_token = token("rotated", claims(&v2_issuer(), in_an_hour));
+        assert!(validator.validate(&rotated_token).await.is_err());
+        assert_eq!(provider.fetches.load(Ordering::SeqCst), 1);
+
+        let validator = validator.with_key_refresh_interval(Duration::from_secs(0));
+        assert!(validator.validate(&rotated_token).await.is_err());
+        assert_eq!(provider.fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fetches_keys_listed_in_openid_metadata() {
+        let (jwks_url, jwks_handle) =
+            fake_server(vec![(200, JWKS.to_owned()), (200, JWKS.to_owned())]);
+        let jwks_uri = jwks_url.join("discovery/v2.0/keys").unwrap();
+        let metadata = serde_json::json!({
+            "issuer": "https://login.microsoftonline.com/{tenantid}/v2.0",
+            "jwks_uri": jwks_uri.as_str(),
+            "token_endpoint": "https://login.microsoftonline.com/common/oauth2/v2.0/token",
+        });
+        let (metadata_url, metadata_handle) = fake_server(vec![(200, metadata.to_string())]);
+
+        let provider = OpenIdKeySetProvider::with_metadata_url(
+            Arc::new(reqwest::Client::new()),
+            metadata_url
+                .join("common/v2.0/.well-known/openid-configuration")
+                .unwrap(),
+        );
+        let key_set = provider.fetch_key_set().await.unwrap();
+        assert_eq!(key_set.keys[0].kid, KID);
+
+        // the metadata is fetched once, the keys every time
+        provider.fetch_key_set().await.unwrap();
+
+        let metadata_requests = metadata_handle.join().unwrap();
+        assert!(
+            metadata_requests[0].starts_with("GET /common/v2.0/.well-known/openid-configuration ")
+        );
+        let jwks_requests = jwks_handle.join().unwrap();
+        assert_eq!(jwks_requests.len(), 2);
+        assert!(jwks_requests[1].starts_with("GET /discovery/v2.0/keys "));
+    }
+}
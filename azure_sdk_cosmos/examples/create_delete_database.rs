@@ -47,23 +47,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     {
         let db_client = client.with_database(&database_name);
 
-        let indexes = IncludedPathIndex {
-            kind: KeyKind::Hash,
-            data_type: DataType::String,
-            precision: Some(3),
-        };
-
-        let ip = IncludedPath {
-            path: "/*".to_owned(),
-            indexes: Some(vec![indexes]),
-        };
-
-        let ip = IndexingPolicy {
-            automatic: true,
-            indexing_mode: IndexingMode::Consistent,
-            included_paths: vec![ip],
-            excluded_paths: vec![],
-        };
+        let ip = IndexingPolicy::default().with_included_path("/*");
 
         let create_collection_response = db_client
             .create_collection()
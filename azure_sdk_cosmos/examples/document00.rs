@@ -85,23 +85,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         {
             collection
         } else {
-            let indexes = IncludedPathIndex {
-                kind: KeyKind::Hash,
-                data_type: DataType::String,
-                precision: Some(3),
-            };
-
-            let ip = IncludedPath {
-                path: "/*".to_owned(),
-                indexes: Some(vec![indexes]),
-            };
-
-            let ip = IndexingPolicy {
-                automatic: true,
-                indexing_mode: IndexingMode::Consistent,
-                included_paths: vec![ip],
-                excluded_paths: vec![],
-            };
+            let ip = IndexingPolicy::default().with_included_path("/*");
 
             // Notice here we specify the expected performance level.
             // Performance levels have price impact. Also, higher
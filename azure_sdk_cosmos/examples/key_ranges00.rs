@@ -22,5 +22,23 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let resp = client.get_partition_key_ranges().execute().await?;
     println!("resp == {:#?}", resp);
 
+    let partition_key = client
+        .get_collection()
+        .execute()
+        .await?
+        .collection
+        .parition_key;
+    if let Some(partition_key_value) = std::env::args().nth(3) {
+        let mut partition_keys = PartitionKeys::new();
+        partition_keys.push(&partition_key_value)?;
+
+        let range = resp.partition_key_range_for(&partition_keys, partition_key.hash_version())?;
+        println!(
+            "{} belongs to range {:?}",
+            partition_key_value,
+            range.map(|r| &r.id)
+        );
+    }
+
     Ok(())
 }
@@ -0,0 +1,265 @@
+use crate::{AuthorizationToken, AuthorizationTokenProvider};
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock, Weak};
+use std::time::Duration;
+
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+const RETRY_AFTER_FAILURE: Duration = Duration::from_secs(10);
+
+type FetchAadToken =
+    dyn Fn() -> Pin<Box<dyn Future<Output = Result<AadToken, AzureError>> + Send>> + Send + Sync;
+
+/// An Azure Active Directory access token along with its expiry, as
+/// returned by the `azure_sdk_auth_aad` login functions.
+#[derive(Clone, PartialEq)]
+pub struct AadToken {
+    access_token: String,
+    expires_on: DateTime<Utc>,
+}
+
+impl AadToken {
+    pub fn new(access_token: String, expires_on: DateTime<Utc>) -> Self {
+        Self {
+            access_token,
+            expires_on,
+        }
+    }
+
+    pub fn expires_on(&self) -> DateTime<Utc> {
+        self.expires_on
+    }
+
+    fn expires_within(&self, margin: Duration) -> bool {
+        let margin =
+            chrono::Duration::from_std(margin).unwrap_or_else(|_| chrono::Duration::zero());
+        Utc::now() + margin >= self.expires_on
+    }
+}
+
+impl std::fmt::Debug for AadToken {
+    //! We provide a custom implementation to hide the token value.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AadToken")
+            .field("access_token", &"***hidden***")
+            .field("expires_on", &self.expires_on)
+            .finish()
+    }
+}
+
+/// Signs requests with an Azure Active Directory bearer token
+/// (`type=aad&ver=1.0&sig=<token>`). The constructor fetches the first token
+/// through the supplied function and spawns a task that fetches a new one
+/// whenever the cached token gets within `refresh_margin` of its expiry, so
+/// requests never go out unsigned. The task stops once every clone of the
+/// provider has been dropped. The principal needs a Cosmos DB data plane
+/// role assignment on the account.
+///
+/// For example, using a `TokenCredential` of `azure_sdk_auth_aad`:
+///
+/// ```ignore
+/// let provider = AadTokenProvider::new(move || {
+///     let credential = credential.clone();
+///     async move {
+///         let token = credential
+///             .get_token(&["https://myaccount.documents.azure.com/.default"])
+///             .await?;
+///         Ok(AadToken::new(
+///             token.access_token().secret().to_owned(),
+///             token.expires_on,
+///         ))
+///     }
+/// })
+/// .await?;
+/// let client = client.with_token_provider(Arc::new(provider));
+/// ```
+#[derive(Clone)]
+pub struct AadTokenProvider {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    fetch_token: Box<FetchAadToken>,
+    refresh_margin: Duration,
+    token: RwLock<AadToken>,
+}
+
+impl std::fmt::Debug for AadTokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("AadTokenProvider")
+            .field("refresh_margin", &self.shared.refresh_margin)
+            .field("token", &self.shared.token)
+            .finish()
+    }
+}
+
+impl AadTokenProvider {
+    pub async fn new<F, FUT>(fetch_token: F) -> Result<Self, AzureError>
+    where
+        F: Fn() -> FUT + Send + Sync + 'static,
+        FUT: Future<Output = Result<AadToken, AzureError>> + Send + 'static,
+    {
+        Self::with_refresh_margin(fetch_token, DEFAULT_REFRESH_MARGIN).await
+    }
+
+    pub async fn with_refresh_margin<F, FUT>(
+        fetch_token: F,
+        refresh_margin: Duration,
+    ) -> Result<Self, AzureError>
+    where
+        F: Fn() -> FUT + Send + Sync + 'static,
+        FUT: Future<Output = Result<AadToken, AzureError>> + Send + 'static,
+    {
+        let token = fetch_token().await?;
+        let shared = Arc::new(Shared {
+            fetch_token: Box::new(move || Box::pin(fetch_token())),
+            refresh_margin,
+            token: RwLock::new(token),
+        });
+        tokio::spawn(keep_fresh(Arc::downgrade(&shared)));
+
+        Ok(Self { shared })
+    }
+
+    /// Returns the cached token, fetching a new one first if it is about
+    /// to expire.
+    pub async fn token(&self) -> Result<AadToken, AzureError> {
+        let cached = self.shared.token.read().unwrap().clone();
+        if cached.expires_within(self.shared.refresh_margin) {
+            self.refresh().await
+        } else {
+            Ok(cached)
+        }
+    }
+
+    /// Fetches a new token unconditionally and caches it.
+    pub async fn refresh(&self) -> Result<AadToken, AzureError> {
+        self.shared.refresh().await
+    }
+}
+
+impl Shared {
+    async fn refresh(&self) -> Result<AadToken, AzureError> {
+        debug!("refreshing Azure Active Directory token");
+
+        let token = (self.fetch_token)().await?;
+        *self.token.write().unwrap() = token.clone();
+        Ok(token)
+    }
+
+    fn refresh_in(&self) -> Duration {
+        (self.token.read().unwrap().expires_on - Utc::now())
+            .to_std()
+            .unwrap_or_default()
+            .checked_sub(self.refresh_margin)
+            .unwrap_or_default()
+    }
+}
+
+/// Refreshes the token ahead of its expiry for as long as a provider is
+/// alive. Only a weak reference is kept while waiting, so that dropping the
+/// providers ends the task.
+async fn keep_fresh(shared: Weak<Shared>) {
+    loop {
+        let wait = match shared.upgrade() {
+            Some(shared) => shared.refresh_in(),
+            None => return,
+        };
+        tokio::time::delay_for(wait).await;
+
+        let shared = match shared.upgrade() {
+            Some(shared) => shared,
+            None => return,
+        };
+        if let Err(error) = shared.refresh().await {
+            warn!("Azure Active Directory token refresh failed: {}", error);
+            tokio::time::delay_for(RETRY_AFTER_FAILURE).await;
+        }
+    }
+}
+
+impl AuthorizationTokenProvider for AadTokenProvider {
+    fn authorization_token(&self, _resource_link: &str) -> Result<AuthorizationToken, AzureError> {
+        let token = self.shared.token.read().unwrap();
+        if token.expires_within(Duration::default()) {
+            warn!(
+                "Azure Active Directory token expired on {}, the refresh keeps failing",
+                token.expires_on
+            );
+        }
+        Ok(AuthorizationToken::new_aad(token.access_token.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting_provider(
+        fetches: &Arc<AtomicUsize>,
+        lifetime: chrono::Duration,
+    ) -> impl Fn() -> futures::future::Ready<Result<AadToken, AzureError>> + Send + Sync {
+        let fetches = fetches.clone();
+        move || {
+            let fetch = fetches.fetch_add(1, Ordering::SeqCst);
+            futures::future::ready(Ok(AadToken::new(
+                format!("token{}", fetch),
+                Utc::now() + lifetime,
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn first_token_is_fetched_on_construction() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let provider =
+            AadTokenProvider::new(counting_provider(&fetches, chrono::Duration::minutes(60)))
+                .await
+                .unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            provider.authorization_token("dbs/db").unwrap(),
+            AuthorizationToken::new_aad("token0".to_owned())
+        );
+
+        provider.token().await.unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn construction_fails_without_a_token() {
+        let provider = AadTokenProvider::new(|| async {
+            Err(AzureError::GenericErrorWithText("no token".to_owned()))
+        })
+        .await;
+        assert!(provider.is_err());
+    }
+
+    #[tokio::test]
+    async fn token_is_refreshed_in_the_background() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let provider = AadTokenProvider::with_refresh_margin(
+            counting_provider(&fetches, chrono::Duration::milliseconds(100)),
+            Duration::from_millis(50),
+        )
+        .await
+        .unwrap();
+
+        tokio::time::delay_for(Duration::from_millis(120)).await;
+        assert!(fetches.load(Ordering::SeqCst) >= 2);
+        assert_ne!(
+            provider.authorization_token("dbs/db").unwrap(),
+            AuthorizationToken::new_aad("token0".to_owned())
+        );
+
+        // the background task stops with the last provider
+        drop(provider);
+        tokio::time::delay_for(Duration::from_millis(60)).await;
+        let after_drop = fetches.load(Ordering::SeqCst);
+        tokio::time::delay_for(Duration::from_millis(120)).await;
+        assert_eq!(fetches.load(Ordering::SeqCst), after_drop);
+    }
+}
@@ -1,5 +1,7 @@
 mod attachment_name;
 
+use azure_sdk_core::errors::AzureError;
+
 pub use self::attachment_name::AttachmentName;
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -22,3 +24,34 @@ impl crate::attachment::AttachmentName for Attachment {
         &self.id
     }
 }
+
+// Extracts the media id from the media link of an attachment, such as
+// /media/Sn4dAIF3XQABAAAAAAAAAAEAAAAAAAAA. Reference attachments point to
+// external media that must be fetched directly.
+pub(crate) fn media_id(media: &str) -> Result<&str, AzureError> {
+    let media_id = media.trim_start_matches('/');
+    if media_id.starts_with("media/") && media_id.len() > "media/".len() {
+        Ok(&media_id["media/".len()..])
+    } else {
+        Err(AzureError::InputParametersError(format!(
+            "media {} is not stored in Cosmos DB",
+            media
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_media_id() {
+        assert_eq!(
+            media_id("/media/Sn4dAIF3XQABAAAAAAAAAAEAAAAAAAAA").unwrap(),
+            "Sn4dAIF3XQABAAAAAAAAAAEAAAAAAAAA"
+        );
+        assert_eq!(media_id("media/abc").unwrap(), "abc");
+        assert!(media_id("https://www.bing.com/image.png").is_err());
+        assert!(media_id("/media/").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::PermissionToken;
+use azure_sdk_core::errors::AzureError;
 use base64;
 use std::fmt::{Debug, Error, Formatter};
 
@@ -6,6 +7,7 @@ use std::fmt::{Debug, Error, Formatter};
 pub enum AuthorizationToken {
     Master(Vec<u8>),
     Resource(String),
+    AzureActiveDirectory(String),
 }
 
 impl AuthorizationToken {
@@ -17,6 +19,12 @@ impl AuthorizationToken {
     pub fn new_resource(resource: String) -> AuthorizationToken {
         AuthorizationToken::Resource(resource)
     }
+
+    /// Wraps an Azure Active Directory access token issued for the
+    /// `https://<account>.documents.azure.com` resource.
+    pub fn new_aad(access_token: String) -> AuthorizationToken {
+        AuthorizationToken::AzureActiveDirectory(access_token)
+    }
 }
 
 impl Debug for AuthorizationToken {
@@ -28,11 +36,27 @@ impl Debug for AuthorizationToken {
             match self {
                 AuthorizationToken::Master(_) => "AuthorizationToken::Master(***hidden***)",
                 AuthorizationToken::Resource(_) => "AuthorizationToken::Resource(***hidden***)",
+                AuthorizationToken::AzureActiveDirectory(_) =>
+                    "AuthorizationToken::AzureActiveDirectory(***hidden***)",
             }
         )
     }
 }
 
+/// Supplies the token used to sign a request. The provider is consulted
+/// for every request, so implementations can rotate tokens or pick a
+/// different one depending on the resource being accessed. An error fails
+/// the request before it is sent.
+pub trait AuthorizationTokenProvider: Debug + Send + Sync {
+    fn authorization_token(&self, resource_link: &str) -> Result<AuthorizationToken, AzureError>;
+}
+
+impl AuthorizationTokenProvider for AuthorizationToken {
+    fn authorization_token(&self, _resource_link: &str) -> Result<AuthorizationToken, AzureError> {
+        Ok(self.clone())
+    }
+}
+
 impl std::convert::From<PermissionToken> for AuthorizationToken {
     fn from(permission_token: PermissionToken) -> Self {
         trace!(
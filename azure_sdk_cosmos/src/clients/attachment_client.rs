@@ -1,11 +1,12 @@
 use crate::attachment::AttachmentName;
-use crate::clients::{Client, CosmosUriBuilder, DocumentClient, ResourceType};
+use crate::clients::{Client, CosmosUriBuilder, DocumentClient, HttpClient, ResourceType};
 use crate::collection::CollectionName;
 use crate::database::DatabaseName;
 use crate::document::DocumentName;
 use crate::requests;
 use crate::DocumentTrait;
 use crate::{AttachmentBuilderTrait, AttachmentTrait};
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 
 #[derive(Debug, Clone)]
@@ -39,11 +40,31 @@ where
         &self.document_client
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
+
+    // Returns the id of the media of the attachment, reading the attachment
+    // first if the media link is not known.
+    pub(crate) async fn media_id(&self, media: Option<&str>) -> Result<String, AzureError> {
+        let media = match media {
+            Some(media) => media.to_owned(),
+            None => self.get().execute().await?.attachment.media,
+        };
+        Ok(crate::attachment::media_id(&media)?.to_owned())
+    }
+
+    pub(crate) fn prepare_media_request(
+        &self,
+        media_id: &str,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError> {
+        self.main_client().prepare_request(
+            &format!("media/{}", media_id),
+            method,
+            ResourceType::Media,
+        )
+    }
 }
 
 impl<'a, CUB> AttachmentTrait<'a, CUB> for AttachmentClient<'a, CUB>
@@ -91,13 +112,21 @@ where
     fn get(&self) -> requests::GetAttachmentBuilder<'_, '_, CUB> {
         requests::GetAttachmentBuilder::new(self)
     }
+
+    fn get_media(&self) -> requests::GetMediaBuilder<'_, '_, CUB> {
+        requests::GetMediaBuilder::new(self)
+    }
+
+    fn replace_media(&self) -> requests::ReplaceMediaBuilder<'_, '_, CUB, No> {
+        requests::ReplaceMediaBuilder::new(self)
+    }
 }
 
 impl<'a, CUB> AttachmentBuilderTrait<'a, CUB> for AttachmentClient<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder {
+    fn prepare_request(&self, method: hyper::Method) -> Result<http::request::Builder, AzureError> {
         self.main_client().prepare_request(
             &format!(
                 "dbs/{}/colls/{}/docs/{}/attachments/{}",
@@ -1,8 +1,10 @@
-use crate::clients::DatabaseClient;
+use crate::clients::{DatabaseClient, HttpClient};
 use crate::database::DatabaseName;
 use crate::headers::*;
 use crate::requests;
-use crate::{requests::*, AuthorizationToken, CosmosTrait};
+use crate::{
+    requests::*, AuthorizationToken, AuthorizationTokenProvider, CosmosTrait, DiagnosticsContext,
+};
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 use base64;
@@ -12,9 +14,9 @@ use hyper::{
     self,
     header::{self, HeaderValue},
 };
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::borrow::Cow;
+use std::sync::Arc;
 use url::form_urlencoded;
 
 const AZURE_VERSION: &str = "2018-12-31";
@@ -34,6 +36,8 @@ pub(crate) enum ResourceType {
     PartitionKeyRanges,
     UserDefinedFunctions,
     Triggers,
+    Conflicts,
+    Media,
 }
 
 pub trait CosmosUriBuilder {
@@ -45,9 +49,9 @@ pub struct Client<CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    hyper_client: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    hyper_client: HttpClient,
     account: String,
-    auth_token: AuthorizationToken,
+    auth_token: Arc<dyn AuthorizationTokenProvider>,
     cosmos_uri_builder: CUB,
 }
 
@@ -56,6 +60,10 @@ where
     CUB: CosmosUriBuilder + Clone,
 {
     pub fn with_auth_token(&self, auth_token: AuthorizationToken) -> Self {
+        self.with_token_provider(Arc::new(auth_token))
+    }
+
+    pub fn with_token_provider(&self, auth_token: Arc<dyn AuthorizationTokenProvider>) -> Self {
         Self {
             hyper_client: self.hyper_client.clone(),
             account: self.account.clone(),
@@ -63,6 +71,20 @@ where
             cosmos_uri_builder: self.cosmos_uri_builder.clone(),
         }
     }
+
+    /// Returns a client recording the diagnostics of every request it
+    /// sends, including the ones of the clients and builders created from
+    /// it, into `diagnostics_context`.
+    pub fn with_diagnostics_context(&self, diagnostics_context: DiagnosticsContext) -> Self {
+        Self {
+            hyper_client: self
+                .hyper_client
+                .with_diagnostics_context(diagnostics_context),
+            account: self.account.clone(),
+            auth_token: self.auth_token.clone(),
+            cosmos_uri_builder: self.cosmos_uri_builder.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -122,13 +144,13 @@ impl ClientBuilder {
         account: String,
         auth_token: AuthorizationToken,
     ) -> Result<Client<DefaultCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = HttpClient::new();
         let cosmos_uri_builder = DefaultCosmosUri::new(&account);
 
         Ok(Client {
             hyper_client: client,
             account,
-            auth_token,
+            auth_token: Arc::new(auth_token),
             cosmos_uri_builder,
         })
     }
@@ -137,13 +159,13 @@ impl ClientBuilder {
         account: String,
         auth_token: AuthorizationToken,
     ) -> Result<Client<ChinaCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = HttpClient::new();
         let cosmos_uri_builder = ChinaCosmosUri::new(&account);
 
         Ok(Client {
             hyper_client: client,
             account,
-            auth_token,
+            auth_token: Arc::new(auth_token),
             cosmos_uri_builder,
         })
     }
@@ -153,18 +175,18 @@ impl ClientBuilder {
         auth_token: AuthorizationToken,
         uri: String,
     ) -> Result<Client<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = HttpClient::new();
 
         Ok(Client {
             hyper_client: client,
             account,
-            auth_token,
+            auth_token: Arc::new(auth_token),
             cosmos_uri_builder: CustomCosmosUri { uri },
         })
     }
 
     pub fn new_emulator(address: &str, port: u16) -> Result<Client<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = HttpClient::new();
 
         //Account name: localhost:<port>
         //Account key: C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==
@@ -174,7 +196,7 @@ impl ClientBuilder {
         Ok(Client {
             hyper_client: client,
             account: format!("{}:{}", address, port),
-            auth_token,
+            auth_token: Arc::new(auth_token),
             cosmos_uri_builder: CustomCosmosUri {
                 uri: format!("https://{}:{}", address, port),
             },
@@ -207,9 +229,7 @@ where
     CUB: CosmosUriBuilder,
 {
     #[inline]
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         &self.hyper_client
     }
 
@@ -219,20 +239,27 @@ where
         uri_path: &str,
         http_method: hyper::Method,
         resource_type: ResourceType,
-    ) -> RequestBuilder {
+    ) -> Result<RequestBuilder, AzureError> {
         let time = format!("{}", chrono::Utc::now().format(TIME_FORMAT));
 
         let auth = {
-            let resource_link = generate_resource_link(&uri_path);
+            let resource_link = match resource_type {
+                // media are addressed by resource id, which is signed
+                // lowercased
+                ResourceType::Media => {
+                    Cow::Owned(uri_path.trim_start_matches("media/").to_lowercase())
+                }
+                _ => Cow::Borrowed(generate_resource_link(&uri_path)),
+            };
             generate_authorization(
-                &self.auth_token,
+                &self.auth_token.authorization_token(&resource_link)?,
                 &http_method,
                 resource_type,
-                resource_link,
+                &resource_link,
                 &time,
             )
         };
-        self.prepare_request_with_signature(uri_path, http_method, &time, &auth)
+        Ok(self.prepare_request_with_signature(uri_path, http_method, &time, &auth))
     }
 
     #[inline]
@@ -276,11 +303,13 @@ fn generate_authorization(
         match auth_token {
             AuthorizationToken::Master(_) => "master",
             AuthorizationToken::Resource(_) => "resource",
+            AuthorizationToken::AzureActiveDirectory(_) => "aad",
         },
         VERSION,
         match auth_token {
             AuthorizationToken::Master(key) => Cow::Owned(encode_str_to_sign(&string_to_sign, key)),
             AuthorizationToken::Resource(key) => Cow::Borrowed(key),
+            AuthorizationToken::AzureActiveDirectory(access_token) => Cow::Borrowed(access_token),
         },
     );
 
@@ -338,6 +367,8 @@ fn string_to_sign(
             ResourceType::PartitionKeyRanges => "pkranges",
             ResourceType::UserDefinedFunctions => "udfs",
             ResourceType::Triggers => "triggers",
+            ResourceType::Conflicts => "conflicts",
+            ResourceType::Media => "media",
         },
         resource_link,
         time.to_lowercase()
@@ -356,6 +387,7 @@ fn generate_resource_link(u: &str) -> &str {
         "pkranges",
         "udfs",
         "triggers",
+        "conflicts",
     ];
 
     // store the element only if it does not end with dbs, colls or docs
@@ -467,6 +499,29 @@ mon, 01 jan 1900 01:00:00 gmt
         );
     }
 
+    #[test]
+    fn generate_authorization_aad() {
+        let time =
+            chrono::DateTime::parse_from_rfc3339("2017-04-27T00:51:12.000000000+00:00").unwrap();
+        let time = time.with_timezone(&chrono::Utc);
+        let time = format!("{}", time.format(TIME_FORMAT));
+
+        let auth_token = AuthorizationToken::new_aad("eyJ0eXAi.eyJhdWQi.c2lnbmF0dXJl".to_owned());
+
+        let ret = generate_authorization(
+            &auth_token,
+            &hyper::Method::GET,
+            ResourceType::Databases,
+            "dbs/ToDoList",
+            &time,
+        );
+
+        assert_eq!(
+            ret,
+            "type%3Daad%26ver%3D1.0%26sig%3DeyJ0eXAi.eyJhdWQi.c2lnbmF0dXJl"
+        );
+    }
+
     #[test]
     fn generate_resource_link_00() {
         assert_eq!(generate_resource_link("dbs/second"), "dbs/second");
@@ -1,6 +1,6 @@
 use crate::clients::{
-    Client, CosmosUriBuilder, DatabaseClient, DocumentClient, ResourceType, StoredProcedureClient,
-    TriggerClient, UserDefinedFunctionClient,
+    Client, CosmosUriBuilder, DatabaseClient, DocumentClient, HttpClient, ResourceType,
+    StoredProcedureClient, TriggerClient, UserDefinedFunctionClient,
 };
 use crate::collection::CollectionName;
 use crate::database::DatabaseName;
@@ -9,9 +9,11 @@ use crate::requests;
 use crate::stored_procedure::StoredProcedureName;
 use crate::trigger::TriggerName;
 use crate::user_defined_function::UserDefinedFunctionName;
-use crate::{CollectionBuilderTrait, CollectionTrait, DatabaseTrait, PartitionKeys};
+use crate::{CollectionBuilderTrait, CollectionTrait, CosmosEntity, DatabaseTrait, PartitionKeys};
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 use serde::Serialize;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct CollectionClient<'a, CUB>
@@ -40,9 +42,7 @@ where
         self.database_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -93,6 +93,17 @@ where
         requests::QueryDocumentsBuilder::new(self)
     }
 
+    fn transactional_batch(&self) -> requests::TransactionalBatchBuilder<'_, '_, CUB, No, No> {
+        requests::TransactionalBatchBuilder::new(self)
+    }
+
+    fn bulk_import<T>(&self) -> requests::BulkImportBuilder<'_, '_, T, CUB, No>
+    where
+        T: Serialize,
+    {
+        requests::BulkImportBuilder::new(self)
+    }
+
     fn with_stored_procedure<'c>(
         &'c self,
         stored_procedure_name: &'c dyn StoredProcedureName,
@@ -129,12 +140,35 @@ where
         requests::GetPartitionKeyRangesBuilder::new(self)
     }
 
+    fn list_conflicts(&self) -> requests::ListConflictsBuilder<'_, '_, CUB> {
+        requests::ListConflictsBuilder::new(self)
+    }
+
+    fn get_conflict(&self) -> requests::GetConflictBuilder<'_, '_, CUB, No, No> {
+        requests::GetConflictBuilder::new(self)
+    }
+
+    fn delete_conflict(&self) -> requests::DeleteConflictBuilder<'_, '_, CUB, No, No> {
+        requests::DeleteConflictBuilder::new(self)
+    }
+
     fn with_document<'c>(
         &'c self,
         document_name: &'c dyn DocumentName,
         partition_keys: &'c PartitionKeys,
     ) -> DocumentClient<'c, CUB> {
-        DocumentClient::new(&self, document_name, partition_keys)
+        DocumentClient::new(&self, document_name, Cow::Borrowed(partition_keys))
+    }
+
+    fn with_entity<'c, E>(&'c self, entity: &'c E) -> Result<DocumentClient<'c, CUB>, AzureError>
+    where
+        E: CosmosEntity + std::fmt::Debug,
+    {
+        Ok(DocumentClient::new(
+            self,
+            entity,
+            Cow::Owned(entity.partition_keys()?),
+        ))
     }
 }
 
@@ -142,7 +176,7 @@ impl<'a, CUB> CollectionBuilderTrait<'a, CUB> for CollectionClient<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder {
+    fn prepare_request(&self, method: hyper::Method) -> Result<http::request::Builder, AzureError> {
         self.main_client().prepare_request(
             &format!(
                 "dbs/{}/colls/{}",
@@ -1,8 +1,11 @@
-use crate::clients::{Client, CollectionClient, CosmosUriBuilder, ResourceType, UserClient};
+use crate::clients::{
+    Client, CollectionClient, CosmosUriBuilder, HttpClient, ResourceType, UserClient,
+};
 use crate::database::DatabaseName;
 use crate::DatabaseBuilderTrait;
 use crate::{requests, UserName};
 use crate::{CollectionName, DatabaseTrait};
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 
 #[derive(Debug, Clone)]
@@ -29,9 +32,7 @@ where
         self.main_client
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -80,7 +81,7 @@ impl<'a, CUB> DatabaseBuilderTrait<'a, CUB> for DatabaseClient<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder {
+    fn prepare_request(&self, method: hyper::Method) -> Result<http::request::Builder, AzureError> {
         self.main_client().prepare_request(
             &format!("dbs/{}", self.database_name().name()),
             method,
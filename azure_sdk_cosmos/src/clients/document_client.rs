@@ -1,11 +1,16 @@
 use crate::attachment::AttachmentName;
-use crate::clients::{AttachmentClient, Client, CollectionClient, CosmosUriBuilder, ResourceType};
+use crate::clients::{
+    AttachmentClient, Client, CollectionClient, CosmosUriBuilder, HttpClient, ResourceType,
+};
 use crate::collection::CollectionName;
 use crate::database::DatabaseName;
 use crate::document::DocumentName;
 use crate::requests;
 use crate::CollectionTrait;
 use crate::{DocumentBuilderTrait, DocumentTrait, PartitionKeys};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::No;
+use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct DocumentClient<'a, CUB>
@@ -14,7 +19,7 @@ where
 {
     collection_client: &'a CollectionClient<'a, CUB>,
     document_name: &'a dyn DocumentName,
-    partition_keys: &'a PartitionKeys,
+    partition_keys: Cow<'a, PartitionKeys>,
 }
 
 impl<'a, CUB> DocumentClient<'a, CUB>
@@ -24,7 +29,7 @@ where
     pub(crate) fn new(
         collection_client: &'a CollectionClient<'a, CUB>,
         document_name: &'a dyn DocumentName,
-        partition_keys: &'a PartitionKeys,
+        partition_keys: Cow<'a, PartitionKeys>,
     ) -> Self {
         Self {
             collection_client,
@@ -33,13 +38,15 @@ where
         }
     }
 
+    pub(crate) fn collection_client(&self) -> &'a CollectionClient<'a, CUB> {
+        self.collection_client
+    }
+
     pub(crate) fn main_client(&self) -> &Client<CUB> {
         self.collection_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -60,8 +67,8 @@ where
         self.document_name
     }
 
-    fn partition_keys(&self) -> &'a PartitionKeys {
-        self.partition_keys
+    fn partition_keys(&self) -> &PartitionKeys {
+        &self.partition_keys
     }
 
     fn get_document(&self) -> requests::GetDocumentBuilder<'_, '_, CUB> {
@@ -72,6 +79,14 @@ where
         requests::DeleteDocumentBuilder::new(self)
     }
 
+    fn patch_document(&self) -> requests::PatchDocumentBuilder<'_, '_, CUB, No> {
+        requests::PatchDocumentBuilder::new(self)
+    }
+
+    fn update_document(&self) -> requests::UpdateDocumentBuilder<'_, CUB> {
+        requests::UpdateDocumentBuilder::new(self)
+    }
+
     fn with_attachment(
         &'a self,
         attachment_name: &'a dyn AttachmentName,
@@ -88,7 +103,7 @@ impl<'a, CUB> DocumentBuilderTrait<'a, CUB> for DocumentClient<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder {
+    fn prepare_request(&self, method: hyper::Method) -> Result<http::request::Builder, AzureError> {
         self.main_client().prepare_request(
             &format!(
                 "dbs/{}/colls/{}/docs/{}",
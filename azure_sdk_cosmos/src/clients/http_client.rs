@@ -0,0 +1,74 @@
+use crate::diagnostics::{is_retry, DiagnosticsContext, RequestDiagnostics};
+use crate::headers::{HEADER_ACTIVITY_ID, HEADER_REQUEST_CHARGE};
+use hyper::{Body, Request, Response};
+use hyper_rustls::HttpsConnector;
+use std::future::Future;
+use std::time::Instant;
+
+/// The hyper client shared by the Cosmos clients. Every response goes
+/// through the diagnostics context of the request builder or of the client,
+/// if any.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpClient {
+    hyper_client: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    diagnostics_context: Option<DiagnosticsContext>,
+}
+
+impl HttpClient {
+    pub(crate) fn new() -> Self {
+        Self {
+            hyper_client: hyper::Client::builder().build(HttpsConnector::new()),
+            diagnostics_context: None,
+        }
+    }
+
+    pub(crate) fn with_diagnostics_context(&self, diagnostics_context: DiagnosticsContext) -> Self {
+        Self {
+            hyper_client: self.hyper_client.clone(),
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+
+    pub(crate) fn request(
+        &self,
+        request: Request<Body>,
+    ) -> impl Future<Output = Result<Response<Body>, hyper::Error>> {
+        // a context set on the builder wins over the one of the client
+        let diagnostics_context = request
+            .extensions()
+            .get::<DiagnosticsContext>()
+            .or(self.diagnostics_context.as_ref())
+            .cloned();
+        let method = request.method().clone();
+        let path = request.uri().path().to_owned();
+        let response = self.hyper_client.request(request);
+
+        async move {
+            let started = Instant::now();
+            let response = response.await;
+
+            if let Some(diagnostics_context) = diagnostics_context {
+                let headers = response.as_ref().ok().map(|response| response.headers());
+                let header = |name: &str| {
+                    headers
+                        .and_then(|headers| headers.get(name))
+                        .and_then(|value| value.to_str().ok())
+                };
+
+                diagnostics_context.record(RequestDiagnostics {
+                    method,
+                    path,
+                    status_code: response.as_ref().ok().map(|response| response.status()),
+                    activity_id: header(HEADER_ACTIVITY_ID).and_then(|s| s.parse().ok()),
+                    request_charge: header(HEADER_REQUEST_CHARGE)
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or_default(),
+                    latency: started.elapsed(),
+                    is_retry: is_retry(),
+                });
+            }
+
+            response
+        }
+    }
+}
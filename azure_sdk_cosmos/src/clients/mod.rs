@@ -3,6 +3,7 @@ mod client;
 mod collection_client;
 mod database_client;
 mod document_client;
+mod http_client;
 mod permission_client;
 mod stored_procedure_client;
 mod trigger_client;
@@ -13,6 +14,7 @@ pub use client::*;
 pub use collection_client::CollectionClient;
 pub use database_client::DatabaseClient;
 pub use document_client::DocumentClient;
+pub(crate) use http_client::HttpClient;
 pub use permission_client::PermissionClient;
 pub use stored_procedure_client::StoredProcedureClient;
 pub use trigger_client::TriggerClient;
@@ -1,4 +1,4 @@
-use crate::clients::{Client, CosmosUriBuilder, UserClient};
+use crate::clients::{Client, CosmosUriBuilder, HttpClient, UserClient};
 use crate::database::DatabaseName;
 use crate::{requests, PermissionName, PermissionResource, PermissionTrait, UserName, UserTrait};
 use azure_sdk_core::No;
@@ -30,9 +30,7 @@ where
         self.user_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
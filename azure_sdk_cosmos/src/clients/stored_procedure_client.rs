@@ -1,9 +1,10 @@
-use crate::clients::{Client, CollectionClient, CosmosUriBuilder, ResourceType};
+use crate::clients::{Client, CollectionClient, CosmosUriBuilder, HttpClient, ResourceType};
 use crate::collection::CollectionName;
 use crate::database::DatabaseName;
 use crate::requests;
 use crate::stored_procedure::StoredProcedureName;
 use crate::{CollectionTrait, StoredProcedureBuilderTrait, StoredProcedureTrait};
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 
 #[derive(Debug, Clone)]
@@ -33,9 +34,7 @@ where
         self.collection_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -77,7 +76,7 @@ impl<'a, CUB> StoredProcedureBuilderTrait<'a, CUB> for StoredProcedureClient<'a,
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder {
+    fn prepare_request(&self, method: hyper::Method) -> Result<http::request::Builder, AzureError> {
         self.main_client().prepare_request(
             &format!(
                 "dbs/{}/colls/{}/sprocs/{}",
@@ -1,4 +1,4 @@
-use crate::clients::{Client, CosmosUriBuilder, DatabaseClient, PermissionClient};
+use crate::clients::{Client, CosmosUriBuilder, DatabaseClient, HttpClient, PermissionClient};
 use crate::database::DatabaseName;
 use crate::{requests, DatabaseTrait, PermissionName, UserName, UserTrait};
 use azure_sdk_core::No;
@@ -30,9 +30,7 @@ where
         self.database_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -1,9 +1,10 @@
-use crate::clients::{Client, CollectionClient, CosmosUriBuilder, ResourceType};
+use crate::clients::{Client, CollectionClient, CosmosUriBuilder, HttpClient, ResourceType};
 use crate::collection::CollectionName;
 use crate::database::DatabaseName;
 use crate::requests;
 use crate::user_defined_function::UserDefinedFunctionName;
 use crate::{CollectionTrait, UserDefinedFunctionBuilderTrait, UserDefinedFunctionTrait};
+use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 
 #[derive(Debug, Clone)]
@@ -33,9 +34,7 @@ where
         self.collection_client.main_client()
     }
 
-    pub(crate) fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
+    pub(crate) fn hyper_client(&self) -> &HttpClient {
         self.main_client().hyper_client()
     }
 }
@@ -81,7 +80,7 @@ where
         &self,
         method: hyper::Method,
         specify_user_defined_function_name: bool,
-    ) -> http::request::Builder {
+    ) -> Result<http::request::Builder, AzureError> {
         if specify_user_defined_function_name {
             self.main_client().prepare_request(
                 &format!(
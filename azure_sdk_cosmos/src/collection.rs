@@ -1,3 +1,5 @@
+use crate::PartitionKeyHashVersion;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
 pub enum KeyKind {
     Hash,
@@ -23,6 +25,7 @@ pub enum IndexingMode {
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+#[allow(deprecated)]
 pub struct IncludedPath {
     #[serde(rename = "path")]
     pub path: String,
@@ -31,6 +34,20 @@ pub struct IncludedPath {
     pub indexes: Option<Vec<IncludedPathIndex>>,
 }
 
+impl std::convert::From<String> for IncludedPath {
+    fn from(s: String) -> Self {
+        Self {
+            path: s,
+            indexes: None,
+        }
+    }
+}
+
+// Legacy index definition: the service ignores the precision and the kind
+// and indexes every path with the maximum precision.
+#[deprecated(
+    note = "the service ignores the index kind and precision: include the path without indexes"
+)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
 pub struct IncludedPathIndex {
     #[serde(rename = "dataType")]
@@ -54,10 +71,144 @@ impl std::convert::From<String> for ExcludedPath {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, PartialEq)]
+pub enum CompositeIndexOrder {
+    #[serde(rename = "ascending")]
+    Ascending,
+    #[serde(rename = "descending")]
+    Descending,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct CompositeIndexPath {
+    pub path: String,
+    pub order: CompositeIndexOrder,
+}
+
+impl CompositeIndexPath {
+    pub fn new<P: Into<String>>(path: P, order: CompositeIndexOrder) -> Self {
+        Self {
+            path: path.into(),
+            order,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, PartialEq)]
+pub enum SpatialType {
+    Point,
+    Polygon,
+    LineString,
+    MultiPolygon,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct SpatialIndex {
+    pub path: String,
+    pub types: Vec<SpatialType>,
+}
+
+impl SpatialIndex {
+    pub fn new<P: Into<String>>(path: P, types: Vec<SpatialType>) -> Self {
+        Self {
+            path: path.into(),
+            types,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct UniqueKey {
+    pub paths: Vec<String>,
+}
+
+impl<T> std::convert::From<T> for UniqueKey
+where
+    T: AsRef<str>,
+{
+    fn from(t: T) -> Self {
+        Self {
+            paths: vec![t.as_ref().to_owned()],
+        }
+    }
+}
+
+// Unique keys can only be specified when the collection is created.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialOrd, PartialEq)]
+pub struct UniqueKeyPolicy {
+    #[serde(rename = "uniqueKeys")]
+    pub unique_keys: Vec<UniqueKey>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, PartialEq)]
+pub enum ConflictResolutionMode {
+    LastWriterWins,
+    Custom,
+}
+
+// Used by multi-master accounts. With LastWriterWins the conflicts are
+// resolved using the numeric property in conflict_resolution_path (_ts by
+// default), with Custom using the stored procedure in
+// conflict_resolution_procedure. If there is no procedure the conflicts
+// are stored in the conflicts feed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
+pub struct ConflictResolutionPolicy {
+    pub mode: ConflictResolutionMode,
+    #[serde(rename = "conflictResolutionPath")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub conflict_resolution_path: Option<String>,
+    #[serde(rename = "conflictResolutionProcedure")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub conflict_resolution_procedure: Option<String>,
+}
+
+impl ConflictResolutionPolicy {
+    pub fn last_writer_wins<P: Into<String>>(conflict_resolution_path: P) -> Self {
+        Self {
+            mode: ConflictResolutionMode::LastWriterWins,
+            conflict_resolution_path: Some(conflict_resolution_path.into()),
+            conflict_resolution_procedure: None,
+        }
+    }
+
+    pub fn custom<P: Into<String>>(conflict_resolution_procedure: P) -> Self {
+        Self {
+            mode: ConflictResolutionMode::Custom,
+            conflict_resolution_path: None,
+            conflict_resolution_procedure: Some(conflict_resolution_procedure.into()),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
 pub struct PartitionKey {
     pub paths: Vec<String>,
     pub kind: KeyKind,
+    // hash version: 1 (the default) or 2 for large partition keys
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub version: Option<u8>,
+}
+
+impl PartitionKey {
+    /// Partition key using the hash version 2, needed for values longer
+    /// than 100 bytes.
+    pub fn new_v2<T: AsRef<str>>(path: T) -> Self {
+        Self {
+            paths: vec![path.as_ref().to_owned()],
+            kind: KeyKind::Hash,
+            version: Some(2),
+        }
+    }
+
+    pub fn hash_version(&self) -> PartitionKeyHashVersion {
+        match self.version {
+            Some(2) => PartitionKeyHashVersion::V2,
+            _ => PartitionKeyHashVersion::V1,
+        }
+    }
 }
 
 impl std::default::Default for PartitionKey {
@@ -65,6 +216,7 @@ impl std::default::Default for PartitionKey {
         Self {
             paths: vec![],
             kind: KeyKind::Hash,
+            version: None,
         }
     }
 }
@@ -77,6 +229,7 @@ where
         Self {
             paths: vec![t.as_ref().to_owned()],
             kind: KeyKind::Hash,
+            version: None,
         }
     }
 }
@@ -91,6 +244,60 @@ pub struct IndexingPolicy {
     pub included_paths: Vec<IncludedPath>,
     #[serde(rename = "excludedPaths")]
     pub excluded_paths: Vec<ExcludedPath>,
+    // every inner vector is a composite index, used by ORDER BY on
+    // multiple properties
+    #[serde(rename = "compositeIndexes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub composite_indexes: Vec<Vec<CompositeIndexPath>>,
+    #[serde(rename = "spatialIndexes")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub spatial_indexes: Vec<SpatialIndex>,
+}
+
+// Automatic and consistent indexing. With no included path the service
+// indexes every path.
+impl std::default::Default for IndexingPolicy {
+    fn default() -> Self {
+        Self {
+            automatic: true,
+            indexing_mode: IndexingMode::Consistent,
+            included_paths: vec![],
+            excluded_paths: vec![],
+            composite_indexes: vec![],
+            spatial_indexes: vec![],
+        }
+    }
+}
+
+impl IndexingPolicy {
+    pub fn with_indexing_mode(self, indexing_mode: IndexingMode) -> Self {
+        Self {
+            indexing_mode,
+            ..self
+        }
+    }
+
+    pub fn with_included_path<P: Into<String>>(mut self, path: P) -> Self {
+        self.included_paths.push(path.into().into());
+        self
+    }
+
+    pub fn with_excluded_path<P: Into<String>>(mut self, path: P) -> Self {
+        self.excluded_paths.push(path.into().into());
+        self
+    }
+
+    pub fn with_composite_index(mut self, composite_index: Vec<CompositeIndexPath>) -> Self {
+        self.composite_indexes.push(composite_index);
+        self
+    }
+
+    pub fn with_spatial_index(mut self, spatial_index: SpatialIndex) -> Self {
+        self.spatial_indexes.push(spatial_index);
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialOrd, PartialEq)]
@@ -100,6 +307,21 @@ pub struct Collection {
     pub indexing_policy: IndexingPolicy,
     #[serde(rename = "partitionKey")]
     pub parition_key: PartitionKey,
+    // None: documents never expire, -1: documents do not expire unless
+    // they specify a ttl, n: documents expire n seconds after their last
+    // modification
+    #[serde(rename = "defaultTtl")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub default_ttl: Option<i64>,
+    #[serde(rename = "uniqueKeyPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub unique_key_policy: Option<UniqueKeyPolicy>,
+    #[serde(rename = "conflictResolutionPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub conflict_resolution_policy: Option<ConflictResolutionPolicy>,
     #[serde(rename = "_rid")]
     pub rid: String,
     #[serde(rename = "_ts")]
@@ -126,6 +348,9 @@ impl Collection {
             id: id.to_owned(),
             indexing_policy,
             parition_key: PartitionKey::default(),
+            default_ttl: None,
+            unique_key_policy: None,
+            conflict_resolution_policy: None,
             rid: "".to_owned(),
             ts: 0,
             _self: "".to_owned(),
@@ -160,3 +385,84 @@ impl CollectionName for String {
         self.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_collection_policies() {
+        let body = r#"{
+            "id": "coll",
+            "indexingPolicy": {
+                "indexingMode": "consistent",
+                "automatic": true,
+                "includedPaths": [{ "path": "/*" }],
+                "excludedPaths": [{ "path": "/\"_etag\"/?" }],
+                "compositeIndexes": [[
+                    { "path": "/name", "order": "ascending" },
+                    { "path": "/age", "order": "descending" }
+                ]],
+                "spatialIndexes": [{ "path": "/location/*", "types": ["Point", "Polygon"] }]
+            },
+            "partitionKey": { "paths": ["/tenant"], "kind": "Hash", "version": 2 },
+            "defaultTtl": -1,
+            "uniqueKeyPolicy": { "uniqueKeys": [{ "paths": ["/email"] }] },
+            "conflictResolutionPolicy": {
+                "mode": "LastWriterWins",
+                "conflictResolutionPath": "/_ts",
+                "conflictResolutionProcedure": ""
+            },
+            "_rid": "rid",
+            "_ts": 1,
+            "_self": "dbs/rid/colls/rid/",
+            "_etag": "\"etag\"",
+            "_docs": "docs/",
+            "_sprocs": "sprocs/",
+            "_triggers": "triggers/",
+            "_udfs": "udfs/",
+            "_conflicts": "conflicts/"
+        }"#;
+
+        let collection: Collection = serde_json::from_str(body).unwrap();
+
+        assert_eq!(collection.default_ttl, Some(-1));
+        assert_eq!(
+            collection.parition_key.hash_version(),
+            PartitionKeyHashVersion::V2
+        );
+        assert_eq!(
+            collection.indexing_policy.composite_indexes,
+            vec![vec![
+                CompositeIndexPath::new("/name", CompositeIndexOrder::Ascending),
+                CompositeIndexPath::new("/age", CompositeIndexOrder::Descending)
+            ]]
+        );
+        assert_eq!(
+            collection.indexing_policy.spatial_indexes[0].types,
+            vec![SpatialType::Point, SpatialType::Polygon]
+        );
+        assert_eq!(
+            collection.unique_key_policy,
+            Some(UniqueKeyPolicy {
+                unique_keys: vec!["/email".into()]
+            })
+        );
+        assert_eq!(
+            collection.conflict_resolution_policy.unwrap().mode,
+            ConflictResolutionMode::LastWriterWins
+        );
+    }
+
+    #[test]
+    fn serialize_collection_skips_missing_policies() {
+        let collection =
+            Collection::new("coll", IndexingPolicy::default().with_included_path("/*"));
+
+        let ser = serde_json::to_string(&collection).unwrap();
+        assert!(!ser.contains("compositeIndexes"));
+        assert!(!ser.contains("defaultTtl"));
+        assert!(!ser.contains("uniqueKeyPolicy"));
+        assert!(!ser.contains("version"));
+    }
+}
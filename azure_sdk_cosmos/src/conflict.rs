@@ -0,0 +1,107 @@
+use crate::Document;
+use azure_sdk_core::errors::AzureError;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ConflictOperationKind {
+    #[serde(rename = "create", alias = "Create")]
+    Create,
+    #[serde(rename = "replace", alias = "Replace")]
+    Replace,
+    #[serde(rename = "delete", alias = "Delete")]
+    Delete,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ConflictResourceType {
+    #[serde(rename = "document", alias = "Document")]
+    Document,
+    #[serde(rename = "storedProcedure", alias = "StoredProcedure")]
+    StoredProcedure,
+    #[serde(rename = "trigger", alias = "Trigger")]
+    Trigger,
+    #[serde(rename = "userDefinedFunction", alias = "UserDefinedFunction")]
+    UserDefinedFunction,
+    #[serde(other)]
+    Other,
+}
+
+/// A write that could not be applied because of a conflict between
+/// regions. `content` holds the losing version of the resource as
+/// serialized JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conflict {
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    #[serde(rename = "_ts")]
+    pub ts: u64,
+    pub _self: String,
+    #[serde(rename = "_etag")]
+    pub etag: String,
+
+    pub id: String,
+    #[serde(rename = "resourceType")]
+    pub resource_type: ConflictResourceType,
+    #[serde(rename = "operationType")]
+    pub operation_kind: ConflictOperationKind,
+    #[serde(rename = "resourceId")]
+    pub resource_id: String,
+    #[serde(default)]
+    pub content: String,
+}
+
+impl Conflict {
+    /// Deserializes the conflicting document. Returns None if the conflict
+    /// has no content, for example for deletes.
+    pub fn document<T>(&self) -> Result<Option<Document<T>>, AzureError>
+    where
+        T: DeserializeOwned,
+    {
+        if self.content.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(serde_json::from_str(&self.content)?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct MyDocument {
+        id: String,
+        hello: u32,
+    }
+
+    #[test]
+    fn deserialize_conflict() {
+        let body = r#"{
+            "id": "K1oAAA==",
+            "resourceType": "document",
+            "operationType": "replace",
+            "resourceId": "K1oAAJ3J-AABAAAAAAAAAA==",
+            "content": "{\"id\":\"doc\",\"hello\":42,\"_rid\":\"K1oAAJ3J-AABAAAAAAAAAA==\",\"_self\":\"dbs/K1oAAA==/colls/K1oAAJ3J-AA=/docs/K1oAAJ3J-AABAAAAAAAAAA==/\",\"_etag\":\"\\\"00000000-0000-0000-0000-000000000000\\\"\",\"_attachments\":\"attachments/\",\"_ts\":1590000000}",
+            "conflict_lsn": 42,
+            "_rid": "K1oAAJ3J-AABAAAAAAAAAA==",
+            "_self": "dbs/K1oAAA==/colls/K1oAAJ3J-AA=/conflicts/K1oAAJ3J-AABAAAAAAAAAA==/",
+            "_etag": "\"00000000-0000-0000-0000-000000000000\"",
+            "_ts": 1590000000
+        }"#;
+
+        let conflict: Conflict = serde_json::from_str(body).unwrap();
+
+        assert_eq!(conflict.resource_type, ConflictResourceType::Document);
+        assert_eq!(conflict.operation_kind, ConflictOperationKind::Replace);
+        assert_eq!(
+            conflict.document::<MyDocument>().unwrap().unwrap().document,
+            MyDocument {
+                id: "doc".to_owned(),
+                hello: 42
+            }
+        );
+    }
+}
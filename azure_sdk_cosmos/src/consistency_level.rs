@@ -80,7 +80,12 @@ implement_from!(ListUserDefinedFunctionsResponse);
 implement_from!(CreateTriggerResponse);
 implement_from!(ListTriggersResponse);
 implement_from!(DeleteTriggerResponse);
+implement_from!(ListConflictsResponse);
+implement_from!(GetConflictResponse);
+implement_from!(DeleteConflictResponse);
+implement_from!(TransactionalBatchResponse);
 implement_from!(ListDocumentsResponse, T);
+implement_from!(PatchDocumentResponse, T);
 implement_from!(QueryDocumentsResponse, T);
 implement_from!(QueryDocumentsResponseRaw, T);
 implement_from!(QueryDocumentsResponseDocuments, T);
@@ -0,0 +1,60 @@
+use crate::{DocumentName, PartitionKeys};
+use azure_sdk_core::errors::AzureError;
+
+/// A document type that knows its own id and partition key, so that the
+/// document builders can fill in the document name and the partition keys
+/// header from the document itself. It can be derived with
+/// `#[derive(CosmosEntity)]` enabling the `derive` feature: mark the id
+/// field with `#[cosmos(id)]` and the partition key field(s) with
+/// `#[cosmos(partition_key)]`.
+pub trait CosmosEntity {
+    fn id(&self) -> &str;
+    fn partition_keys(&self) -> Result<PartitionKeys, AzureError>;
+}
+
+impl<E> DocumentName for E
+where
+    E: CosmosEntity + std::fmt::Debug,
+{
+    fn name(&self) -> &str {
+        self.id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct MySampleEntity {
+        id: String,
+        tenant: String,
+        value: u64,
+    }
+
+    impl CosmosEntity for MySampleEntity {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn partition_keys(&self) -> Result<PartitionKeys, AzureError> {
+            let mut partition_keys = PartitionKeys::new();
+            partition_keys.push(&self.tenant)?;
+            Ok(partition_keys)
+        }
+    }
+
+    #[test]
+    fn entity_is_a_document_name() {
+        let entity = MySampleEntity {
+            id: "unique_id".to_owned(),
+            tenant: "contoso".to_owned(),
+            value: 42,
+        };
+
+        let document_name: &dyn DocumentName = &entity;
+        assert_eq!(document_name.name(), "unique_id");
+        assert_eq!(entity.partition_keys().unwrap().to_json(), "[\"contoso\"]");
+        assert_eq!(entity.value, 42);
+    }
+}
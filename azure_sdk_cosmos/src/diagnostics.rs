@@ -0,0 +1,207 @@
+use crate::DiagnosticsContextSupport;
+use hyper::{Method, StatusCode};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+tokio::task_local! {
+    static IS_RETRY: bool;
+}
+
+/// A single round trip to the service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestDiagnostics {
+    pub method: Method,
+    pub path: String,
+    /// `None` if the request failed before receiving a response.
+    pub status_code: Option<StatusCode>,
+    pub activity_id: Option<uuid::Uuid>,
+    pub request_charge: f64,
+    pub latency: Duration,
+    /// Whether the request was sent again by the SDK after it was
+    /// throttled or failed.
+    pub is_retry: bool,
+}
+
+/// Runs `request` as the retry of a request that was throttled or failed:
+/// every request it sends is recorded with `is_retry` set.
+pub(crate) async fn retrying<F: Future>(request: F) -> F::Output {
+    IS_RETRY.scope(true, request).await
+}
+
+/// Whether the current request is sent from within `retrying`.
+pub(crate) fn is_retry() -> bool {
+    IS_RETRY.try_with(|is_retry| *is_retry).unwrap_or(false)
+}
+
+/// Passes the diagnostics context of a builder, if any, to the builder of a
+/// request it sends on its behalf.
+pub(crate) fn with_diagnostics_context_of<'a, B>(
+    builder: B,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+) -> B
+where
+    B: DiagnosticsContextSupport<'a, O = B>,
+{
+    match diagnostics_context {
+        Some(diagnostics_context) => builder.with_diagnostics_context(diagnostics_context),
+        None => builder,
+    }
+}
+
+/// Collects the diagnostics of every request sent by a client, or by a
+/// single builder through `with_diagnostics_context`, for example to sum up
+/// the request units consumed by a logical operation spanning several
+/// pages. The context is cheap to clone: every clone records into the same
+/// list.
+///
+/// ```no_run
+/// # use azure_sdk_cosmos::prelude::*;
+/// # use azure_sdk_cosmos::DiagnosticsContext;
+/// # async fn f<CUB: azure_sdk_cosmos::clients::CosmosUriBuilder + Clone>(client: Client<CUB>) {
+/// let diagnostics_context = DiagnosticsContext::new();
+/// let client = client.with_diagnostics_context(diagnostics_context.clone());
+/// // ... page through a query using `client` ...
+/// println!(
+///     "{} RUs in {} round trips",
+///     diagnostics_context.total_request_charge(),
+///     diagnostics_context.round_trips()
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsContext {
+    requests: Arc<Mutex<Vec<RequestDiagnostics>>>,
+}
+
+impl DiagnosticsContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record(&self, request: RequestDiagnostics) {
+        self.requests.lock().unwrap().push(request);
+    }
+
+    pub fn requests(&self) -> Vec<RequestDiagnostics> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn total_request_charge(&self) -> f64 {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.request_charge)
+            .sum()
+    }
+
+    pub fn round_trips(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    pub fn retries(&self) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|request| request.is_retry)
+            .count()
+    }
+
+    pub fn activity_ids(&self) -> Vec<uuid::Uuid> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|request| request.activity_id)
+            .collect()
+    }
+
+    pub fn total_latency(&self) -> Duration {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.latency)
+            .sum()
+    }
+
+    /// Forgets the requests recorded so far, so the context can be reused
+    /// for the next operation.
+    pub fn clear(&self) {
+        self.requests.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(
+        path: &str,
+        status_code: StatusCode,
+        request_charge: f64,
+        is_retry: bool,
+    ) -> RequestDiagnostics {
+        RequestDiagnostics {
+            method: Method::POST,
+            path: path.to_owned(),
+            status_code: Some(status_code),
+            activity_id: Some(uuid::Uuid::new_v4()),
+            request_charge,
+            latency: Duration::from_millis(10),
+            is_retry,
+        }
+    }
+
+    #[test]
+    fn aggregates_requests() {
+        let diagnostics_context = DiagnosticsContext::new();
+        let clone = diagnostics_context.clone();
+
+        clone.record(request(
+            "/dbs/db/colls/coll/docs",
+            StatusCode::OK,
+            2.5,
+            false,
+        ));
+        clone.record(request(
+            "/dbs/db/colls/coll/docs",
+            StatusCode::TOO_MANY_REQUESTS,
+            0.0,
+            false,
+        ));
+        clone.record(request(
+            "/dbs/db/colls/coll/docs",
+            StatusCode::OK,
+            3.0,
+            true,
+        ));
+        clone.record(request(
+            "/dbs/db/colls/coll2/docs",
+            StatusCode::OK,
+            1.0,
+            false,
+        ));
+
+        assert_eq!(diagnostics_context.round_trips(), 4);
+        assert_eq!(diagnostics_context.retries(), 1);
+        assert_eq!(diagnostics_context.total_request_charge(), 6.5);
+        assert_eq!(diagnostics_context.activity_ids().len(), 4);
+        assert_eq!(
+            diagnostics_context.total_latency(),
+            Duration::from_millis(40)
+        );
+
+        diagnostics_context.clear();
+        assert_eq!(clone.round_trips(), 0);
+    }
+
+    #[tokio::test]
+    async fn retries_are_flagged_by_the_retry_site() {
+        assert!(!is_retry());
+        assert!(retrying(async { is_retry() }).await);
+        assert!(!is_retry());
+    }
+}
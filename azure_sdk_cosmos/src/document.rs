@@ -1,18 +1,24 @@
 use crate::DocumentAttributes;
 use azure_sdk_core::errors::AzureError;
 use hyper::header::HeaderMap;
-use serde::de::DeserializeOwned;
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 pub trait DocumentName: std::fmt::Debug {
     fn name(&self) -> &str;
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct Document<T> {
-    #[serde(flatten)]
     pub document_attributes: DocumentAttributes,
-    #[serde(flatten)]
     pub document: T, // raw, id not included
+    /// Seconds after the last modification the document expires, -1 never.
+    /// It requires the collection to have a default ttl. It is read from the
+    /// document body but left to `document` too, which can have a `ttl`
+    /// field of its own: when it does, that one is serialized instead.
+    pub ttl: Option<i64>,
 }
 
 impl<T> Document<T> {
@@ -22,7 +28,58 @@ impl<T> Document<T> {
         Self {
             document_attributes,
             document: t,
+            ttl: None,
+        }
+    }
+
+    pub fn with_ttl(self, ttl: i64) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..self
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct DocumentFields<A, T> {
+    #[serde(flatten)]
+    document_attributes: A,
+    #[serde(flatten)]
+    document: T,
+}
+
+impl<T> Serialize for Document<T>
+where
+    T: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = serde_json::to_value(DocumentFields {
+            document_attributes: &self.document_attributes,
+            document: &self.document,
+        })
+        .map_err(ser::Error::custom)?;
+        if let (Some(ttl), Some(map)) = (self.ttl, value.as_object_mut()) {
+            map.entry("ttl").or_insert_with(|| ttl.into());
         }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Document<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let ttl = ttl_from_value(&value);
+        let fields: DocumentFields<DocumentAttributes, T> =
+            serde_json::from_value(value).map_err(de::Error::custom)?;
+
+        Ok(Self {
+            document_attributes: fields.document_attributes,
+            document: fields.document,
+            ttl,
+        })
     }
 }
 
@@ -56,3 +113,73 @@ where
         Ok(serde_json::from_slice(body)?)
     }
 }
+
+/// The per-document time to live in a document body. It is not part of
+/// `DocumentAttributes` since those are flattened next to the user type,
+/// which can have a `ttl` field of its own.
+pub(crate) fn ttl_from_body(body: &[u8]) -> Result<Option<i64>, AzureError> {
+    #[derive(Deserialize)]
+    struct Ttl {
+        #[serde(default)]
+        ttl: Option<i64>,
+    }
+
+    Ok(serde_json::from_slice::<Ttl>(body)?.ttl)
+}
+
+pub(crate) fn ttl_from_value(document: &Value) -> Option<i64> {
+    document.get("ttl").and_then(Value::as_i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Session {
+        id: String,
+        ttl: i64,
+    }
+
+    #[test]
+    fn user_type_keeps_its_ttl() {
+        let body = br#"{"id":"session","ttl":300,"_rid":"rid","_ts":100,"_self":"_self","_etag":"etag","_attachments":"attachments/"}"#;
+
+        let document: Document<Session> = serde_json::from_slice(body).unwrap();
+        assert_eq!(document.document.ttl, 300);
+        assert_eq!(document.ttl, Some(300));
+        assert_eq!(ttl_from_body(body).unwrap(), Some(300));
+
+        let serialized = serde_json::to_string(&document.with_ttl(60)).unwrap();
+        assert_eq!(serialized.matches("\"ttl\"").count(), 1);
+        assert!(serialized.contains("\"ttl\":300"));
+
+        let body = br#"{"id":"session","_rid":"rid","_ts":100,"_self":"_self","_etag":"etag","_attachments":"attachments/"}"#;
+        assert_eq!(ttl_from_body(body).unwrap(), None);
+    }
+
+    #[test]
+    fn document_ttl() {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Counter {
+            id: String,
+            count: u64,
+        }
+
+        let body = br#"{"id":"counter","count":1,"ttl":-1,"_rid":"rid","_ts":100,"_self":"_self","_etag":"etag","_attachments":"attachments/"}"#;
+        let document: Document<Counter> = serde_json::from_slice(body).unwrap();
+        assert_eq!(document.ttl, Some(-1));
+        assert_eq!(document.document.count, 1);
+
+        let serialized = serde_json::to_value(document.with_ttl(30)).unwrap();
+        assert_eq!(serialized["ttl"], 30);
+        assert_eq!(serialized["count"], 1);
+
+        let serialized = serde_json::to_value(Document::new(Counter {
+            id: "counter".to_owned(),
+            count: 1,
+        }))
+        .unwrap();
+        assert!(serialized.get("ttl").is_none());
+    }
+}
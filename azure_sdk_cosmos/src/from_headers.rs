@@ -1,9 +1,10 @@
 use crate::headers::*;
 use crate::resource_quota::resource_quotas_from_str;
-use crate::{IndexingDirective, ResourceQuota};
+use crate::{IndexMetrics, IndexingDirective, QueryMetrics, ResourceQuota};
 use azure_sdk_core::errors::AzureError;
 use chrono::{DateTime, Utc};
 use http::HeaderMap;
+use std::time::Duration;
 
 pub(crate) fn request_charge_from_headers(headers: &HeaderMap) -> Result<f64, AzureError> {
     Ok(headers
@@ -13,6 +14,24 @@ pub(crate) fn request_charge_from_headers(headers: &HeaderMap) -> Result<f64, Az
         .parse()?)
 }
 
+pub(crate) fn request_charge_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<f64>, AzureError> {
+    Ok(match headers.get(HEADER_REQUEST_CHARGE) {
+        Some(val) => Some(val.to_str()?.parse()?),
+        None => None,
+    })
+}
+
+pub(crate) fn retry_after_from_headers(
+    headers: &HeaderMap,
+) -> Result<Option<Duration>, AzureError> {
+    Ok(match headers.get(HEADER_RETRY_AFTER_MS) {
+        Some(val) => Some(Duration::from_millis(val.to_str()?.parse()?)),
+        None => None,
+    })
+}
+
 //pub(crate) fn request_item_count_from_headers(headers: &HeaderMap) -> Result<u32, AzureError> {
 //    Ok(headers
 //        .get(HEADER_ITEM_COUNT)
@@ -95,6 +114,24 @@ pub(crate) fn quorum_acked_lsn_from_headers(headers: &HeaderMap) -> Result<u64,
         .parse()?)
 }
 
+pub(crate) fn query_metrics_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<QueryMetrics>, AzureError> {
+    Ok(match headers.get(HEADER_DOCUMENTDB_QUERY_METRICS) {
+        Some(val) => Some(val.to_str()?.parse()?),
+        None => None,
+    })
+}
+
+pub(crate) fn index_metrics_from_headers_optional(
+    headers: &HeaderMap,
+) -> Result<Option<IndexMetrics>, AzureError> {
+    Ok(match headers.get(HEADER_COSMOS_INDEX_UTILIZATION) {
+        Some(val) => Some(val.to_str()?.parse()?),
+        None => None,
+    })
+}
+
 pub(crate) fn quorum_acked_lsn_from_headers_optional(
     headers: &HeaderMap,
 ) -> Result<Option<u64>, AzureError> {
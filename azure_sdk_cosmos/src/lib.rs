@@ -9,12 +9,16 @@ extern crate failure;
 #[macro_use]
 extern crate azure_sdk_core;
 
+mod aad_token_provider;
 pub mod attachment;
 mod authorization_token;
 pub mod clients;
 pub mod collection;
+pub mod conflict;
 mod consistency_level;
+mod cosmos_entity;
 mod database;
+mod diagnostics;
 mod document;
 mod document_attributes;
 mod errors;
@@ -22,40 +26,67 @@ pub(crate) mod from_headers;
 mod headers;
 mod indexing_directive;
 pub mod offer;
+mod partition_key_hash;
 mod partition_key_range;
 mod partition_keys;
+mod patch_operation;
 mod permission;
 mod permission_resource;
 mod permission_token;
 pub mod prelude;
 mod query;
+mod query_builder;
+mod query_metrics;
 mod requests;
 mod resource;
 mod resource_quota;
+mod resource_token_broker;
 pub mod responses;
 pub mod stored_procedure;
 mod to_json_vector;
+mod transactional_batch;
 pub mod trigger;
 mod user;
 mod user_defined_function;
 
+pub use self::aad_token_provider::{AadToken, AadTokenProvider};
 pub use self::attachment::Attachment;
 pub use self::authorization_token::*;
-use self::collection::IndexingPolicy;
+use self::collection::{ConflictResolutionPolicy, IndexingPolicy, UniqueKeyPolicy};
+pub use self::conflict::Conflict;
 pub use self::consistency_level::ConsistencyLevel;
+pub use self::cosmos_entity::CosmosEntity;
+#[cfg(feature = "derive")]
+pub use azure_sdk_cosmos_derive::CosmosEntity;
 pub use self::database::{Database, DatabaseName};
+pub use self::diagnostics::{DiagnosticsContext, RequestDiagnostics};
 pub use self::document::{Document, DocumentName};
 pub use self::document_attributes::DocumentAttributes;
 pub use self::indexing_directive::IndexingDirective;
 pub use self::offer::Offer;
+pub use self::partition_key_hash::{
+    effective_partition_key, partition_keys_effective_partition_key, PartitionKeyComponent,
+    PartitionKeyHashVersion,
+};
 pub use self::partition_key_range::PartitionKeyRange;
+pub use self::patch_operation::PatchOperation;
 pub use self::permission::{Permission, PermissionMode, PermissionName};
 pub use self::permission_resource::PermissionResource;
 pub use self::permission_token::PermissionToken;
 pub use self::query::{Param, ParamDef, Query};
+pub use self::query_builder::{
+    ComparisonOperator, Condition, Field, QueryBuilder, SortOrder, SqlQuery,
+};
+pub use self::query_metrics::{
+    CompositeIndexMetric, IndexMetrics, QueryMetrics, SingleIndexMetric,
+};
 pub use self::requests::*;
 pub use self::resource::Resource;
 pub use self::resource_quota::ResourceQuota;
+pub use self::resource_token_broker::{
+    ResourceToken, ResourceTokenBroker, ResourceTokenProvider, ResourceTokens,
+};
+pub use self::transactional_batch::{BatchOperation, BatchOperationType, TransactionalBatch};
 pub use self::trigger::{Trigger, TriggerName};
 use crate::clients::{
     AttachmentClient, Client, CollectionClient, CosmosUriBuilder, DatabaseClient, DocumentClient,
@@ -69,6 +100,7 @@ use crate::stored_procedure::{Parameters, StoredProcedureName};
 pub use crate::user::{User, UserName};
 pub use crate::user_defined_function::UserDefinedFunctionName;
 use attachment::AttachmentName;
+pub use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::No;
 use http::request::Builder;
 use serde::Serialize;
@@ -109,6 +141,42 @@ pub trait ParallelizeCrossPartitionQuerySupport {
     ) -> Self::O;
 }
 
+pub trait PopulateQueryMetricsSupport {
+    type O;
+    fn with_populate_query_metrics(self, populate_query_metrics: bool) -> Self::O;
+}
+
+pub trait PopulateQueryMetricsOption {
+    fn populate_query_metrics(&self) -> bool;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        if self.populate_query_metrics() {
+            builder.header(HEADER_DOCUMENTDB_POPULATEQUERYMETRICS, true.to_string())
+        } else {
+            builder
+        }
+    }
+}
+
+pub trait PopulateIndexMetricsSupport {
+    type O;
+    fn with_populate_index_metrics(self, populate_index_metrics: bool) -> Self::O;
+}
+
+pub trait PopulateIndexMetricsOption {
+    fn populate_index_metrics(&self) -> bool;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        if self.populate_index_metrics() {
+            builder.header(HEADER_COSMOS_POPULATEINDEXMETRICS, true.to_string())
+        } else {
+            builder
+        }
+    }
+}
+
 pub trait ParametersOption<'a> {
     fn parameters(&self) -> Option<&'a Parameters>;
 
@@ -138,6 +206,34 @@ pub trait ParallelizeCrossPartitionQueryOption {
     }
 }
 
+pub trait TtlOption {
+    fn ttl(&self) -> Option<i64>;
+}
+
+pub trait TtlSupport {
+    type O;
+    fn with_ttl(self, ttl: i64) -> Self::O;
+}
+
+pub trait DiagnosticsContextSupport<'a> {
+    type O;
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O;
+}
+
+pub trait DiagnosticsContextOption<'a> {
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext>;
+
+    /// The request is recorded in the diagnostics context of the builder
+    /// rather than in the one of the client.
+    #[must_use]
+    fn add_extension(&self, builder: Builder) -> Builder {
+        match self.diagnostics_context() {
+            Some(diagnostics_context) => builder.extension(diagnostics_context.clone()),
+            None => builder,
+        }
+    }
+}
+
 pub trait IsUpsertSupport {
     type O;
     fn with_is_upsert(self, is_upsert: bool) -> Self::O;
@@ -364,6 +460,10 @@ pub trait MediaSupport<'a> {
     fn with_media(self, media: &'a str) -> Self::O;
 }
 
+pub trait MediaOption<'a> {
+    fn media(&self) -> Option<&'a str>;
+}
+
 pub trait StoredProcedureBodyRequired<'a> {
     fn body(&self) -> &'a str;
 }
@@ -463,6 +563,15 @@ where
     fn user_client(&self) -> &'a UserClient<'a, CUB>;
 }
 
+pub trait ConflictIdRequired<'a> {
+    fn conflict_id(&self) -> &'a str;
+}
+
+pub trait ConflictIdSupport<'a> {
+    type O;
+    fn with_conflict_id(self, conflict_id: &'a str) -> Self::O;
+}
+
 pub trait StoredProcedureNameRequired<'a> {
     fn stored_procedure_name(&self) -> &'a str;
 }
@@ -538,6 +647,36 @@ pub trait CollectionSupport<'a> {
     fn with_collection(self, collection: &'a Collection) -> Self::O;
 }
 
+pub trait DefaultTtlOption {
+    fn default_ttl(&self) -> Option<i64>;
+}
+
+pub trait DefaultTtlSupport {
+    type O;
+    fn with_default_ttl(self, default_ttl: i64) -> Self::O;
+}
+
+pub trait UniqueKeyPolicyOption<'a> {
+    fn unique_key_policy(&self) -> Option<&'a UniqueKeyPolicy>;
+}
+
+pub trait UniqueKeyPolicySupport<'a> {
+    type O;
+    fn with_unique_key_policy(self, unique_key_policy: &'a UniqueKeyPolicy) -> Self::O;
+}
+
+pub trait ConflictResolutionPolicyOption<'a> {
+    fn conflict_resolution_policy(&self) -> Option<&'a ConflictResolutionPolicy>;
+}
+
+pub trait ConflictResolutionPolicySupport<'a> {
+    type O;
+    fn with_conflict_resolution_policy(
+        self,
+        conflict_resolution_policy: &'a ConflictResolutionPolicy,
+    ) -> Self::O;
+}
+
 pub trait IndexingPolicyRequired<'a> {
     fn indexing_policy(&self) -> &'a IndexingPolicy;
 }
@@ -576,6 +715,84 @@ pub trait DocumentIdSupport<'a> {
     fn with_document_id(self, document_id: &'a str) -> Self::O;
 }
 
+pub trait PatchOperationsRequired<'a> {
+    fn patch_operations(&self) -> &'a [PatchOperation];
+}
+
+pub trait PatchOperationsSupport<'a> {
+    type O;
+    fn with_patch_operations(self, patch_operations: &'a [PatchOperation]) -> Self::O;
+}
+
+pub trait PatchConditionOption<'a> {
+    fn patch_condition(&self) -> Option<&'a str>;
+}
+
+pub trait PatchConditionSupport<'a> {
+    type O;
+    fn with_patch_condition(self, patch_condition: &'a str) -> Self::O;
+}
+
+pub trait DocumentsRequired<'a, T>
+where
+    T: Serialize,
+{
+    fn documents(&self) -> &'a [T];
+}
+
+pub trait DocumentsSupport<'a, T>
+where
+    T: Serialize,
+{
+    type O;
+    fn with_documents(self, documents: &'a [T]) -> Self::O;
+}
+
+pub trait MaxConcurrencyPerRangeOption {
+    fn max_concurrency_per_range(&self) -> u32;
+}
+
+pub trait MaxConcurrencyPerRangeSupport {
+    type O;
+    fn with_max_concurrency_per_range(self, max_concurrency_per_range: u32) -> Self::O;
+}
+
+pub trait MaxThrottlingRetriesOption {
+    fn max_throttling_retries(&self) -> u32;
+}
+
+pub trait MaxThrottlingRetriesSupport {
+    type O;
+    fn with_max_throttling_retries(self, max_throttling_retries: u32) -> Self::O;
+}
+
+pub trait MaxAttemptsOption {
+    fn max_attempts(&self) -> u32;
+}
+
+pub trait MaxAttemptsSupport {
+    type O;
+    fn with_max_attempts(self, max_attempts: u32) -> Self::O;
+}
+
+pub trait RetryBackoffOption {
+    fn retry_backoff(&self) -> std::time::Duration;
+}
+
+pub trait RetryBackoffSupport {
+    type O;
+    fn with_retry_backoff(self, retry_backoff: std::time::Duration) -> Self::O;
+}
+
+pub trait TransactionalBatchRequired<'a> {
+    fn transactional_batch(&self) -> &'a TransactionalBatch;
+}
+
+pub trait TransactionalBatchSupport<'a> {
+    type O;
+    fn with_transactional_batch(self, transactional_batch: &'a TransactionalBatch) -> Self::O;
+}
+
 pub trait QueryRequired<'a> {
     fn query(&self) -> &'a Query<'a>;
 }
@@ -642,7 +859,10 @@ pub(crate) trait DatabaseBuilderTrait<'a, CUB>: DatabaseTrait<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder;
+    fn prepare_request(
+        &self,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait CollectionTrait<'a, CUB>
@@ -662,6 +882,10 @@ where
     where
         T: Serialize;
     fn query_documents(&self) -> requests::QueryDocumentsBuilder<'_, '_, CUB, No>;
+    fn transactional_batch(&self) -> requests::TransactionalBatchBuilder<'_, '_, CUB, No, No>;
+    fn bulk_import<T>(&self) -> requests::BulkImportBuilder<'_, '_, T, CUB, No>
+    where
+        T: Serialize;
     fn with_stored_procedure<'c>(
         &'c self,
         stored_procedure_name: &'c dyn StoredProcedureName,
@@ -676,18 +900,27 @@ where
         -> requests::ListUserDefinedFunctionsBuilder<'_, '_, CUB>;
     fn list_triggers(&self) -> requests::ListTriggersBuilder<'_, '_, CUB>;
     fn get_partition_key_ranges(&self) -> requests::GetPartitionKeyRangesBuilder<'_, '_, CUB>;
+    fn list_conflicts(&self) -> requests::ListConflictsBuilder<'_, '_, CUB>;
+    fn get_conflict(&self) -> requests::GetConflictBuilder<'_, '_, CUB, No, No>;
+    fn delete_conflict(&self) -> requests::DeleteConflictBuilder<'_, '_, CUB, No, No>;
     fn with_document<'c>(
         &'c self,
         document_name: &'c dyn DocumentName,
         partition_keys: &'c PartitionKeys,
     ) -> DocumentClient<'c, CUB>;
+    fn with_entity<'c, E>(&'c self, entity: &'c E) -> Result<DocumentClient<'c, CUB>, AzureError>
+    where
+        E: CosmosEntity + std::fmt::Debug;
 }
 
 pub(crate) trait CollectionBuilderTrait<'a, CUB>: CollectionTrait<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder;
+    fn prepare_request(
+        &self,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait DocumentTrait<'a, CUB>
@@ -697,9 +930,11 @@ where
     fn database_name(&self) -> &'a dyn DatabaseName;
     fn collection_name(&self) -> &'a dyn CollectionName;
     fn document_name(&self) -> &'a dyn DocumentName;
-    fn partition_keys(&self) -> &'a PartitionKeys;
+    fn partition_keys(&self) -> &PartitionKeys;
     fn get_document(&self) -> requests::GetDocumentBuilder<'_, '_, CUB>;
     fn delete_document(&self) -> requests::DeleteDocumentBuilder<'_, CUB>;
+    fn patch_document(&self) -> requests::PatchDocumentBuilder<'_, '_, CUB, No>;
+    fn update_document(&self) -> requests::UpdateDocumentBuilder<'_, CUB>;
     fn list_attachments(&self) -> requests::ListAttachmentsBuilder<'_, '_, CUB>;
     fn with_attachment(
         &'a self,
@@ -711,7 +946,10 @@ pub(crate) trait DocumentBuilderTrait<'a, CUB>: DocumentTrait<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder;
+    fn prepare_request(
+        &self,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait StoredProcedureTrait<'a, CUB>
@@ -732,7 +970,10 @@ pub(crate) trait StoredProcedureBuilderTrait<'a, CUB>:
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder;
+    fn prepare_request(
+        &self,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait UserDefinedFunctionTrait<'a, CUB>
@@ -760,7 +1001,7 @@ where
         &self,
         method: hyper::Method,
         specify_user_defined_function_name: bool,
-    ) -> http::request::Builder;
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait TriggerTrait<'a, CUB>
@@ -783,7 +1024,7 @@ where
         &self,
         method: hyper::Method,
         specify_trigger_name: bool,
-    ) -> http::request::Builder;
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait AttachmentTrait<'a, CUB>
@@ -801,13 +1042,18 @@ where
         -> requests::ReplaceReferenceAttachmentBuilder<'_, '_, CUB, No, No>;
     fn delete(&self) -> requests::DeleteAttachmentBuilder<'_, '_, CUB>;
     fn get(&self) -> requests::GetAttachmentBuilder<'_, '_, CUB>;
+    fn get_media(&self) -> requests::GetMediaBuilder<'_, '_, CUB>;
+    fn replace_media(&self) -> requests::ReplaceMediaBuilder<'_, '_, CUB, No>;
 }
 
 pub(crate) trait AttachmentBuilderTrait<'a, CUB>: AttachmentTrait<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    fn prepare_request(&self, method: hyper::Method) -> http::request::Builder;
+    fn prepare_request(
+        &self,
+        method: hyper::Method,
+    ) -> Result<http::request::Builder, AzureError>;
 }
 
 pub trait UserTrait<'a, CUB>
@@ -0,0 +1,445 @@
+// Effective partition key computation, mirroring what the Cosmos gateway
+// does to route a document to its physical partition. The effective
+// partition key is an hex string that can be compared with the
+// minInclusive/maxExclusive boundaries of a PartitionKeyRange.
+use crate::PartitionKeys;
+use azure_sdk_core::errors::AzureError;
+use serde_json::Value;
+
+const MAX_STRING_CHARS_V1: usize = 100;
+const MAX_STRING_BYTES_TO_APPEND_V1: usize = 100;
+
+const COMPONENT_UNDEFINED: u8 = 0x00;
+const COMPONENT_NULL: u8 = 0x01;
+const COMPONENT_FALSE: u8 = 0x02;
+const COMPONENT_TRUE: u8 = 0x03;
+const COMPONENT_NUMBER: u8 = 0x05;
+const COMPONENT_STRING: u8 = 0x08;
+
+/// The hash function used by the collection. Collections created with
+/// large partition keys support use version 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionKeyHashVersion {
+    V1,
+    V2,
+}
+
+/// A single value of a (possibly hierarchical) partition key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartitionKeyComponent<'a> {
+    Undefined,
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(&'a str),
+}
+
+impl<'a> PartitionKeyComponent<'a> {
+    // Objects and arrays are not valid partition key values: Cosmos treats
+    // them as undefined.
+    pub fn from_value(value: Option<&'a Value>) -> Self {
+        match value {
+            None => PartitionKeyComponent::Undefined,
+            Some(Value::Null) => PartitionKeyComponent::Null,
+            Some(Value::Bool(b)) => PartitionKeyComponent::Bool(*b),
+            Some(Value::Number(n)) => n
+                .as_f64()
+                .map(PartitionKeyComponent::Number)
+                .unwrap_or(PartitionKeyComponent::Undefined),
+            Some(Value::String(s)) => PartitionKeyComponent::String(s),
+            Some(_) => PartitionKeyComponent::Undefined,
+        }
+    }
+
+    fn write_for_hashing(&self, buffer: &mut Vec<u8>, string_terminator: u8) {
+        match self {
+            PartitionKeyComponent::Undefined => buffer.push(COMPONENT_UNDEFINED),
+            PartitionKeyComponent::Null => buffer.push(COMPONENT_NULL),
+            PartitionKeyComponent::Bool(false) => buffer.push(COMPONENT_FALSE),
+            PartitionKeyComponent::Bool(true) => buffer.push(COMPONENT_TRUE),
+            PartitionKeyComponent::Number(n) => {
+                buffer.push(COMPONENT_NUMBER);
+                buffer.extend_from_slice(&n.to_le_bytes());
+            }
+            PartitionKeyComponent::String(s) => {
+                buffer.push(COMPONENT_STRING);
+                buffer.extend_from_slice(s.as_bytes());
+                buffer.push(string_terminator);
+            }
+        }
+    }
+
+    fn write_for_binary_encoding(&self, buffer: &mut Vec<u8>) {
+        match self {
+            PartitionKeyComponent::Number(n) => {
+                buffer.push(COMPONENT_NUMBER);
+
+                let bits = n.to_bits();
+                let mut payload = if bits & 0x8000_0000_0000_0000 == 0 {
+                    bits ^ 0x8000_0000_0000_0000
+                } else {
+                    (!bits).wrapping_add(1)
+                };
+
+                // the first chunk carries 8 bits of payload, the others 7
+                // bits followed by a 1. The last one ends with a 0 instead.
+                buffer.push((payload >> 56) as u8);
+                payload <<= 8;
+
+                let mut byte_to_write = 0u8;
+                let mut first_iteration = true;
+                while payload != 0 {
+                    if !first_iteration {
+                        buffer.push(byte_to_write);
+                    } else {
+                        first_iteration = false;
+                    }
+                    byte_to_write = ((payload >> 56) as u8) | 0x01;
+                    payload <<= 7;
+                }
+                buffer.push(byte_to_write & 0xFE);
+            }
+            PartitionKeyComponent::String(s) => {
+                buffer.push(COMPONENT_STRING);
+
+                let bytes = s.as_bytes();
+                let short_string = bytes.len() <= MAX_STRING_BYTES_TO_APPEND_V1;
+                let len = if short_string {
+                    bytes.len()
+                } else {
+                    MAX_STRING_BYTES_TO_APPEND_V1 + 1
+                };
+                buffer.extend(
+                    bytes[..len]
+                        .iter()
+                        .map(|b| if *b < 0xFF { *b + 1 } else { *b }),
+                );
+
+                if short_string {
+                    buffer.push(0x00);
+                }
+            }
+            other => other.write_for_hashing(buffer, 0x00),
+        }
+    }
+
+    // hash v1 considers only the first 100 characters (UTF-16 code units,
+    // as the reference implementation) of a string.
+    fn truncate_v1(self) -> Self {
+        match self {
+            PartitionKeyComponent::String(s) => {
+                let mut utf16_len = 0;
+                let mut end = s.len();
+                for (index, c) in s.char_indices() {
+                    utf16_len += c.len_utf16();
+                    if utf16_len > MAX_STRING_CHARS_V1 {
+                        end = index;
+                        break;
+                    }
+                }
+                PartitionKeyComponent::String(&s[..end])
+            }
+            other => other,
+        }
+    }
+}
+
+/// Computes the effective partition key of the passed values. The result
+/// can be compared with the boundaries of a `PartitionKeyRange`.
+pub fn effective_partition_key(
+    components: &[PartitionKeyComponent<'_>],
+    version: PartitionKeyHashVersion,
+) -> String {
+    match version {
+        PartitionKeyHashVersion::V1 => effective_partition_key_v1(components),
+        PartitionKeyHashVersion::V2 => effective_partition_key_v2(components),
+    }
+}
+
+/// Same as `effective_partition_key` but takes the values from the
+/// `PartitionKeys` sent in the `x-ms-documentdb-partitionkey` header. An
+/// empty object (`{}`) is treated as undefined.
+pub fn partition_keys_effective_partition_key(
+    partition_keys: &PartitionKeys,
+    version: PartitionKeyHashVersion,
+) -> Result<String, AzureError> {
+    let values: Vec<Value> = serde_json::from_str(&partition_keys.to_json())?;
+    let components: Vec<PartitionKeyComponent<'_>> = values
+        .iter()
+        .map(|value| PartitionKeyComponent::from_value(Some(value)))
+        .collect();
+
+    Ok(effective_partition_key(&components, version))
+}
+
+fn effective_partition_key_v1(components: &[PartitionKeyComponent<'_>]) -> String {
+    let truncated: Vec<PartitionKeyComponent<'_>> =
+        components.iter().map(|c| c.truncate_v1()).collect();
+
+    let mut buffer = Vec::new();
+    for component in truncated.iter() {
+        component.write_for_hashing(&mut buffer, 0x00);
+    }
+    let hash = murmur_hash3_32(&buffer, 0);
+
+    // the hash is computed on the truncated values but the components are
+    // appended untruncated
+    let mut encoded = Vec::new();
+    PartitionKeyComponent::Number(f64::from(hash)).write_for_binary_encoding(&mut encoded);
+    for component in components {
+        component.write_for_binary_encoding(&mut encoded);
+    }
+
+    to_hex(&encoded)
+}
+
+fn effective_partition_key_v2(components: &[PartitionKeyComponent<'_>]) -> String {
+    let mut buffer = Vec::new();
+    for component in components {
+        component.write_for_hashing(&mut buffer, 0xFF);
+    }
+    let (h1, h2) = murmur_hash3_128(&buffer, 0);
+
+    let mut hash = [0u8; 16];
+    hash[..8].copy_from_slice(&h2.to_be_bytes());
+    hash[8..].copy_from_slice(&h1.to_be_bytes());
+    // the two most significant bits are reset as the maximum exclusive
+    // boundary is "FF"
+    hash[0] &= 0x3F;
+
+    to_hex(&hash)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn murmur_hash3_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    let mut h1 = seed;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k1 = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k1 = k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+
+        h1 ^= k1;
+        h1 = h1.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = chunks.remainder();
+    if !tail.is_empty() {
+        let mut k1 = 0u32;
+        for (i, b) in tail.iter().enumerate() {
+            k1 |= u32::from(*b) << (8 * i);
+        }
+        h1 ^= k1.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2);
+    }
+
+    h1 ^= data.len() as u32;
+    fmix32(h1)
+}
+
+fn murmur_hash3_128(data: &[u8], seed: u64) -> (u64, u64) {
+    const C1: u64 = 0x87c3_7b91_1142_53d5;
+    const C2: u64 = 0x4cf5_ad43_2745_937f;
+
+    let mut h1 = seed;
+    let mut h2 = seed;
+
+    let mut chunks = data.chunks_exact(16);
+    for chunk in &mut chunks {
+        let mut k1 = u64::from_le_bytes(to_array8(&chunk[..8]));
+        let mut k2 = u64::from_le_bytes(to_array8(&chunk[8..]));
+
+        k1 = k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+        h1 ^= k1;
+        h1 = h1
+            .rotate_left(27)
+            .wrapping_add(h2)
+            .wrapping_mul(5)
+            .wrapping_add(0x52dc_e729);
+
+        k2 = k2.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+        h2 ^= k2;
+        h2 = h2
+            .rotate_left(31)
+            .wrapping_add(h1)
+            .wrapping_mul(5)
+            .wrapping_add(0x3849_5ab5);
+    }
+
+    let tail = chunks.remainder();
+    let mut k1 = 0u64;
+    let mut k2 = 0u64;
+    for (i, b) in tail.iter().enumerate() {
+        if i < 8 {
+            k1 |= u64::from(*b) << (8 * i);
+        } else {
+            k2 |= u64::from(*b) << (8 * (i - 8));
+        }
+    }
+    if tail.len() > 8 {
+        h2 ^= k2.wrapping_mul(C2).rotate_left(33).wrapping_mul(C1);
+    }
+    if !tail.is_empty() {
+        h1 ^= k1.wrapping_mul(C1).rotate_left(31).wrapping_mul(C2);
+    }
+
+    h1 ^= data.len() as u64;
+    h2 ^= data.len() as u64;
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    h1 = fmix64(h1);
+    h2 = fmix64(h2);
+
+    h1 = h1.wrapping_add(h2);
+    h2 = h2.wrapping_add(h1);
+
+    (h1, h2)
+}
+
+fn to_array8(slice: &[u8]) -> [u8; 8] {
+    let mut array = [0u8; 8];
+    array.copy_from_slice(slice);
+    array
+}
+
+fn fmix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    h
+}
+
+fn fmix64(mut k: u64) -> u64 {
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    k ^= k >> 33;
+    k = k.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    k ^= k >> 33;
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn murmur_hash3() {
+        assert_eq!(murmur_hash3_32(b"", 0), 0);
+        assert_eq!(murmur_hash3_32(b"hello", 0), 0x248b_fa47);
+        assert_eq!(murmur_hash3_128(b"", 0), (0, 0));
+        assert_eq!(
+            murmur_hash3_128(b"hello", 0),
+            (0xcbd8_a7b3_41bd_9b02, 0x5b1e_906a_48ae_1d19)
+        );
+    }
+
+    #[test]
+    fn partition_keys_effective_partition_key() {
+        let mut partition_keys = PartitionKeys::new();
+        partition_keys.push("partitionKey").unwrap();
+        assert_eq!(
+            super::partition_keys_effective_partition_key(
+                &partition_keys,
+                PartitionKeyHashVersion::V2
+            )
+            .unwrap(),
+            "013AEFCF77FA271571CF665A58C933F1"
+        );
+
+        let mut partition_keys = PartitionKeys::new();
+        partition_keys.push(serde_json::json!({})).unwrap();
+        assert_eq!(
+            super::partition_keys_effective_partition_key(
+                &partition_keys,
+                PartitionKeyHashVersion::V1
+            )
+            .unwrap(),
+            "05C1D529E345DC00"
+        );
+    }
+
+    #[test]
+    fn effective_partition_key_hash_v1() {
+        let long = "a".repeat(1024);
+        let long_v1 = format!("05C1EB5921F70608{}", "62".repeat(101));
+        let cases: Vec<(PartitionKeyComponent, &str)> = vec![
+            (PartitionKeyComponent::String(""), "05C1CF33970FF80800"),
+            (
+                PartitionKeyComponent::String("partitionKey"),
+                "05C1E1B3D9CD2608716273756A756A706F4C667A00",
+            ),
+            (PartitionKeyComponent::String(&long), &long_v1),
+            (PartitionKeyComponent::Null, "05C1ED45D7475601"),
+            (PartitionKeyComponent::Undefined, "05C1D529E345DC00"),
+            (PartitionKeyComponent::Bool(true), "05C1D7C5A903D803"),
+            (PartitionKeyComponent::Bool(false), "05C1DB857D857C02"),
+            (
+                PartitionKeyComponent::Number(-128f64),
+                "05C1D73349F54C053FA0",
+            ),
+            (
+                PartitionKeyComponent::Number(127f64),
+                "05C1DD539DDFCC05C05FE0",
+            ),
+        ];
+
+        for (component, expected) in cases {
+            assert_eq!(effective_partition_key_v1(&[component]), expected);
+        }
+    }
+
+    #[test]
+    fn effective_partition_key_hash_v2() {
+        let long = "a".repeat(1024);
+        let cases: Vec<(PartitionKeyComponent, &str)> = vec![
+            (
+                PartitionKeyComponent::String(""),
+                "32E9366E637A71B4E710384B2F4970A0",
+            ),
+            (
+                PartitionKeyComponent::String("partitionKey"),
+                "013AEFCF77FA271571CF665A58C933F1",
+            ),
+            (
+                PartitionKeyComponent::String(&long),
+                "332BDF5512AE49615F32C7D98C2DB86C",
+            ),
+            (
+                PartitionKeyComponent::Null,
+                "378867E4430E67857ACE5C908374FE16",
+            ),
+            (
+                PartitionKeyComponent::Undefined,
+                "11622DAA78F835834610ABE56EFF5CB5",
+            ),
+            (
+                PartitionKeyComponent::Bool(true),
+                "0E711127C5B5A8E4726AC6DD306A3E59",
+            ),
+            (
+                PartitionKeyComponent::Bool(false),
+                "2FE1BE91E90A3439635E0E9E37361EF2",
+            ),
+            (
+                PartitionKeyComponent::Number(-128f64),
+                "01DAEDABF913540367FE219B2AD06148",
+            ),
+            (
+                PartitionKeyComponent::Number(127f64),
+                "0C507ACAC853ECA7977BF4CEFB562A25",
+            ),
+        ];
+
+        for (component, expected) in cases {
+            assert_eq!(effective_partition_key_v2(&[component]), expected);
+        }
+    }
+}
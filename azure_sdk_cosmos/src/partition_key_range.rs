@@ -19,3 +19,12 @@ pub struct PartitionKeyRange {
     #[serde(rename = "_ts")]
     pub ts: u64,
 }
+
+impl PartitionKeyRange {
+    /// Returns true if the effective partition key falls in this range. See
+    /// `effective_partition_key` to calculate it.
+    pub fn contains(&self, effective_partition_key: &str) -> bool {
+        self.min_exclusive.as_str() <= effective_partition_key
+            && effective_partition_key < self.max_exclusive.as_str()
+    }
+}
@@ -0,0 +1,104 @@
+use serde_json::Value;
+
+/// A single partial document update operation. The operations are sent
+/// in order, as a JSON Patch-like array, to the Cosmos patch endpoint. For
+/// more details see
+/// [https://docs.microsoft.com/en-us/azure/cosmos-db/partial-document-update](https://docs.microsoft.com/en-us/azure/cosmos-db/partial-document-update).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "op")]
+pub enum PatchOperation {
+    #[serde(rename = "add")]
+    Add { path: String, value: Value },
+    #[serde(rename = "set")]
+    Set { path: String, value: Value },
+    #[serde(rename = "replace")]
+    Replace { path: String, value: Value },
+    #[serde(rename = "remove")]
+    Remove { path: String },
+    #[serde(rename = "incr")]
+    Increment { path: String, value: Value },
+    #[serde(rename = "move")]
+    Move { from: String, path: String },
+}
+
+impl PatchOperation {
+    pub fn add<P: Into<String>, T: Into<Value>>(path: P, value: T) -> Self {
+        PatchOperation::Add {
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn set<P: Into<String>, T: Into<Value>>(path: P, value: T) -> Self {
+        PatchOperation::Set {
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn replace<P: Into<String>, T: Into<Value>>(path: P, value: T) -> Self {
+        PatchOperation::Replace {
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn remove<P: Into<String>>(path: P) -> Self {
+        PatchOperation::Remove { path: path.into() }
+    }
+
+    pub fn increment<P: Into<String>, T: Into<Value>>(path: P, value: T) -> Self {
+        PatchOperation::Increment {
+            path: path.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn move_from<F: Into<String>, P: Into<String>>(from: F, path: P) -> Self {
+        PatchOperation::Move {
+            from: from.into(),
+            path: path.into(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        match self {
+            PatchOperation::Add { path, .. }
+            | PatchOperation::Set { path, .. }
+            | PatchOperation::Replace { path, .. }
+            | PatchOperation::Remove { path }
+            | PatchOperation::Increment { path, .. }
+            | PatchOperation::Move { path, .. } => path,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_patch_operations() {
+        let operations = vec![
+            PatchOperation::add("/tags/-", "new"),
+            PatchOperation::set("/name", "pollo"),
+            PatchOperation::replace("/address/city", "Milano"),
+            PatchOperation::remove("/obsolete"),
+            PatchOperation::increment("/counter", 5),
+            PatchOperation::move_from("/old", "/new"),
+        ];
+
+        let ser = serde_json::to_string(&operations).unwrap();
+
+        assert_eq!(
+            ser,
+            r#"[{"op":"add","path":"/tags/-","value":"new"},{"op":"set","path":"/name","value":"pollo"},{"op":"replace","path":"/address/city","value":"Milano"},{"op":"remove","path":"/obsolete"},{"op":"incr","path":"/counter","value":5},{"op":"move","from":"/old","path":"/new"}]"#
+        );
+    }
+
+    #[test]
+    fn patch_operation_path() {
+        assert_eq!(PatchOperation::remove("/a").path(), "/a");
+        assert_eq!(PatchOperation::move_from("/a", "/b").path(), "/b");
+    }
+}
@@ -1,32 +1,46 @@
 pub use crate::clients::{Client, ClientBuilder};
+#[allow(deprecated)]
 pub use crate::collection::{
-    Collection, DataType, IncludedPath, IncludedPathIndex, IndexingMode, IndexingPolicy, KeyKind,
+    Collection, CompositeIndexOrder, CompositeIndexPath, ConflictResolutionMode,
+    ConflictResolutionPolicy, DataType, IncludedPath, IncludedPathIndex, IndexingMode,
+    IndexingPolicy, KeyKind, SpatialIndex, SpatialType, UniqueKey, UniqueKeyPolicy,
 };
 pub use crate::database::DatabaseName;
 pub use crate::document::Document;
 pub use crate::query::Query;
+pub use crate::query_builder::{Field, QueryBuilder, SortOrder};
 pub use crate::responses::{QueryDocumentsResponse, QueryDocumentsResponseRaw, QueryResult};
 pub use crate::{
     AIMOption, AIMSupport, AllowTentativeWritesOption, AllowTentativeWritesSupport,
-    AttachmentTrait, AuthorizationToken, ClientRequired, CollectionClientRequired,
-    CollectionNameRequired, CollectionNameSupport, CollectionRequired, CollectionSupport,
-    CollectionTrait, ConsistencyLevel, ConsistencyLevelOption, ConsistencyLevelSupport,
-    ContinuationOption, ContinuationSupport, CosmosTrait, DatabaseClientRequired,
-    DatabaseNameRequired, DatabaseNameSupport, DatabaseTrait, DocumentIdRequired,
-    DocumentIdSupport, DocumentRequired, DocumentSupport, DocumentTrait, ExpirySecondsOption,
+    AttachmentTrait, AuthorizationToken, AuthorizationTokenProvider, ClientRequired,
+    CollectionClientRequired, CollectionNameRequired, CollectionNameSupport, CollectionRequired,
+    CollectionSupport, CollectionTrait, ConflictIdRequired, ConflictIdSupport,
+    ConflictResolutionPolicyOption, ConflictResolutionPolicySupport, ConsistencyLevel,
+    ConsistencyLevelOption, ConsistencyLevelSupport, ContinuationOption, ContinuationSupport,
+    CosmosEntity, CosmosTrait, DatabaseClientRequired, DatabaseNameRequired, DatabaseNameSupport,
+    DatabaseTrait, DefaultTtlOption, DefaultTtlSupport, DiagnosticsContextOption,
+    DiagnosticsContextSupport, DocumentIdRequired, DocumentIdSupport, DocumentRequired,
+    DocumentSupport, DocumentTrait, DocumentsRequired, DocumentsSupport, ExpirySecondsOption,
     ExpirySecondsSupport, IndexingDirective, IndexingDirectiveOption, IndexingDirectiveSupport,
     IndexingPolicyRequired, IndexingPolicySupport, IsUpsertOption, IsUpsertSupport,
-    MaxItemCountOption, MaxItemCountSupport, MediaRequired, MediaSupport, Offer, OfferRequired,
-    OfferSupport, ParallelizeCrossPartitionQueryOption, ParallelizeCrossPartitionQuerySupport,
-    ParametersOption, ParametersSupport, PartitionKeyOption, PartitionKeyRequired,
-    PartitionKeySupport, PartitionKeys, PartitionKeysOption, PartitionKeysRequired,
-    PartitionKeysSupport, PartitionRangeIdOption, PartitionRangeIdSupport,
-    PermissionClientRequired, PermissionModeRequired, PermissionModeSupport, PermissionTrait,
+    MaxAttemptsOption, MaxAttemptsSupport, MaxConcurrencyPerRangeOption,
+    MaxConcurrencyPerRangeSupport, MaxItemCountOption, MaxItemCountSupport,
+    MaxThrottlingRetriesOption, MaxThrottlingRetriesSupport, MediaOption, MediaRequired,
+    MediaSupport, Offer, OfferRequired, OfferSupport, ParallelizeCrossPartitionQueryOption,
+    ParallelizeCrossPartitionQuerySupport, ParametersOption, ParametersSupport, PartitionKeyOption,
+    PartitionKeyRequired, PartitionKeySupport, PartitionKeys, PartitionKeysOption,
+    PartitionKeysRequired, PartitionKeysSupport, PartitionRangeIdOption, PartitionRangeIdSupport,
+    PatchConditionOption, PatchConditionSupport, PatchOperation, PatchOperationsRequired,
+    PatchOperationsSupport, PermissionClientRequired, PermissionModeRequired,
+    PermissionModeSupport, PermissionTrait, PopulateIndexMetricsOption,
+    PopulateIndexMetricsSupport, PopulateQueryMetricsOption, PopulateQueryMetricsSupport,
     QueryCrossPartitionOption, QueryCrossPartitionSupport, QueryRequired, QuerySupport,
-    StoredProcedureBodyRequired, StoredProcedureBodySupport, StoredProcedureNameRequired,
-    StoredProcedureNameSupport, StoredProcedureTrait, TriggerBodyRequired, TriggerBodySupport,
-    TriggerOperationRequired, TriggerOperationSupport, TriggerTrait, TriggerTypeRequired,
-    TriggerTypeSupport, UserClientRequired, UserDefinedFunctionBodyRequired,
-    UserDefinedFunctionBodySupport, UserDefinedFunctionTrait, UserName, UserNameRequired,
-    UserNameSupport, UserTrait,
+    RetryBackoffOption, RetryBackoffSupport, StoredProcedureBodyRequired,
+    StoredProcedureBodySupport, StoredProcedureNameRequired, StoredProcedureNameSupport,
+    StoredProcedureTrait, TransactionalBatchRequired, TransactionalBatchSupport,
+    TriggerBodyRequired, TriggerBodySupport, TriggerOperationRequired, TriggerOperationSupport,
+    TriggerTrait, TriggerTypeRequired, TriggerTypeSupport, TtlOption, TtlSupport,
+    UniqueKeyPolicyOption, UniqueKeyPolicySupport, UserClientRequired,
+    UserDefinedFunctionBodyRequired, UserDefinedFunctionBodySupport, UserDefinedFunctionTrait,
+    UserName, UserNameRequired, UserNameSupport, UserTrait,
 };
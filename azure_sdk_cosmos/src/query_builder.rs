@@ -0,0 +1,711 @@
+use crate::{Param, Query};
+use azure_sdk_core::errors::AzureError;
+use serde_json::Value;
+
+// Composable builder for Cosmos SQL queries. The values passed to the
+// conditions are never written in the query text: every one of them is
+// bound to a generated parameter (@p0, @p1, ...) so there is no need to
+// quote or escape them. Field and alias names are validated and the
+// property names that are not plain identifiers, or that are reserved
+// words, are written with the ["name"] syntax.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOperator {
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl ComparisonOperator {
+    fn as_str(self) -> &'static str {
+        match self {
+            ComparisonOperator::Equal => "=",
+            ComparisonOperator::NotEqual => "!=",
+            ComparisonOperator::LessThan => "<",
+            ComparisonOperator::LessThanOrEqual => "<=",
+            ComparisonOperator::GreaterThan => ">",
+            ComparisonOperator::GreaterThanOrEqual => ">=",
+        }
+    }
+}
+
+/// A property path such as `c.address.city`. The first segment must be
+/// the alias of the collection or of a join.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    path: String,
+}
+
+impl Field {
+    pub fn new<P: Into<String>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn eq<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::Equal, value)
+    }
+
+    pub fn ne<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::NotEqual, value)
+    }
+
+    pub fn lt<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::LessThan, value)
+    }
+
+    pub fn le<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::LessThanOrEqual, value)
+    }
+
+    pub fn gt<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::GreaterThan, value)
+    }
+
+    pub fn ge<T: Into<Value>>(self, value: T) -> Condition {
+        self.compare(ComparisonOperator::GreaterThanOrEqual, value)
+    }
+
+    pub fn compare<T: Into<Value>>(self, operator: ComparisonOperator, value: T) -> Condition {
+        Condition::Comparison {
+            field: self,
+            operator,
+            value: value.into(),
+        }
+    }
+
+    pub fn in_values<T: Into<Value>, I: IntoIterator<Item = T>>(self, values: I) -> Condition {
+        Condition::In {
+            field: self,
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn array_contains<T: Into<Value>>(self, value: T) -> Condition {
+        self.function("ARRAY_CONTAINS", vec![value.into()])
+    }
+
+    /// Matches the arrays containing an object with (at least) the
+    /// properties of the passed one.
+    pub fn array_contains_partial<T: Into<Value>>(self, value: T) -> Condition {
+        Condition::Function {
+            function: "ARRAY_CONTAINS",
+            field: self,
+            arguments: vec![value.into()],
+            trailing: Some("true"),
+        }
+    }
+
+    pub fn starts_with<T: Into<Value>>(self, value: T) -> Condition {
+        self.function("STARTSWITH", vec![value.into()])
+    }
+
+    pub fn ends_with<T: Into<Value>>(self, value: T) -> Condition {
+        self.function("ENDSWITH", vec![value.into()])
+    }
+
+    pub fn contains<T: Into<Value>>(self, value: T) -> Condition {
+        self.function("CONTAINS", vec![value.into()])
+    }
+
+    pub fn is_defined(self) -> Condition {
+        self.function("IS_DEFINED", vec![])
+    }
+
+    pub fn is_null(self) -> Condition {
+        self.function("IS_NULL", vec![])
+    }
+
+    pub fn is_string(self) -> Condition {
+        self.function("IS_STRING", vec![])
+    }
+
+    pub fn is_number(self) -> Condition {
+        self.function("IS_NUMBER", vec![])
+    }
+
+    pub fn is_bool(self) -> Condition {
+        self.function("IS_BOOL", vec![])
+    }
+
+    pub fn is_array(self) -> Condition {
+        self.function("IS_ARRAY", vec![])
+    }
+
+    fn function(self, function: &'static str, arguments: Vec<Value>) -> Condition {
+        Condition::Function {
+            function,
+            field: self,
+            arguments,
+            trailing: None,
+        }
+    }
+
+    fn render(&self) -> Result<String, AzureError> {
+        let mut segments = self.path.split('.');
+        // split always returns at least one segment
+        let alias = segments.next().unwrap();
+        check_identifier(alias)?;
+
+        let mut rendered = alias.to_owned();
+        for segment in segments {
+            if is_identifier(segment) && !is_keyword(segment) {
+                rendered.push('.');
+                rendered.push_str(segment);
+            } else {
+                rendered.push('[');
+                rendered.push_str(&serde_json::to_string(segment)?);
+                rendered.push(']');
+            }
+        }
+        Ok(rendered)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(path: &str) -> Self {
+        Field::new(path)
+    }
+}
+
+impl From<String> for Field {
+    fn from(path: String) -> Self {
+        Field::new(path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Comparison {
+        field: Field,
+        operator: ComparisonOperator,
+        value: Value,
+    },
+    In {
+        field: Field,
+        values: Vec<Value>,
+    },
+    Function {
+        function: &'static str,
+        field: Field,
+        arguments: Vec<Value>,
+        trailing: Option<&'static str>,
+    },
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+}
+
+impl Condition {
+    pub fn and(self, other: Condition) -> Condition {
+        Condition::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Condition) -> Condition {
+        Condition::Or(Box::new(self), Box::new(other))
+    }
+
+    fn render(&self, parameters: &mut Parameters) -> Result<String, AzureError> {
+        Ok(match self {
+            Condition::Comparison {
+                field,
+                operator,
+                value,
+            } => format!(
+                "{} {} {}",
+                field.render()?,
+                operator.as_str(),
+                parameters.bind(value.clone())
+            ),
+            Condition::In { field, values } => {
+                if values.is_empty() {
+                    return Err(AzureError::InputParametersError(format!(
+                        "IN condition on {} requires at least one value",
+                        field.path()
+                    )));
+                }
+                let names: Vec<String> = values
+                    .iter()
+                    .map(|value| parameters.bind(value.clone()))
+                    .collect();
+                format!("{} IN ({})", field.render()?, names.join(", "))
+            }
+            Condition::Function {
+                function,
+                field,
+                arguments,
+                trailing,
+            } => {
+                let mut rendered = vec![field.render()?];
+                rendered.extend(arguments.iter().map(|value| parameters.bind(value.clone())));
+                if let Some(trailing) = trailing {
+                    rendered.push((*trailing).to_owned());
+                }
+                format!("{}({})", function, rendered.join(", "))
+            }
+            Condition::And(left, right) => format!(
+                "({} AND {})",
+                left.render(parameters)?,
+                right.render(parameters)?
+            ),
+            Condition::Or(left, right) => format!(
+                "({} OR {})",
+                left.render(parameters)?,
+                right.render(parameters)?
+            ),
+            Condition::Not(condition) => format!("NOT ({})", condition.render(parameters)?),
+        })
+    }
+}
+
+impl std::ops::Not for Condition {
+    type Output = Condition;
+
+    fn not(self) -> Condition {
+        Condition::Not(Box::new(self))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Projection {
+    All,
+    Fields(Vec<(Field, Option<String>)>),
+    Value(Field),
+    Count,
+}
+
+#[derive(Debug, Default)]
+struct Parameters {
+    parameters: Vec<(String, Value)>,
+}
+
+impl Parameters {
+    fn bind(&mut self, value: Value) -> String {
+        let name = format!("@p{}", self.parameters.len());
+        self.parameters.push((name.clone(), value));
+        name
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryBuilder {
+    alias: String,
+    projection: Projection,
+    joins: Vec<(String, Field)>,
+    condition: Option<Condition>,
+    order_by: Vec<(Field, SortOrder)>,
+    top: Option<u64>,
+    offset_limit: Option<(u64, u64)>,
+}
+
+impl QueryBuilder {
+    /// Starts a `SELECT * FROM <alias>` query.
+    pub fn from<A: Into<String>>(alias: A) -> Self {
+        Self {
+            alias: alias.into(),
+            projection: Projection::All,
+            joins: Vec::new(),
+            condition: None,
+            order_by: Vec::new(),
+            top: None,
+            offset_limit: None,
+        }
+    }
+
+    pub fn select<F: Into<Field>, I: IntoIterator<Item = F>>(self, fields: I) -> Self {
+        Self {
+            projection: Projection::Fields(
+                fields
+                    .into_iter()
+                    .map(|field| (field.into(), None))
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Adds a projected field with an alias (`field AS alias`).
+    pub fn select_as<F: Into<Field>, A: Into<String>>(self, field: F, alias: A) -> Self {
+        let mut fields = match self.projection {
+            Projection::Fields(fields) => fields,
+            _ => Vec::new(),
+        };
+        fields.push((field.into(), Some(alias.into())));
+        Self {
+            projection: Projection::Fields(fields),
+            ..self
+        }
+    }
+
+    pub fn select_value<F: Into<Field>>(self, field: F) -> Self {
+        Self {
+            projection: Projection::Value(field.into()),
+            ..self
+        }
+    }
+
+    pub fn select_count(self) -> Self {
+        Self {
+            projection: Projection::Count,
+            ..self
+        }
+    }
+
+    /// Adds `JOIN <alias> IN <field>`.
+    pub fn join<A: Into<String>, F: Into<Field>>(mut self, alias: A, field: F) -> Self {
+        self.joins.push((alias.into(), field.into()));
+        self
+    }
+
+    /// Sets the WHERE clause. Calling it more than once combines the
+    /// conditions with AND.
+    pub fn filter(self, condition: Condition) -> Self {
+        let condition = match self.condition {
+            Some(previous) => previous.and(condition),
+            None => condition,
+        };
+        Self {
+            condition: Some(condition),
+            ..self
+        }
+    }
+
+    pub fn order_by<F: Into<Field>>(mut self, field: F, sort_order: SortOrder) -> Self {
+        self.order_by.push((field.into(), sort_order));
+        self
+    }
+
+    pub fn top(self, top: u64) -> Self {
+        Self {
+            top: Some(top),
+            ..self
+        }
+    }
+
+    pub fn offset_limit(self, offset: u64, limit: u64) -> Self {
+        Self {
+            offset_limit: Some((offset, limit)),
+            ..self
+        }
+    }
+
+    pub fn build(&self) -> Result<SqlQuery, AzureError> {
+        check_identifier(&self.alias)?;
+        if self.top.is_some() && self.offset_limit.is_some() {
+            return Err(AzureError::InputParametersError(
+                "TOP and OFFSET LIMIT cannot be used in the same query".to_owned(),
+            ));
+        }
+
+        let mut parameters = Parameters::default();
+        let mut sql = String::from("SELECT ");
+
+        if let Some(top) = self.top {
+            sql.push_str(&format!("TOP {} ", top));
+        }
+
+        match &self.projection {
+            Projection::All => sql.push('*'),
+            Projection::Count => sql.push_str("VALUE COUNT(1)"),
+            Projection::Value(field) => {
+                sql.push_str("VALUE ");
+                sql.push_str(&field.render()?);
+            }
+            Projection::Fields(fields) => {
+                if fields.is_empty() {
+                    return Err(AzureError::InputParametersError(
+                        "at least one field must be selected".to_owned(),
+                    ));
+                }
+                let mut rendered = Vec::with_capacity(fields.len());
+                for (field, alias) in fields {
+                    match alias {
+                        Some(alias) => {
+                            check_identifier(alias)?;
+                            rendered.push(format!("{} AS {}", field.render()?, alias));
+                        }
+                        None => rendered.push(field.render()?),
+                    }
+                }
+                sql.push_str(&rendered.join(", "));
+            }
+        }
+
+        sql.push_str(" FROM ");
+        sql.push_str(&self.alias);
+
+        for (alias, field) in self.joins.iter() {
+            check_identifier(alias)?;
+            sql.push_str(&format!(" JOIN {} IN {}", alias, field.render()?));
+        }
+
+        if let Some(condition) = &self.condition {
+            sql.push_str(" WHERE ");
+            sql.push_str(&condition.render(&mut parameters)?);
+        }
+
+        if !self.order_by.is_empty() {
+            let mut rendered = Vec::with_capacity(self.order_by.len());
+            for (field, sort_order) in self.order_by.iter() {
+                rendered.push(format!(
+                    "{} {}",
+                    field.render()?,
+                    match sort_order {
+                        SortOrder::Ascending => "ASC",
+                        SortOrder::Descending => "DESC",
+                    }
+                ));
+            }
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&rendered.join(", "));
+        }
+
+        if let Some((offset, limit)) = self.offset_limit {
+            sql.push_str(&format!(" OFFSET {} LIMIT {}", offset, limit));
+        }
+
+        Ok(SqlQuery {
+            sql,
+            parameters: parameters.parameters,
+        })
+    }
+}
+
+/// The result of `QueryBuilder::build`. It owns the query text and the
+/// bound parameters; call `query` to get the `Query` to pass to
+/// `QueryDocumentsBuilder`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlQuery {
+    sql: String,
+    parameters: Vec<(String, Value)>,
+}
+
+impl SqlQuery {
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    pub fn parameters(&self) -> &[(String, Value)] {
+        &self.parameters
+    }
+
+    pub fn query(&self) -> Query<'_> {
+        Query::with_params(
+            &self.sql,
+            self.parameters
+                .iter()
+                .map(|(name, value)| Param::new(name, value.clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+// Reserved words of the Cosmos SQL grammar, which are case insensitive.
+const KEYWORDS: &[&str] = &[
+    "and",
+    "array",
+    "as",
+    "asc",
+    "between",
+    "by",
+    "case",
+    "cast",
+    "convert",
+    "cross",
+    "desc",
+    "distinct",
+    "else",
+    "end",
+    "escape",
+    "exists",
+    "false",
+    "for",
+    "from",
+    "group",
+    "having",
+    "in",
+    "inner",
+    "insert",
+    "into",
+    "is",
+    "join",
+    "left",
+    "like",
+    "limit",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "outer",
+    "over",
+    "right",
+    "select",
+    "set",
+    "then",
+    "top",
+    "true",
+    "udf",
+    "undefined",
+    "update",
+    "value",
+    "when",
+    "where",
+    "with",
+    "infinity",
+    "nan",
+];
+
+fn is_keyword(s: &str) -> bool {
+    KEYWORDS
+        .iter()
+        .any(|keyword| keyword.eq_ignore_ascii_case(s))
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+fn check_identifier(s: &str) -> Result<(), AzureError> {
+    if is_keyword(s) {
+        Err(AzureError::InputParametersError(format!(
+            "{:?} is a reserved word and cannot be used as an alias",
+            s
+        )))
+    } else if is_identifier(s) {
+        Ok(())
+    } else {
+        Err(AzureError::InputParametersError(format!(
+            "{:?} is not a valid identifier",
+            s
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_query() {
+        let query = QueryBuilder::from("c")
+            .select(vec!["c.id", "c.address.city"])
+            .join("t", "c.tags")
+            .filter(
+                Field::new("c.age")
+                    .ge(18)
+                    .and(Field::new("c.name").starts_with("Fra'; DROP"))
+                    .or(!Field::new("t").array_contains("rust")),
+            )
+            .filter(Field::new("c.first name").in_values(vec!["a", "b"]))
+            .order_by("c.age", SortOrder::Descending)
+            .offset_limit(10, 5)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            query.sql(),
+            r#"SELECT c.id, c.address.city FROM c JOIN t IN c.tags WHERE (((c.age >= @p0 AND STARTSWITH(c.name, @p1)) OR NOT (ARRAY_CONTAINS(t, @p2))) AND c["first name"] IN (@p3, @p4)) ORDER BY c.age DESC OFFSET 10 LIMIT 5"#
+        );
+
+        let ser = serde_json::to_string(&query.query()).unwrap();
+        assert_eq!(
+            ser,
+            r#"{"query":"SELECT c.id, c.address.city FROM c JOIN t IN c.tags WHERE (((c.age >= @p0 AND STARTSWITH(c.name, @p1)) OR NOT (ARRAY_CONTAINS(t, @p2))) AND c[\"first name\"] IN (@p3, @p4)) ORDER BY c.age DESC OFFSET 10 LIMIT 5","parameters":[{"name":"@p0","value":18},{"name":"@p1","value":"Fra'; DROP"},{"name":"@p2","value":"rust"},{"name":"@p3","value":"a"},{"name":"@p4","value":"b"}]}"#
+        );
+    }
+
+    #[test]
+    fn build_query_projections() {
+        assert_eq!(
+            QueryBuilder::from("c")
+                .select_count()
+                .filter(!Field::new("c.deleted").is_defined())
+                .build()
+                .unwrap()
+                .sql(),
+            "SELECT VALUE COUNT(1) FROM c WHERE NOT (IS_DEFINED(c.deleted))"
+        );
+        assert_eq!(
+            QueryBuilder::from("c")
+                .select_value("c.id")
+                .top(3)
+                .build()
+                .unwrap()
+                .sql(),
+            "SELECT TOP 3 VALUE c.id FROM c"
+        );
+        assert_eq!(
+            QueryBuilder::from("c")
+                .select_as("c.id", "identifier")
+                .build()
+                .unwrap()
+                .sql(),
+            "SELECT c.id AS identifier FROM c"
+        );
+    }
+
+    #[test]
+    fn build_query_rejects_invalid_identifiers() {
+        assert!(QueryBuilder::from("c; DROP").build().is_err());
+        assert!(QueryBuilder::from("c")
+            .select(vec!["c) OR 1=1"])
+            .build()
+            .is_err());
+        assert!(QueryBuilder::from("c")
+            .top(1)
+            .offset_limit(0, 1)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn build_query_quotes_reserved_words() {
+        assert_eq!(
+            QueryBuilder::from("c")
+                .select(vec!["c.value", "c.address.Order"])
+                .filter(Field::new("c.top").eq(1))
+                .order_by("c.value", SortOrder::Ascending)
+                .build()
+                .unwrap()
+                .sql(),
+            r#"SELECT c["value"], c.address["Order"] FROM c WHERE c["top"] = @p0 ORDER BY c["value"] ASC"#
+        );
+    }
+
+    #[test]
+    fn build_query_rejects_reserved_aliases() {
+        assert!(QueryBuilder::from("value").build().is_err());
+        assert!(QueryBuilder::from("c")
+            .select_as("c.id", "Order")
+            .build()
+            .is_err());
+        assert!(QueryBuilder::from("c")
+            .join("in", "c.tags")
+            .build()
+            .is_err());
+        assert!(QueryBuilder::from("c")
+            .select(vec!["select.id"])
+            .build()
+            .is_err());
+    }
+}
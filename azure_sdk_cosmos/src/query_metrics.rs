@@ -0,0 +1,308 @@
+use azure_sdk_core::errors::AzureError;
+use std::time::Duration;
+
+const TOTAL_EXECUTION_TIME: &str = "totalExecutionTimeInMs";
+const QUERY_COMPILE_TIME: &str = "queryCompileTimeInMs";
+const LOGICAL_PLAN_BUILD_TIME: &str = "queryLogicalPlanBuildTimeInMs";
+const PHYSICAL_PLAN_BUILD_TIME: &str = "queryPhysicalPlanBuildTimeInMs";
+const QUERY_OPTIMIZATION_TIME: &str = "queryOptimizationTimeInMs";
+const VM_EXECUTION_TIME: &str = "VMExecutionTimeInMs";
+const INDEX_LOOKUP_TIME: &str = "indexLookupTimeInMs";
+const DOCUMENT_LOAD_TIME: &str = "documentLoadTimeInMs";
+const SYSTEM_FUNCTION_EXECUTION_TIME: &str = "systemFunctionExecuteTimeInMs";
+const USER_FUNCTION_EXECUTION_TIME: &str = "userFunctionExecuteTimeInMs";
+const DOCUMENT_WRITE_TIME: &str = "writeOutputTimeInMs";
+const RETRIEVED_DOCUMENT_COUNT: &str = "retrievedDocumentCount";
+const RETRIEVED_DOCUMENT_SIZE: &str = "retrievedDocumentSize";
+const OUTPUT_DOCUMENT_COUNT: &str = "outputDocumentCount";
+const OUTPUT_DOCUMENT_SIZE: &str = "outputDocumentSize";
+const INDEX_HIT_RATIO: &str = "indexUtilizationRatio";
+
+/// Server side execution metrics of a query, returned in the
+/// `x-ms-documentdb-query-metrics` header when the query is executed
+/// with `with_populate_query_metrics(true)`. Metrics of several pages
+/// can be summed up with `+`, `+=` or `Iterator::sum`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryMetrics {
+    pub retrieved_document_count: u64,
+    pub retrieved_document_size: u64,
+    pub output_document_count: u64,
+    pub output_document_size: u64,
+    /// Fraction of the retrieved documents that matched the filter
+    /// through the index alone, from 0 to 1.
+    pub index_hit_ratio: f64,
+    pub total_execution_time: Duration,
+    pub query_compile_time: Duration,
+    pub logical_plan_build_time: Duration,
+    pub physical_plan_build_time: Duration,
+    pub query_optimization_time: Duration,
+    pub vm_execution_time: Duration,
+    pub index_lookup_time: Duration,
+    pub document_load_time: Duration,
+    pub system_function_execution_time: Duration,
+    pub user_function_execution_time: Duration,
+    pub document_write_time: Duration,
+}
+
+fn duration_from_millis(value: &str) -> Result<Duration, AzureError> {
+    let millis: f64 = value.parse()?;
+    let secs = millis.max(0.0) / 1000.0;
+    // Duration::from_secs_f64 panics on infinite and overflowing values
+    if !secs.is_finite() || secs >= u64::MAX as f64 {
+        return Err(AzureError::GenericErrorWithText(format!(
+            "invalid query metrics duration \"{}\"",
+            value
+        )));
+    }
+    Ok(Duration::from_secs_f64(secs))
+}
+
+impl std::str::FromStr for QueryMetrics {
+    type Err = AzureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        debug!("QueryMetrics::from_str(\"{}\") called", s);
+        let mut query_metrics = QueryMetrics::default();
+
+        for token in s.split(';').filter(|token| !token.is_empty()) {
+            let mut key_value = token.splitn(2, '=');
+            let key = key_value.next().unwrap_or_default().trim();
+            let value = key_value
+                .next()
+                .ok_or_else(|| {
+                    AzureError::GenericErrorWithText(format!(
+                        "invalid query metrics token \"{}\" in \"{}\"",
+                        token, s
+                    ))
+                })?
+                .trim();
+
+            match key {
+                TOTAL_EXECUTION_TIME => {
+                    query_metrics.total_execution_time = duration_from_millis(value)?
+                }
+                QUERY_COMPILE_TIME => {
+                    query_metrics.query_compile_time = duration_from_millis(value)?
+                }
+                LOGICAL_PLAN_BUILD_TIME => {
+                    query_metrics.logical_plan_build_time = duration_from_millis(value)?
+                }
+                PHYSICAL_PLAN_BUILD_TIME => {
+                    query_metrics.physical_plan_build_time = duration_from_millis(value)?
+                }
+                QUERY_OPTIMIZATION_TIME => {
+                    query_metrics.query_optimization_time = duration_from_millis(value)?
+                }
+                VM_EXECUTION_TIME => query_metrics.vm_execution_time = duration_from_millis(value)?,
+                INDEX_LOOKUP_TIME => query_metrics.index_lookup_time = duration_from_millis(value)?,
+                DOCUMENT_LOAD_TIME => {
+                    query_metrics.document_load_time = duration_from_millis(value)?
+                }
+                SYSTEM_FUNCTION_EXECUTION_TIME => {
+                    query_metrics.system_function_execution_time = duration_from_millis(value)?
+                }
+                USER_FUNCTION_EXECUTION_TIME => {
+                    query_metrics.user_function_execution_time = duration_from_millis(value)?
+                }
+                DOCUMENT_WRITE_TIME => {
+                    query_metrics.document_write_time = duration_from_millis(value)?
+                }
+                RETRIEVED_DOCUMENT_COUNT => {
+                    query_metrics.retrieved_document_count = value.parse()?
+                }
+                RETRIEVED_DOCUMENT_SIZE => query_metrics.retrieved_document_size = value.parse()?,
+                OUTPUT_DOCUMENT_COUNT => query_metrics.output_document_count = value.parse()?,
+                OUTPUT_DOCUMENT_SIZE => query_metrics.output_document_size = value.parse()?,
+                INDEX_HIT_RATIO => query_metrics.index_hit_ratio = value.parse()?,
+                // the service adds new metrics from time to time
+                _ => debug!("skipping unknown query metric {}", token),
+            }
+        }
+
+        Ok(query_metrics)
+    }
+}
+
+impl std::ops::AddAssign for QueryMetrics {
+    fn add_assign(&mut self, other: Self) {
+        // the ratio is weighted by the documents retrieved by each page
+        let retrieved_document_count =
+            self.retrieved_document_count + other.retrieved_document_count;
+        self.index_hit_ratio = if retrieved_document_count == 0 {
+            0.0
+        } else {
+            (self.index_hit_ratio * self.retrieved_document_count as f64
+                + other.index_hit_ratio * other.retrieved_document_count as f64)
+                / retrieved_document_count as f64
+        };
+
+        self.retrieved_document_count = retrieved_document_count;
+        self.retrieved_document_size += other.retrieved_document_size;
+        self.output_document_count += other.output_document_count;
+        self.output_document_size += other.output_document_size;
+        self.total_execution_time += other.total_execution_time;
+        self.query_compile_time += other.query_compile_time;
+        self.logical_plan_build_time += other.logical_plan_build_time;
+        self.physical_plan_build_time += other.physical_plan_build_time;
+        self.query_optimization_time += other.query_optimization_time;
+        self.vm_execution_time += other.vm_execution_time;
+        self.index_lookup_time += other.index_lookup_time;
+        self.document_load_time += other.document_load_time;
+        self.system_function_execution_time += other.system_function_execution_time;
+        self.user_function_execution_time += other.user_function_execution_time;
+        self.document_write_time += other.document_write_time;
+    }
+}
+
+impl std::ops::Add for QueryMetrics {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl std::iter::Sum for QueryMetrics {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(QueryMetrics::default(), |total, query_metrics| {
+            total + query_metrics
+        })
+    }
+}
+
+/// A single path index either used by the query or that would have helped
+/// it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SingleIndexMetric {
+    pub filter_expression: String,
+    pub index_spec: String,
+    pub filter_precise_set: bool,
+    pub index_precise_set: bool,
+    #[serde(default)]
+    pub index_impact_score: Option<String>,
+}
+
+/// A composite index either used by the query or that would have helped
+/// it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompositeIndexMetric {
+    pub index_specs: Vec<String>,
+    pub index_precise_set: bool,
+    #[serde(default)]
+    pub index_impact_score: Option<String>,
+}
+
+/// Index utilization of a query, returned in the
+/// `x-ms-cosmos-index-utilization` header when the query is executed with
+/// `with_populate_index_metrics(true)`. The potential indexes are the
+/// service recommendations: adding them to the indexing policy should
+/// lower the request charge of the query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct IndexMetrics {
+    pub utilized_single_indexes: Vec<SingleIndexMetric>,
+    pub potential_single_indexes: Vec<SingleIndexMetric>,
+    pub utilized_composite_indexes: Vec<CompositeIndexMetric>,
+    pub potential_composite_indexes: Vec<CompositeIndexMetric>,
+}
+
+impl IndexMetrics {
+    pub fn has_recommendations(&self) -> bool {
+        !self.potential_single_indexes.is_empty() || !self.potential_composite_indexes.is_empty()
+    }
+}
+
+impl std::str::FromStr for IndexMetrics {
+    type Err = AzureError;
+
+    /// Parses the base64 encoded JSON sent by the service.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = base64::decode(s.trim())?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUERY_METRICS: &str = "totalExecutionTimeInMs=33.67;queryCompileTimeInMs=0.06;\
+        queryLogicalPlanBuildTimeInMs=0.02;queryPhysicalPlanBuildTimeInMs=0.10;\
+        queryOptimizationTimeInMs=0.00;VMExecutionTimeInMs=32.56;indexLookupTimeInMs=0.36;\
+        documentLoadTimeInMs=9.58;systemFunctionExecuteTimeInMs=0.00;\
+        userFunctionExecuteTimeInMs=0.00;retrievedDocumentCount=2000;\
+        retrievedDocumentSize=1125600;outputDocumentCount=2000;outputDocumentSize=1125600;\
+        writeOutputTimeInMs=18.10;indexUtilizationRatio=1.00";
+
+    #[test]
+    fn parse_query_metrics() {
+        let query_metrics: QueryMetrics = QUERY_METRICS.parse().unwrap();
+
+        assert_eq!(query_metrics.retrieved_document_count, 2000);
+        assert_eq!(query_metrics.retrieved_document_size, 1_125_600);
+        assert_eq!(query_metrics.output_document_count, 2000);
+        assert_eq!(query_metrics.index_hit_ratio, 1.0);
+        assert_eq!(
+            query_metrics.total_execution_time,
+            Duration::from_secs_f64(0.03367)
+        );
+        assert_eq!(
+            query_metrics.query_compile_time,
+            Duration::from_secs_f64(0.00006)
+        );
+
+        assert!("retrievedDocumentCount".parse::<QueryMetrics>().is_err());
+        assert!("retrievedDocumentCount=x".parse::<QueryMetrics>().is_err());
+        assert!("totalExecutionTimeInMs=inf"
+            .parse::<QueryMetrics>()
+            .is_err());
+        assert!("totalExecutionTimeInMs=1e300"
+            .parse::<QueryMetrics>()
+            .is_err());
+        assert_eq!(
+            "someNewMetric=1;".parse::<QueryMetrics>().unwrap(),
+            QueryMetrics::default()
+        );
+    }
+
+    #[test]
+    fn sum_query_metrics() {
+        let first = QueryMetrics {
+            retrieved_document_count: 300,
+            index_hit_ratio: 1.0,
+            total_execution_time: Duration::from_millis(10),
+            ..QueryMetrics::default()
+        };
+        let second = QueryMetrics {
+            retrieved_document_count: 100,
+            index_hit_ratio: 0.0,
+            total_execution_time: Duration::from_millis(5),
+            ..QueryMetrics::default()
+        };
+
+        let total: QueryMetrics = vec![first, second].into_iter().sum();
+        assert_eq!(total.retrieved_document_count, 400);
+        assert_eq!(total.index_hit_ratio, 0.75);
+        assert_eq!(total.total_execution_time, Duration::from_millis(15));
+    }
+
+    #[test]
+    fn parse_index_metrics() {
+        let json = r#"{"UtilizedSingleIndexes":[{"FilterExpression":"","IndexSpec":"\/name\/?","FilterPreciseSet":true,"IndexPreciseSet":true,"IndexImpactScore":"High"}],"PotentialSingleIndexes":[],"UtilizedCompositeIndexes":[],"PotentialCompositeIndexes":[{"IndexSpecs":["\/name ASC","\/age ASC"],"IndexPreciseSet":false,"IndexImpactScore":"High"}]}"#;
+        let index_metrics: IndexMetrics = base64::encode(json).parse().unwrap();
+
+        assert_eq!(index_metrics.utilized_single_indexes.len(), 1);
+        assert_eq!(
+            index_metrics.utilized_single_indexes[0].index_spec,
+            "/name/?"
+        );
+        assert!(index_metrics.has_recommendations());
+        assert_eq!(
+            index_metrics.potential_composite_indexes[0].index_specs,
+            vec!["/name ASC", "/age ASC"]
+        );
+    }
+}
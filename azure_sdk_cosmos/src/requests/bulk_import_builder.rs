@@ -0,0 +1,557 @@
+use crate::clients::{CollectionClient, CosmosUriBuilder};
+use crate::collection::PartitionKey;
+use crate::diagnostics::{retrying, with_diagnostics_context_of};
+use crate::from_headers::{request_charge_from_headers_optional, retry_after_from_headers};
+use crate::partition_key_hash::{effective_partition_key, PartitionKeyComponent};
+use crate::prelude::*;
+use crate::requests::create_document_builder::create_document_response;
+use crate::responses::{
+    BulkImportFailure, BulkImportResponse, CreateDocumentResponse, GetPartitionKeyRangesResponse,
+};
+use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{No, ToAssign, Yes};
+use futures::stream::{FuturesUnordered, StreamExt};
+use hyper::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    collection_client: &'a CollectionClient<'a, CUB>,
+    p_documents: PhantomData<DocumentsSet>,
+    documents: Option<&'b [T]>,
+    is_upsert: bool,
+    max_concurrency_per_range: u32,
+    max_throttling_retries: u32,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, T, CUB> BulkImportBuilder<'a, 'b, T, CUB, No>
+where
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        collection_client: &'a CollectionClient<'a, CUB>,
+    ) -> BulkImportBuilder<'a, 'b, T, CUB, No> {
+        BulkImportBuilder {
+            collection_client,
+            p_documents: PhantomData {},
+            documents: None,
+            is_upsert: false,
+            max_concurrency_per_range: 5,
+            max_throttling_retries: 9,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> CollectionClientRequired<'a, CUB>
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn collection_client(&self) -> &'a CollectionClient<'a, CUB> {
+        self.collection_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, T, CUB> DocumentsRequired<'b, T> for BulkImportBuilder<'a, 'b, T, CUB, Yes>
+where
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn documents(&self) -> &'b [T] {
+        self.documents.unwrap()
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> IsUpsertOption
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn is_upsert(&self) -> bool {
+        self.is_upsert
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> MaxConcurrencyPerRangeOption
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn max_concurrency_per_range(&self) -> u32 {
+        self.max_concurrency_per_range
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> MaxThrottlingRetriesOption
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn max_throttling_retries(&self) -> u32 {
+        self.max_throttling_retries
+    }
+}
+
+impl<'a, 'b, T, CUB> DocumentsSupport<'b, T> for BulkImportBuilder<'a, 'b, T, CUB, No>
+where
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = BulkImportBuilder<'a, 'b, T, CUB, Yes>;
+
+    #[inline]
+    fn with_documents(self, documents: &'b [T]) -> Self::O {
+        BulkImportBuilder {
+            collection_client: self.collection_client,
+            p_documents: PhantomData {},
+            documents: Some(documents),
+            is_upsert: self.is_upsert,
+            max_concurrency_per_range: self.max_concurrency_per_range,
+            max_throttling_retries: self.max_throttling_retries,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> IsUpsertSupport
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>;
+
+    #[inline]
+    fn with_is_upsert(self, is_upsert: bool) -> Self::O {
+        BulkImportBuilder {
+            collection_client: self.collection_client,
+            p_documents: PhantomData {},
+            documents: self.documents,
+            is_upsert,
+            max_concurrency_per_range: self.max_concurrency_per_range,
+            max_throttling_retries: self.max_throttling_retries,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> MaxConcurrencyPerRangeSupport
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>;
+
+    #[inline]
+    fn with_max_concurrency_per_range(self, max_concurrency_per_range: u32) -> Self::O {
+        BulkImportBuilder {
+            collection_client: self.collection_client,
+            p_documents: PhantomData {},
+            documents: self.documents,
+            is_upsert: self.is_upsert,
+            max_concurrency_per_range,
+            max_throttling_retries: self.max_throttling_retries,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> MaxThrottlingRetriesSupport
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>;
+
+    #[inline]
+    fn with_max_throttling_retries(self, max_throttling_retries: u32) -> Self::O {
+        BulkImportBuilder {
+            collection_client: self.collection_client,
+            p_documents: PhantomData {},
+            documents: self.documents,
+            is_upsert: self.is_upsert,
+            max_concurrency_per_range: self.max_concurrency_per_range,
+            max_throttling_retries,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PendingDocument {
+    index: usize,
+    document: Document<Value>,
+    partition_keys: PartitionKeys,
+    attempts: u32,
+}
+
+// The outcome of a single request. Throttled and failed requests are
+// charged too.
+#[derive(Debug)]
+struct Attempt {
+    result: Result<CreateDocumentResponse, AzureError>,
+    charge: f64,
+    retry_after: Option<Duration>,
+}
+
+// What completed in a range: a request, or the backoff of a throttled
+// document, which does not count against the concurrency while it waits.
+#[derive(Debug)]
+enum Step {
+    Sent(PendingDocument, Box<Attempt>),
+    BackedOff(PendingDocument),
+}
+
+#[derive(Debug, Default)]
+struct RangeOutcome {
+    documents_imported: usize,
+    failures: Vec<BulkImportFailure>,
+    charge: f64,
+    throttled_requests: u64,
+}
+
+fn is_throttled(error: &AzureError) -> bool {
+    match error {
+        AzureError::UnexpectedHTTPResult(result) => {
+            result.status_code() == StatusCode::TOO_MANY_REQUESTS
+        }
+        _ => false,
+    }
+}
+
+fn throttling_backoff(attempts: u32) -> Duration {
+    Duration::from_millis(100 << attempts.min(6))
+}
+
+// Walks the document following a partition key path such as /address/city.
+fn partition_key_value<'v>(document: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(document, |value, segment| {
+            value.get(segment.trim_matches('"'))
+        })
+}
+
+// Builds the request document along with its partition keys header and
+// returns the id of the owning partition key range.
+fn prepare_document<T: Serialize>(
+    index: usize,
+    document: &T,
+    partition_key: &PartitionKey,
+    partition_key_ranges: &GetPartitionKeyRangesResponse,
+) -> Result<(String, PendingDocument), AzureError> {
+    let value = serde_json::to_value(document)?;
+
+    let mut partition_keys = PartitionKeys::new();
+    let mut components = Vec::with_capacity(partition_key.paths.len());
+    for path in partition_key.paths.iter() {
+        let component_value = partition_key_value(&value, path);
+        let component = PartitionKeyComponent::from_value(component_value);
+        match component {
+            PartitionKeyComponent::Undefined => partition_keys.push(serde_json::json!({}))?,
+            _ => partition_keys.push(component_value)?,
+        };
+        components.push(component);
+    }
+
+    let range = if components.is_empty() {
+        partition_key_ranges.partition_key_ranges.first()
+    } else {
+        let effective_partition_key =
+            effective_partition_key(&components, partition_key.hash_version());
+        partition_key_ranges
+            .partition_key_range_for_effective_partition_key(&effective_partition_key)
+    }
+    .ok_or_else(|| {
+        AzureError::GenericErrorWithText(format!(
+            "no partition key range found for document {}",
+            index
+        ))
+    })?;
+
+    Ok((
+        range.id.clone(),
+        PendingDocument {
+            index,
+            document: Document::new(value),
+            partition_keys,
+            attempts: 0,
+        },
+    ))
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> DiagnosticsContextOption<'a>
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentsSet> DiagnosticsContextSupport<'a>
+    for BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>
+where
+    DocumentsSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = BulkImportBuilder<'a, 'b, T, CUB, DocumentsSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        BulkImportBuilder {
+            collection_client: self.collection_client,
+            p_documents: self.p_documents,
+            documents: self.documents,
+            is_upsert: self.is_upsert,
+            max_concurrency_per_range: self.max_concurrency_per_range,
+            max_throttling_retries: self.max_throttling_retries,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, T, CUB> BulkImportBuilder<'a, 'b, T, CUB, Yes>
+where
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute(&self) -> Result<BulkImportResponse, AzureError> {
+        trace!("BulkImportBuilder::execute called");
+
+        if self.max_concurrency_per_range() == 0 {
+            return Err(AzureError::InputParametersError(
+                "max_concurrency_per_range must be greater than zero".to_owned(),
+            ));
+        }
+
+        let start = Instant::now();
+
+        let partition_key = with_diagnostics_context_of(
+            self.collection_client.get_collection(),
+            self.diagnostics_context(),
+        )
+        .execute()
+        .await?
+        .collection
+        .parition_key;
+        let partition_key_ranges = with_diagnostics_context_of(
+            self.collection_client.get_partition_key_ranges(),
+            self.diagnostics_context(),
+        )
+        .execute()
+        .await?;
+
+        let mut failures = Vec::new();
+        let mut documents_by_range: HashMap<String, Vec<PendingDocument>> = HashMap::new();
+        for (index, document) in self.documents().iter().enumerate() {
+            match prepare_document(index, document, &partition_key, &partition_key_ranges) {
+                Ok((range_id, pending_document)) => documents_by_range
+                    .entry(range_id)
+                    .or_default()
+                    .push(pending_document),
+                Err(error) => failures.push(BulkImportFailure {
+                    index,
+                    document_id: None,
+                    error,
+                }),
+            }
+        }
+        debug!(
+            "importing {} documents in {} partition key ranges",
+            self.documents().len(),
+            documents_by_range.len()
+        );
+
+        // HashMap::into_values needs Rust 1.54.
+        #[allow(clippy::iter_kv_map)]
+        let outcomes = futures::future::join_all(
+            documents_by_range
+                .into_iter()
+                .map(|(_, documents)| self.import_range(documents)),
+        )
+        .await;
+
+        let mut response = BulkImportResponse {
+            documents_imported: 0,
+            failures,
+            charge: 0.0,
+            throttled_requests: 0,
+            partition_key_ranges: partition_key_ranges.partition_key_ranges.len(),
+            elapsed: Duration::default(),
+        };
+        for outcome in outcomes {
+            response.documents_imported += outcome.documents_imported;
+            response.failures.extend(outcome.failures);
+            response.charge += outcome.charge;
+            response.throttled_requests += outcome.throttled_requests;
+        }
+        response.failures.sort_by_key(|failure| failure.index);
+        response.elapsed = start.elapsed();
+
+        Ok(response)
+    }
+
+    // Sends the documents of a single partition key range keeping at most
+    // max_concurrency_per_range requests in flight. The window is halved
+    // every time Cosmos throttles us and grows back by one for every
+    // success. Throttled documents wait for the delay asked by Cosmos
+    // without taking a slot, then go back to the head of the queue.
+    async fn import_range(&self, documents: Vec<PendingDocument>) -> RangeOutcome {
+        let max_concurrency = self.max_concurrency_per_range() as usize;
+        let mut concurrency = max_concurrency;
+        let mut pending: VecDeque<PendingDocument> = documents.into();
+        let mut in_flight = FuturesUnordered::new();
+        let mut sending = 0;
+        let mut outcome = RangeOutcome::default();
+
+        loop {
+            while sending < concurrency {
+                match pending.pop_front() {
+                    Some(document) => {
+                        sending += 1;
+                        in_flight.push(self.import_step(document, None));
+                    }
+                    None => break,
+                }
+            }
+
+            let (mut document, attempt) = match in_flight.next().await {
+                Some(Step::Sent(document, attempt)) => {
+                    sending -= 1;
+                    (document, *attempt)
+                }
+                Some(Step::BackedOff(document)) => {
+                    pending.push_front(document);
+                    continue;
+                }
+                None => break,
+            };
+            outcome.charge += attempt.charge;
+
+            match attempt.result {
+                Ok(_) => {
+                    outcome.documents_imported += 1;
+                    if concurrency < max_concurrency {
+                        concurrency += 1;
+                    }
+                }
+                Err(ref error)
+                    if is_throttled(error) && document.attempts < self.max_throttling_retries() =>
+                {
+                    outcome.throttled_requests += 1;
+                    concurrency = std::cmp::max(1, concurrency / 2);
+
+                    let backoff = attempt
+                        .retry_after
+                        .unwrap_or_else(|| throttling_backoff(document.attempts));
+                    debug!(
+                        "document {} throttled, retrying in {:?} with concurrency {}",
+                        document.index, backoff, concurrency
+                    );
+
+                    document.attempts += 1;
+                    in_flight.push(self.import_step(document, Some(backoff)));
+                }
+                Err(error) => outcome.failures.push(BulkImportFailure {
+                    index: document.index,
+                    document_id: document
+                        .document
+                        .document
+                        .get("id")
+                        .and_then(Value::as_str)
+                        .map(str::to_owned),
+                    error,
+                }),
+            }
+        }
+
+        outcome
+    }
+
+    async fn import_step(&self, document: PendingDocument, backoff: Option<Duration>) -> Step {
+        match backoff {
+            Some(backoff) => {
+                tokio::time::delay_for(backoff).await;
+                Step::BackedOff(document)
+            }
+            None => {
+                let (document, attempt) = self.import_document(document).await;
+                Step::Sent(document, Box::new(attempt))
+            }
+        }
+    }
+
+    async fn import_document(&self, document: PendingDocument) -> (PendingDocument, Attempt) {
+        let create_builder = with_diagnostics_context_of(
+            self.collection_client
+                .create_document()
+                .with_document(&document.document)
+                .with_partition_keys(&document.partition_keys)
+                .with_is_upsert(self.is_upsert()),
+            self.diagnostics_context(),
+        );
+        let response = if document.attempts > 0 {
+            retrying(create_builder.execute_unchecked()).await
+        } else {
+            create_builder.execute_unchecked().await
+        };
+
+        let attempt = match response {
+            Ok((status_code, headers, body)) => Attempt {
+                charge: request_charge_from_headers_optional(&headers)
+                    .unwrap_or_default()
+                    .unwrap_or_default(),
+                retry_after: retry_after_from_headers(&headers).unwrap_or_default(),
+                result: create_document_response(self.is_upsert(), status_code, &headers, &body),
+            },
+            Err(error) => Attempt {
+                charge: 0.0,
+                retry_after: None,
+                result: Err(error),
+            },
+        };
+
+        (document, attempt)
+    }
+}
@@ -1,8 +1,11 @@
 use crate::clients::{CosmosUriBuilder, DatabaseClient, ResourceType};
 use crate::collection::CollectionName;
-use crate::collection::{Collection, IndexingPolicy, PartitionKey};
+use crate::collection::{
+    Collection, ConflictResolutionPolicy, IndexingPolicy, PartitionKey, UniqueKeyPolicy,
+};
 use crate::prelude::*;
 use crate::responses::CreateCollectionResponse;
+use crate::DiagnosticsContext;
 use crate::Offer;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
@@ -35,9 +38,13 @@ pub struct CreateCollectionBuilder<
     collection_name: Option<&'a dyn CollectionName>,
     indexing_policy: Option<&'a IndexingPolicy>,
     partition_key: Option<&'a PartitionKey>,
+    default_ttl: Option<i64>,
+    unique_key_policy: Option<&'a UniqueKeyPolicy>,
+    conflict_resolution_policy: Option<&'a ConflictResolutionPolicy>,
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> CreateCollectionBuilder<'a, CUB, No, No, No, No>
@@ -58,9 +65,13 @@ where
             indexing_policy: None,
             p_partition_key: PhantomData {},
             partition_key: None,
+            default_ttl: None,
+            unique_key_policy: None,
+            conflict_resolution_policy: None,
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -147,6 +158,74 @@ where
     }
 }
 
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet> DefaultTtlOption
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn default_ttl(&self) -> Option<i64> {
+        self.default_ttl
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    UniqueKeyPolicyOption<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn unique_key_policy(&self) -> Option<&'a UniqueKeyPolicy> {
+        self.unique_key_policy
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    ConflictResolutionPolicyOption<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn conflict_resolution_policy(&self) -> Option<&'a ConflictResolutionPolicy> {
+        self.conflict_resolution_policy
+    }
+}
+
 impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet> UserAgentOption<'a>
     for CreateCollectionBuilder<
         'a,
@@ -243,9 +322,13 @@ where
             collection_name: self.collection_name,
             indexing_policy: self.indexing_policy,
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -272,9 +355,13 @@ where
             collection_name: Some(collection_name),
             indexing_policy: self.indexing_policy,
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -301,9 +388,13 @@ where
             collection_name: self.collection_name,
             indexing_policy: Some(indexing_policy),
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -330,9 +421,162 @@ where
             collection_name: self.collection_name,
             indexing_policy: self.indexing_policy,
             partition_key: Some(partition_key),
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet> DefaultTtlSupport
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >;
+
+    #[inline]
+    fn with_default_ttl(self, default_ttl: i64) -> Self::O {
+        CreateCollectionBuilder {
+            database_client: self.database_client,
+            p_offer: PhantomData {},
+            p_collection_name: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            p_partition_key: PhantomData {},
+            offer: self.offer,
+            collection_name: self.collection_name,
+            indexing_policy: self.indexing_policy,
+            partition_key: self.partition_key,
+            default_ttl: Some(default_ttl),
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    UniqueKeyPolicySupport<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >;
+
+    #[inline]
+    fn with_unique_key_policy(self, unique_key_policy: &'a UniqueKeyPolicy) -> Self::O {
+        CreateCollectionBuilder {
+            database_client: self.database_client,
+            p_offer: PhantomData {},
+            p_collection_name: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            p_partition_key: PhantomData {},
+            offer: self.offer,
+            collection_name: self.collection_name,
+            indexing_policy: self.indexing_policy,
+            partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: Some(unique_key_policy),
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    ConflictResolutionPolicySupport<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >;
+
+    #[inline]
+    fn with_conflict_resolution_policy(
+        self,
+        conflict_resolution_policy: &'a ConflictResolutionPolicy,
+    ) -> Self::O {
+        CreateCollectionBuilder {
+            database_client: self.database_client,
+            p_offer: PhantomData {},
+            p_collection_name: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            p_partition_key: PhantomData {},
+            offer: self.offer,
+            collection_name: self.collection_name,
+            indexing_policy: self.indexing_policy,
+            partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: Some(conflict_resolution_policy),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -374,9 +618,13 @@ where
             collection_name: self.collection_name,
             indexing_policy: self.indexing_policy,
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -418,9 +666,13 @@ where
             collection_name: self.collection_name,
             indexing_policy: self.indexing_policy,
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -463,9 +715,83 @@ where
             collection_name: self.collection_name,
             indexing_policy: self.indexing_policy,
             partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    DiagnosticsContextOption<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, OfferSet, CollectionNameSet, IndexingPolicySet, PartitionKeySet>
+    DiagnosticsContextSupport<'a>
+    for CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >
+where
+    OfferSet: ToAssign,
+    CollectionNameSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    PartitionKeySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateCollectionBuilder<
+        'a,
+        CUB,
+        OfferSet,
+        CollectionNameSet,
+        IndexingPolicySet,
+        PartitionKeySet,
+    >;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateCollectionBuilder {
+            database_client: self.database_client,
+            p_offer: self.p_offer,
+            p_collection_name: self.p_collection_name,
+            p_indexing_policy: self.p_indexing_policy,
+            p_partition_key: self.p_partition_key,
+            offer: self.offer,
+            collection_name: self.collection_name,
+            indexing_policy: self.indexing_policy,
+            partition_key: self.partition_key,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -501,7 +827,7 @@ where
             &format!("dbs/{}/colls", self.database_client.database_name().name()),
             hyper::Method::POST,
             ResourceType::Collections,
-        );
+        )?;
 
         req = req.header(http::header::CONTENT_TYPE, "application/json");
 
@@ -509,6 +835,7 @@ where
         let req = OfferRequired::add_header(self, req);
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let mut collection = Collection::new(
@@ -516,6 +843,9 @@ where
             self.indexing_policy().to_owned(),
         );
         collection.parition_key = self.partition_key().to_owned();
+        collection.default_ttl = self.default_ttl();
+        collection.unique_key_policy = self.unique_key_policy().cloned();
+        collection.conflict_resolution_policy = self.conflict_resolution_policy().cloned();
 
         let body = serde_json::to_string(&collection)?;
         debug!("body == {}", body);
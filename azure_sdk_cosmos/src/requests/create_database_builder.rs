@@ -3,6 +3,7 @@ use crate::database::DatabaseName;
 use crate::prelude::*;
 use crate::responses::CreateDatabaseResponse;
 use crate::ClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -19,6 +20,7 @@ where
     client: &'a Client<CUB>,
     p_database_name: PhantomData<DatabaseNameSet>,
     database_name: Option<&'a DB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB, DB> CreateDatabaseBuilder<'a, CUB, DB, No>
@@ -31,6 +33,7 @@ where
             client,
             p_database_name: PhantomData {},
             database_name: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -69,6 +72,38 @@ where
             client: self.client,
             p_database_name: PhantomData {},
             database_name: Some(database_name),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, DB, DatabaseNameSet> DiagnosticsContextOption<'a>
+    for CreateDatabaseBuilder<'a, CUB, DB, DatabaseNameSet>
+where
+    DatabaseNameSet: ToAssign,
+    CUB: CosmosUriBuilder,
+    DB: DatabaseName,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, DB, DatabaseNameSet> DiagnosticsContextSupport<'a>
+    for CreateDatabaseBuilder<'a, CUB, DB, DatabaseNameSet>
+where
+    DatabaseNameSet: ToAssign,
+    CUB: CosmosUriBuilder,
+    DB: DatabaseName,
+{
+    type O = CreateDatabaseBuilder<'a, CUB, DB, DatabaseNameSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateDatabaseBuilder {
+            client: self.client,
+            p_database_name: self.p_database_name,
+            database_name: self.database_name,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -91,10 +126,11 @@ where
             id: self.database_name().name(),
         })?;
 
-        let request = self
-            .client()
-            .prepare_request("dbs", hyper::Method::POST, ResourceType::Databases)
-            .body(hyper::Body::from(req))?; // todo: set content-length here and elsewhere without builders
+        let request =
+            self.client()
+                .prepare_request("dbs", hyper::Method::POST, ResourceType::Databases)?;
+        let request = DiagnosticsContextOption::add_extension(self, request);
+        let request = request.body(hyper::Body::from(req))?; // todo: set content-length here and elsewhere without builders
 
         debug!("create database request prepared == {:?}", request);
 
@@ -3,12 +3,15 @@ use crate::document::Document;
 use crate::prelude::*;
 use crate::responses::CreateDocumentResponse;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
 use azure_sdk_core::{IfMatchConditionOption, IfMatchConditionSupport};
 use azure_sdk_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use hyper::body::Bytes;
 use hyper::StatusCode;
 use serde::Serialize;
 use std::convert::TryFrom;
@@ -28,6 +31,7 @@ where
     document: Option<&'b Document<T>>,
     partition_keys: Option<&'b PartitionKeys>,
     is_upsert: bool,
+    ttl: Option<i64>,
     indexing_directive: IndexingDirective,
     if_match_condition: Option<IfMatchCondition<'b>>,
     if_modified_since: Option<&'b DateTime<Utc>>,
@@ -35,6 +39,7 @@ where
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
     allow_tentative_writes: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, T, CUB> CreateDocumentBuilder<'a, 'b, T, CUB, No, No>
@@ -53,6 +58,7 @@ where
             p_partition_keys: PhantomData {},
             partition_keys: None,
             is_upsert: false,
+            ttl: None,
             indexing_directive: IndexingDirective::Default,
             if_match_condition: None,
             if_modified_since: None,
@@ -60,6 +66,7 @@ where
             activity_id: None,
             consistency_level: None,
             allow_tentative_writes: false,
+            diagnostics_context: None,
         }
     }
 }
@@ -121,6 +128,20 @@ where
     }
 }
 
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet> TtlOption
+    for CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn ttl(&self) -> Option<i64> {
+        self.ttl
+    }
+}
+
 impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet> IndexingDirectiveOption
     for CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>
 where
@@ -237,6 +258,7 @@ where
             document: Some(document),
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -244,6 +266,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -266,6 +289,7 @@ where
             document: self.document,
             partition_keys: Some(partition_keys),
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -273,6 +297,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -296,6 +321,39 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert,
+            ttl: self.ttl,
+            indexing_directive: self.indexing_directive,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet> TtlSupport
+    for CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_ttl(self, ttl: i64) -> Self::O {
+        CreateDocumentBuilder {
+            collection_client: self.collection_client,
+            p_document: PhantomData {},
+            p_partition_keys: PhantomData {},
+            document: self.document,
+            partition_keys: self.partition_keys,
+            is_upsert: self.is_upsert,
+            ttl: Some(ttl),
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -303,6 +361,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -326,6 +385,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -333,6 +393,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -356,6 +417,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: Some(if_match_condition),
             if_modified_since: self.if_modified_since,
@@ -363,6 +425,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -386,6 +449,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: Some(if_modified_since),
@@ -393,6 +457,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -416,6 +481,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -423,6 +489,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -446,6 +513,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -453,6 +521,7 @@ where
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -476,6 +545,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -483,6 +553,7 @@ where
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -506,6 +577,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             is_upsert: self.is_upsert,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -513,6 +585,51 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet> DiagnosticsContextOption<'a>
+    for CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet> DiagnosticsContextSupport<'a>
+    for CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateDocumentBuilder {
+            collection_client: self.collection_client,
+            p_document: self.p_document,
+            p_partition_keys: self.p_partition_keys,
+            document: self.document,
+            partition_keys: self.partition_keys,
+            is_upsert: self.is_upsert,
+            ttl: self.ttl,
+            indexing_directive: self.indexing_directive,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -535,6 +652,48 @@ where
     CUB: CosmosUriBuilder,
 {
     pub async fn execute(&self) -> Result<CreateDocumentResponse, AzureError> {
+        self.send(self.partition_keys()).await
+    }
+
+    // Sends the request without checking the status code, so that the
+    // headers of failed requests (charge, retry after) are not lost.
+    pub(crate) async fn execute_unchecked(
+        &self,
+    ) -> Result<(StatusCode, HeaderMap, Bytes), AzureError> {
+        self.send_unchecked(self.partition_keys()).await
+    }
+}
+
+// entities carry their own partition keys so they can be omitted
+impl<'a, 'b, T, CUB> CreateDocumentBuilder<'a, 'b, T, CUB, Yes, No>
+where
+    T: Serialize + CosmosEntity,
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute(&self) -> Result<CreateDocumentResponse, AzureError> {
+        let partition_keys = self.document().document.partition_keys()?;
+        self.send(&partition_keys).await
+    }
+}
+
+impl<'a, 'b, T, CUB, PartitionKeysSet> CreateDocumentBuilder<'a, 'b, T, CUB, Yes, PartitionKeysSet>
+where
+    PartitionKeysSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    async fn send(
+        &self,
+        partition_keys: &PartitionKeys,
+    ) -> Result<CreateDocumentResponse, AzureError> {
+        let (status_code, headers, whole_body) = self.send_unchecked(partition_keys).await?;
+        create_document_response(self.is_upsert(), status_code, &headers, &whole_body)
+    }
+
+    async fn send_unchecked(
+        &self,
+        partition_keys: &PartitionKeys,
+    ) -> Result<(StatusCode, HeaderMap, Bytes), AzureError> {
         let mut req = self.collection_client.main_client().prepare_request(
             &format!(
                 "dbs/{}/colls/{}/docs",
@@ -543,20 +702,27 @@ where
             ),
             hyper::Method::POST,
             ResourceType::Documents,
-        );
+        )?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = IfModifiedSinceOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
-        req = PartitionKeysRequired::add_header(self, req);
+        req = crate::add_partition_keys_header(partition_keys, req);
         req = IsUpsertOption::add_header(self, req);
         req = IndexingDirectiveOption::add_header(self, req);
         req = AllowTentativeWritesOption::add_header(self, req);
 
-        let serialized = serde_json::to_string(self.document())?;
+        // the ttl passed to the builder takes precedence over a ttl field
+        // of the document
+        let mut serialized = serde_json::to_value(self.document())?;
+        if let (Some(ttl), Some(map)) = (self.ttl(), serialized.as_object_mut()) {
+            map.insert("ttl".to_owned(), ttl.into());
+        }
+        let serialized = serde_json::to_string(&serialized)?;
         let req = req.body(hyper::Body::from(serialized))?;
 
         let (status_code, headers, whole_body) =
@@ -567,24 +733,33 @@ where
         debug!("headers == {:?}", headers);
         debug!("whole body == {:#?}", whole_body);
 
-        // expect CREATED is IsUpsert is off. Otherwise either
-        // CREATED or OK means success.
-        if !self.is_upsert() && status_code != StatusCode::CREATED {
-            return Err(UnexpectedHTTPResult::new(
-                StatusCode::CREATED,
-                status_code,
-                std::str::from_utf8(&whole_body)?,
-            )
-            .into());
-        } else if status_code != StatusCode::CREATED && status_code != StatusCode::OK {
-            return Err(UnexpectedHTTPResult::new_multiple(
-                vec![StatusCode::CREATED, StatusCode::OK],
-                status_code,
-                std::str::from_utf8(&whole_body)?,
-            )
-            .into());
-        }
+        Ok((status_code, headers, whole_body))
+    }
+}
 
-        CreateDocumentResponse::try_from((status_code, &headers, &whole_body as &[u8]))
+pub(crate) fn create_document_response(
+    is_upsert: bool,
+    status_code: StatusCode,
+    headers: &HeaderMap,
+    whole_body: &[u8],
+) -> Result<CreateDocumentResponse, AzureError> {
+    // expect CREATED is IsUpsert is off. Otherwise either
+    // CREATED or OK means success.
+    if !is_upsert && status_code != StatusCode::CREATED {
+        return Err(UnexpectedHTTPResult::new(
+            StatusCode::CREATED,
+            status_code,
+            std::str::from_utf8(whole_body)?,
+        )
+        .into());
+    } else if status_code != StatusCode::CREATED && status_code != StatusCode::OK {
+        return Err(UnexpectedHTTPResult::new_multiple(
+            vec![StatusCode::CREATED, StatusCode::OK],
+            status_code,
+            std::str::from_utf8(whole_body)?,
+        )
+        .into());
     }
+
+    CreateDocumentResponse::try_from((status_code, headers, whole_body))
 }
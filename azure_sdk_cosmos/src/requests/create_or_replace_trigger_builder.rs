@@ -2,6 +2,7 @@ use crate::clients::CosmosUriBuilder;
 use crate::prelude::*;
 use crate::responses::CreateTriggerResponse;
 use crate::trigger::*;
+use crate::DiagnosticsContext;
 use crate::TriggerClient;
 use crate::TriggerClientRequired;
 use crate::{TriggerBuilderTrait, TriggerTrait};
@@ -31,6 +32,7 @@ where
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> CreateOrReplaceTriggerBuilder<'a, CUB, No, No, No>
@@ -54,6 +56,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -177,6 +180,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -204,6 +208,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -231,6 +236,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -259,6 +265,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -287,6 +294,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -315,6 +323,48 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, TriggerOperationSet, TriggerTypeSet, BodySet> DiagnosticsContextOption<'a>
+    for CreateOrReplaceTriggerBuilder<'a, CUB, TriggerOperationSet, TriggerTypeSet, BodySet>
+where
+    TriggerOperationSet: ToAssign,
+    TriggerTypeSet: ToAssign,
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, TriggerOperationSet, TriggerTypeSet, BodySet> DiagnosticsContextSupport<'a>
+    for CreateOrReplaceTriggerBuilder<'a, CUB, TriggerOperationSet, TriggerTypeSet, BodySet>
+where
+    TriggerOperationSet: ToAssign,
+    TriggerTypeSet: ToAssign,
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateOrReplaceTriggerBuilder<'a, CUB, TriggerOperationSet, TriggerTypeSet, BodySet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateOrReplaceTriggerBuilder {
+            trigger_client: self.trigger_client,
+            is_create: self.is_create,
+            p_trigger_operation: self.p_trigger_operation,
+            p_trigger_type: self.p_trigger_type,
+            p_body: self.p_body,
+            trigger_operation: self.trigger_operation,
+            trigger_type: self.trigger_type,
+            body: self.body,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -343,14 +393,15 @@ where
 
         let req = self.trigger_client;
         let req = if self.is_create() {
-            req.prepare_request(hyper::Method::POST, false)
+            req.prepare_request(hyper::Method::POST, false)?
         } else {
-            req.prepare_request(hyper::Method::PUT, true)
+            req.prepare_request(hyper::Method::PUT, true)?
         };
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let req = req.header(http::header::CONTENT_TYPE, "application/json");
@@ -1,6 +1,7 @@
 use crate::clients::CosmosUriBuilder;
 use crate::prelude::*;
 use crate::responses::CreateUserDefinedFunctionResponse;
+use crate::DiagnosticsContext;
 use crate::UserDefinedFunctionClient;
 use crate::UserDefinedFunctionClientRequired;
 use crate::{UserDefinedFunctionBuilderTrait, UserDefinedFunctionTrait};
@@ -24,6 +25,7 @@ where
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> CreateOrReplaceUserDefinedFunctionBuilder<'a, CUB, No>
@@ -43,6 +45,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -124,6 +127,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -146,6 +150,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -168,6 +173,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -190,6 +196,40 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, BodySet> DiagnosticsContextOption<'a>
+    for CreateOrReplaceUserDefinedFunctionBuilder<'a, CUB, BodySet>
+where
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, BodySet> DiagnosticsContextSupport<'a>
+    for CreateOrReplaceUserDefinedFunctionBuilder<'a, CUB, BodySet>
+where
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateOrReplaceUserDefinedFunctionBuilder<'a, CUB, BodySet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateOrReplaceUserDefinedFunctionBuilder {
+            user_defined_function_client: self.user_defined_function_client,
+            is_create: self.is_create,
+            p_body: self.p_body,
+            body: self.body,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -221,15 +261,16 @@ where
         let req = match self.is_create {
             true => self
                 .user_defined_function_client
-                .prepare_request(hyper::Method::POST, false),
+                .prepare_request(hyper::Method::POST, false)?,
             false => self
                 .user_defined_function_client
-                .prepare_request(hyper::Method::PUT, true),
+                .prepare_request(hyper::Method::PUT, true)?,
         };
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let req = req.header(http::header::CONTENT_TYPE, "application/json");
@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, PermissionClient, ResourceType};
 use crate::prelude::*;
 use crate::responses::CreatePermissionResponse;
+use crate::DiagnosticsContext;
 use crate::{PermissionMode, PermissionResource};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::{No, ToAssign, Yes};
@@ -19,6 +20,7 @@ where
     p_permission_mode: PhantomData<PermissionSet>,
     permission_mode: Option<&'a PermissionMode<R>>,
     expiry_seconds: u64,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB, R> CreatePermissionBuilder<'a, CUB, R, No>
@@ -35,6 +37,7 @@ where
             p_permission_mode: PhantomData {},
             permission_mode: None,
             expiry_seconds: 3600,
+            diagnostics_context: None,
         }
     }
 }
@@ -93,6 +96,7 @@ where
             p_permission_mode: PhantomData {},
             permission_mode: Some(permission_mode),
             expiry_seconds: self.expiry_seconds,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -113,6 +117,39 @@ where
             p_permission_mode: PhantomData {},
             permission_mode: self.permission_mode,
             expiry_seconds,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, R, PermissionSet> DiagnosticsContextOption<'a>
+    for CreatePermissionBuilder<'a, CUB, R, PermissionSet>
+where
+    PermissionSet: ToAssign,
+    CUB: CosmosUriBuilder,
+    R: PermissionResource,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, R, PermissionSet> DiagnosticsContextSupport<'a>
+    for CreatePermissionBuilder<'a, CUB, R, PermissionSet>
+where
+    PermissionSet: ToAssign,
+    CUB: CosmosUriBuilder,
+    R: PermissionResource,
+{
+    type O = CreatePermissionBuilder<'a, CUB, R, PermissionSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreatePermissionBuilder {
+            permission_client: self.permission_client,
+            p_permission_mode: self.p_permission_mode,
+            permission_mode: self.permission_mode,
+            expiry_seconds: self.expiry_seconds,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -134,7 +171,7 @@ where
             ),
             hyper::Method::POST,
             ResourceType::Permissions,
-        );
+        )?;
 
         req = req.header(http::header::CONTENT_TYPE, "application/json");
 
@@ -155,6 +192,7 @@ where
         };
         let request_body = serde_json::to_string(&request_body)?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::from(request_body))?;
         debug!("\nreq == {:#?}", req);
 
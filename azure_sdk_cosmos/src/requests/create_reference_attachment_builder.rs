@@ -2,6 +2,7 @@ use crate::clients::{CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::AttachmentClient;
 use crate::AttachmentClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use azure_sdk_core::{No, ToAssign, Yes};
@@ -24,6 +25,7 @@ where
     user_agent: Option<&'b str>,
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> CreateReferenceAttachmentBuilder<'a, 'b, CUB, No, No>
@@ -43,6 +45,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -145,6 +148,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -168,6 +172,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -192,6 +197,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -216,6 +222,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -240,6 +247,43 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet, MediaSet> DiagnosticsContextOption<'a>
+    for CreateReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>
+where
+    ContentTypeSet: ToAssign,
+    MediaSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet, MediaSet> DiagnosticsContextSupport<'a>
+    for CreateReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>
+where
+    ContentTypeSet: ToAssign,
+    MediaSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateReferenceAttachmentBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: self.p_content_type,
+            p_media: self.p_media,
+            content_type: self.content_type,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -261,11 +305,12 @@ where
             ),
             hyper::Method::POST,
             ResourceType::Attachments,
-        );
+        )?;
 
         // add trait headers
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
 
         req = crate::add_partition_keys_header(
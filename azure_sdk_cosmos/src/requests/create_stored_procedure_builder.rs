@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::CreateStoredProcedureResponse;
+use crate::DiagnosticsContext;
 use crate::StoredProcedureClient;
 use crate::StoredProcedureClientRequired;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
@@ -22,6 +23,7 @@ where
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> CreateStoredProcedureBuilder<'a, CUB, No>
@@ -39,6 +41,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -116,6 +119,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -136,6 +140,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -156,6 +161,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -177,6 +183,39 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, BodySet> DiagnosticsContextOption<'a>
+    for CreateStoredProcedureBuilder<'a, CUB, BodySet>
+where
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, BodySet> DiagnosticsContextSupport<'a>
+    for CreateStoredProcedureBuilder<'a, CUB, BodySet>
+where
+    BodySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateStoredProcedureBuilder<'a, CUB, BodySet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateStoredProcedureBuilder {
+            stored_procedure_client: self.stored_procedure_client,
+            p_body: self.p_body,
+            body: self.body,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -197,11 +236,12 @@ where
             ),
             hyper::Method::POST,
             ResourceType::StoredProcedures,
-        );
+        )?;
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let req = req.header(http::header::CONTENT_TYPE, "application/json");
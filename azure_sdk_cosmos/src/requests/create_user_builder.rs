@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType, UserClient};
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -11,6 +12,7 @@ where
     CUB: CosmosUriBuilder,
 {
     user_client: &'a UserClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> CreateUserBuilder<'a, CUB>
@@ -19,7 +21,10 @@ where
 {
     #[inline]
     pub(crate) fn new(user_client: &'a UserClient<'a, CUB>) -> CreateUserBuilder<'a, CUB> {
-        CreateUserBuilder { user_client }
+        CreateUserBuilder {
+            user_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -33,6 +38,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for CreateUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for CreateUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = CreateUserBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        CreateUserBuilder {
+            user_client: self.user_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> CreateUserBuilder<'a, CUB>
 where
@@ -45,7 +73,7 @@ where
             &format!("dbs/{}/users", self.user_client.database_name().name()),
             hyper::Method::POST,
             ResourceType::Users,
-        );
+        )?;
 
         req = req.header(http::header::CONTENT_TYPE, "application/json");
 
@@ -58,6 +86,7 @@ where
         };
         let request_body = serde_json::to_string(&request_body)?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::from(request_body))?;
         debug!("\nreq == {:?}", req);
 
@@ -1,6 +1,7 @@
 use crate::clients::{CollectionClient, CosmosUriBuilder};
 use crate::responses::DeleteCollectionResponse;
 use crate::{CollectionBuilderTrait, CollectionClientRequired};
+use crate::{DiagnosticsContext, DiagnosticsContextOption, DiagnosticsContextSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -11,6 +12,7 @@ where
     CUB: CosmosUriBuilder,
 {
     collection_client: &'a CollectionClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteCollectionBuilder<'a, CUB>
@@ -21,7 +23,10 @@ where
     pub(crate) fn new(
         collection_client: &'a CollectionClient<'a, CUB>,
     ) -> DeleteCollectionBuilder<'a, CUB> {
-        DeleteCollectionBuilder { collection_client }
+        DeleteCollectionBuilder {
+            collection_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -35,6 +40,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteCollectionBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteCollectionBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteCollectionBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteCollectionBuilder {
+            collection_client: self.collection_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> DeleteCollectionBuilder<'a, CUB>
 where
@@ -45,8 +73,9 @@ where
 
         let req = self
             .collection_client()
-            .prepare_request(hyper::Method::DELETE)
-            .body(hyper::Body::empty())?;
+            .prepare_request(hyper::Method::DELETE)?;
+        let req = DiagnosticsContextOption::add_extension(self, req);
+        let req = req.body(hyper::Body::empty())?;
 
         let (headers, body) = check_status_extract_headers_and_body(
             self.collection_client().hyper_client().request(req),
@@ -0,0 +1,327 @@
+use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
+use crate::prelude::*;
+use crate::responses::DeleteConflictResponse;
+use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use hyper::StatusCode;
+use std::convert::TryInto;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    collection_client: &'a CollectionClient<'a, CUB>,
+    p_conflict_id: PhantomData<ConflictIdSet>,
+    p_partition_keys: PhantomData<PartitionKeysSet>,
+    conflict_id: Option<&'b str>,
+    partition_keys: Option<&'b PartitionKeys>,
+    user_agent: Option<&'b str>,
+    activity_id: Option<&'b str>,
+    consistency_level: Option<ConsistencyLevel<'b>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, CUB> DeleteConflictBuilder<'a, 'b, CUB, No, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        collection_client: &'a CollectionClient<'a, CUB>,
+    ) -> DeleteConflictBuilder<'a, 'b, CUB, No, No> {
+        DeleteConflictBuilder {
+            collection_client,
+            p_conflict_id: PhantomData {},
+            conflict_id: None,
+            p_partition_keys: PhantomData {},
+            partition_keys: None,
+            user_agent: None,
+            activity_id: None,
+            consistency_level: None,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> CollectionClientRequired<'a, CUB>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn collection_client(&self) -> &'a CollectionClient<'a, CUB> {
+        self.collection_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, CUB, PartitionKeysSet> ConflictIdRequired<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, Yes, PartitionKeysSet>
+where
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn conflict_id(&self) -> &'b str {
+        self.conflict_id.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet> PartitionKeysRequired<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, Yes>
+where
+    ConflictIdSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn partition_keys(&self) -> &'b PartitionKeys {
+        self.partition_keys.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> UserAgentOption<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn user_agent(&self) -> Option<&'b str> {
+        self.user_agent
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> ActivityIdOption<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn activity_id(&self) -> Option<&'b str> {
+        self.activity_id
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> ConsistencyLevelOption<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn consistency_level(&self) -> Option<ConsistencyLevel<'b>> {
+        self.consistency_level.clone()
+    }
+}
+
+impl<'a, 'b, CUB, PartitionKeysSet> ConflictIdSupport<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, No, PartitionKeysSet>
+where
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, Yes, PartitionKeysSet>;
+
+    #[inline]
+    fn with_conflict_id(self, conflict_id: &'b str) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: PhantomData {},
+            p_partition_keys: PhantomData {},
+            conflict_id: Some(conflict_id),
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet> PartitionKeysSupport<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, No>
+where
+    ConflictIdSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, Yes>;
+
+    #[inline]
+    fn with_partition_keys(self, partition_keys: &'b PartitionKeys) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: PhantomData {},
+            p_partition_keys: PhantomData {},
+            conflict_id: self.conflict_id,
+            partition_keys: Some(partition_keys),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> UserAgentSupport<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_user_agent(self, user_agent: &'b str) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: PhantomData {},
+            p_partition_keys: PhantomData {},
+            conflict_id: self.conflict_id,
+            partition_keys: self.partition_keys,
+            user_agent: Some(user_agent),
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> ActivityIdSupport<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_activity_id(self, activity_id: &'b str) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: PhantomData {},
+            p_partition_keys: PhantomData {},
+            conflict_id: self.conflict_id,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: Some(activity_id),
+            consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> ConsistencyLevelSupport<'b>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_consistency_level(self, consistency_level: ConsistencyLevel<'b>) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: PhantomData {},
+            p_partition_keys: PhantomData {},
+            conflict_id: self.conflict_id,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> DiagnosticsContextOption<'a>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet> DiagnosticsContextSupport<'a>
+    for DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>
+where
+    ConflictIdSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteConflictBuilder<'a, 'b, CUB, ConflictIdSet, PartitionKeysSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteConflictBuilder {
+            collection_client: self.collection_client,
+            p_conflict_id: self.p_conflict_id,
+            p_partition_keys: self.p_partition_keys,
+            conflict_id: self.conflict_id,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, CUB> DeleteConflictBuilder<'a, 'b, CUB, Yes, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute(&self) -> Result<DeleteConflictResponse, AzureError> {
+        trace!("DeleteConflictBuilder::execute called");
+
+        let req = self.collection_client.main_client().prepare_request(
+            &format!(
+                "dbs/{}/colls/{}/conflicts/{}",
+                self.collection_client.database_name().name(),
+                self.collection_client.collection_name().name(),
+                self.conflict_id()
+            ),
+            hyper::Method::DELETE,
+            ResourceType::Conflicts,
+        )?;
+
+        // add trait headers
+        let req = PartitionKeysRequired::add_header(self, req);
+        let req = UserAgentOption::add_header(self, req);
+        let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
+        let req = ConsistencyLevelOption::add_header(self, req);
+
+        let request = req.body(hyper::Body::empty())?;
+
+        let (headers, body) = check_status_extract_headers_and_body(
+            self.collection_client().hyper_client().request(request),
+            StatusCode::NO_CONTENT,
+        )
+        .await?;
+
+        (&headers, &body as &[u8]).try_into()
+    }
+}
@@ -2,6 +2,7 @@ use crate::clients::{CosmosUriBuilder, DatabaseClient, ResourceType};
 use crate::prelude::*;
 use crate::responses::DeleteDatabaseResponse;
 use crate::DatabaseTrait;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -12,6 +13,7 @@ where
     CUB: CosmosUriBuilder,
 {
     database_client: &'a DatabaseClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteDatabaseBuilder<'a, CUB>
@@ -21,7 +23,10 @@ where
     pub(crate) fn new(
         database_client: &'a DatabaseClient<'a, CUB>,
     ) -> DeleteDatabaseBuilder<'a, CUB> {
-        DeleteDatabaseBuilder { database_client }
+        DeleteDatabaseBuilder {
+            database_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -34,6 +39,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteDatabaseBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteDatabaseBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteDatabaseBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteDatabaseBuilder {
+            database_client: self.database_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> DeleteDatabaseBuilder<'a, CUB>
 where
@@ -42,15 +70,13 @@ where
     pub async fn execute(&self) -> Result<DeleteDatabaseResponse, AzureError> {
         trace!("DeleteDatabaseResponse::execute called");
 
-        let request = self
-            .database_client()
-            .main_client()
-            .prepare_request(
-                &format!("dbs/{}", self.database_client().database_name().name()),
-                hyper::Method::DELETE,
-                ResourceType::Databases,
-            )
-            .body(hyper::Body::empty())?;
+        let request = self.database_client().main_client().prepare_request(
+            &format!("dbs/{}", self.database_client().database_name().name()),
+            hyper::Method::DELETE,
+            ResourceType::Databases,
+        )?;
+        let request = DiagnosticsContextOption::add_extension(self, request);
+        let request = request.body(hyper::Body::empty())?;
 
         trace!("request prepared == {:?}", request);
 
@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, DocumentClient};
 use crate::prelude::*;
 use crate::responses::DeleteDocumentResponse;
+use crate::DiagnosticsContext;
 use crate::DocumentBuilderTrait;
 use crate::DocumentClientRequired;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
@@ -23,6 +24,7 @@ where
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
     allow_tentative_writes: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteDocumentBuilder<'a, CUB>
@@ -41,6 +43,7 @@ where
             activity_id: None,
             consistency_level: None,
             allow_tentative_writes: false,
+            diagnostics_context: None,
         }
     }
 }
@@ -134,6 +137,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -154,6 +158,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -174,6 +179,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -194,6 +200,7 @@ where
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -214,6 +221,7 @@ where
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -234,6 +242,36 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteDocumentBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteDocumentBuilder {
+            document_client: self.document_client,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -246,13 +284,16 @@ where
     pub async fn execute(&self) -> Result<DeleteDocumentResponse, AzureError> {
         trace!("DeleteDocumentBuilder::execute called");
 
-        let mut req = self.document_client.prepare_request(hyper::Method::DELETE);
+        let mut req = self
+            .document_client
+            .prepare_request(hyper::Method::DELETE)?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = IfModifiedSinceOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
         req = AllowTentativeWritesOption::add_header(self, req);
 
@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, PermissionClient, ResourceType};
 use crate::prelude::*;
 use crate::responses::DeletePermissionResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -11,6 +12,7 @@ where
     CUB: CosmosUriBuilder,
 {
     permission_client: &'a PermissionClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeletePermissionsBuilder<'a, CUB>
@@ -21,7 +23,10 @@ where
     pub(crate) fn new(
         permission_client: &'a PermissionClient<'a, CUB>,
     ) -> DeletePermissionsBuilder<'a, CUB> {
-        DeletePermissionsBuilder { permission_client }
+        DeletePermissionsBuilder {
+            permission_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -35,6 +40,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeletePermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeletePermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeletePermissionsBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeletePermissionsBuilder {
+            permission_client: self.permission_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> DeletePermissionsBuilder<'a, CUB>
 where
@@ -52,8 +80,9 @@ where
             ),
             hyper::Method::DELETE,
             ResourceType::Permissions,
-        );
+        )?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:#?}", req);
 
@@ -1,6 +1,7 @@
 use crate::clients::CosmosUriBuilder;
 use crate::prelude::*;
 use crate::responses::DeleteTriggerResponse;
+use crate::DiagnosticsContext;
 use crate::TriggerBuilderTrait;
 use crate::TriggerClient;
 use crate::TriggerClientRequired;
@@ -18,6 +19,7 @@ where
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteTriggerBuilder<'a, CUB>
@@ -31,6 +33,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -91,6 +94,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -108,6 +112,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -125,6 +130,33 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteTriggerBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteTriggerBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteTriggerBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteTriggerBuilder {
+            trigger_client: self.trigger_client,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -139,11 +171,12 @@ where
 
         let req = self
             .trigger_client
-            .prepare_request(hyper::Method::DELETE, true);
+            .prepare_request(hyper::Method::DELETE, true)?;
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let request = req.body(hyper::Body::empty())?;
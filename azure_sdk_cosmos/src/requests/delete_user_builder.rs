@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType, UserClient};
 use crate::prelude::*;
 use crate::responses::DeleteUserResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -11,6 +12,7 @@ where
     CUB: CosmosUriBuilder,
 {
     user_client: &'a UserClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteUserBuilder<'a, CUB>
@@ -19,7 +21,10 @@ where
 {
     #[inline]
     pub(crate) fn new(user_client: &'a UserClient<'a, CUB>) -> DeleteUserBuilder<'a, CUB> {
-        DeleteUserBuilder { user_client }
+        DeleteUserBuilder {
+            user_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -33,6 +38,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteUserBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteUserBuilder {
+            user_client: self.user_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> DeleteUserBuilder<'a, CUB>
 where
@@ -49,8 +77,9 @@ where
             ),
             hyper::Method::DELETE,
             ResourceType::Users,
-        );
+        )?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:?}", req);
 
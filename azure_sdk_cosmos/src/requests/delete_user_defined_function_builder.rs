@@ -1,6 +1,7 @@
 use crate::clients::CosmosUriBuilder;
 use crate::prelude::*;
 use crate::responses::DeleteUserDefinedFunctionResponse;
+use crate::DiagnosticsContext;
 use crate::UserDefinedFunctionBuilderTrait;
 use crate::UserDefinedFunctionClient;
 use crate::UserDefinedFunctionClientRequired;
@@ -18,6 +19,7 @@ where
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
     consistency_level: Option<ConsistencyLevel<'a>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> DeleteUserDefinedFunctionBuilder<'a, CUB>
@@ -33,6 +35,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -94,6 +97,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -111,6 +115,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -128,6 +133,33 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for DeleteUserDefinedFunctionBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for DeleteUserDefinedFunctionBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = DeleteUserDefinedFunctionBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        DeleteUserDefinedFunctionBuilder {
+            user_defined_function_client: self.user_defined_function_client,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -142,11 +174,12 @@ where
 
         let req = self
             .user_defined_function_client
-            .prepare_request(hyper::Method::DELETE, true);
+            .prepare_request(hyper::Method::DELETE, true)?;
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
 
         let request = req.body(hyper::Body::empty())?;
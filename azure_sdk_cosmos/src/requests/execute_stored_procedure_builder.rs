@@ -2,6 +2,7 @@ use crate::clients::CosmosUriBuilder;
 use crate::prelude::*;
 use crate::responses::ExecuteStoredProcedureResponse;
 use crate::stored_procedure::Parameters;
+use crate::DiagnosticsContext;
 use crate::StoredProcedureBuilderTrait;
 use crate::StoredProcedureClient;
 use crate::StoredProcedureClientRequired;
@@ -23,6 +24,7 @@ where
     consistency_level: Option<ConsistencyLevel<'b>>,
     allow_tentative_writes: bool,
     partition_keys: Option<&'b PartitionKeys>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> ExecuteStoredProcedureBuilder<'a, 'b, CUB>
@@ -41,6 +43,7 @@ where
             consistency_level: None,
             allow_tentative_writes: false,
             partition_keys: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -56,6 +59,35 @@ where
     }
 }
 
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for ExecuteStoredProcedureBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for ExecuteStoredProcedureBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ExecuteStoredProcedureBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ExecuteStoredProcedureBuilder {
+            stored_procedure_client: self.stored_procedure_client,
+            parameters: self.parameters,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            partition_keys: self.partition_keys,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 //get mandatory no traits methods
 
 //set mandatory no traits methods
@@ -135,6 +167,7 @@ where
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
             partition_keys: self.partition_keys,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -155,6 +188,7 @@ where
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
             partition_keys: self.partition_keys,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -175,6 +209,7 @@ where
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
             partition_keys: self.partition_keys,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -195,6 +230,7 @@ where
             consistency_level: Some(consistency_level),
             allow_tentative_writes: self.allow_tentative_writes,
             partition_keys: self.partition_keys,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -215,6 +251,7 @@ where
             consistency_level: self.consistency_level,
             allow_tentative_writes,
             partition_keys: self.partition_keys,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -235,6 +272,7 @@ where
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
             partition_keys: Some(partition_keys),
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -251,11 +289,12 @@ where
 
         let req = self
             .stored_procedure_client()
-            .prepare_request(hyper::Method::POST);
+            .prepare_request(hyper::Method::POST)?;
 
         // add trait headers
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
         let req = AllowTentativeWritesOption::add_header(self, req);
         let req = PartitionKeysOption::add_header(self, req);
@@ -3,6 +3,7 @@ use crate::prelude::*;
 use crate::AttachmentBuilderTrait;
 use crate::AttachmentClient;
 use crate::AttachmentClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use hyper::StatusCode;
@@ -18,6 +19,7 @@ where
     user_agent: Option<&'b str>,
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> GetAttachmentBuilder<'a, 'b, CUB>
@@ -34,6 +36,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -105,6 +108,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -123,6 +127,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -141,6 +146,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -159,6 +165,34 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for GetAttachmentBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for GetAttachmentBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetAttachmentBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        GetAttachmentBuilder {
+            attachment_client: self.attachment_client,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -169,12 +203,13 @@ where
     CUB: CosmosUriBuilder,
 {
     pub async fn execute(&self) -> Result<crate::responses::GetAttachmentResponse, AzureError> {
-        let mut req = self.attachment_client.prepare_request(hyper::Method::GET);
+        let mut req = self.attachment_client.prepare_request(hyper::Method::GET)?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
 
         req = crate::add_partition_keys_header(
@@ -2,6 +2,7 @@ use crate::clients::{CosmosUriBuilder, DatabaseClient, ResourceType};
 use crate::responses::GetDatabaseResponse;
 use crate::DatabaseClientRequired;
 use crate::DatabaseTrait;
+use crate::{DiagnosticsContext, DiagnosticsContextOption, DiagnosticsContextSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use hyper::StatusCode;
@@ -15,6 +16,7 @@ where
     database_client: &'a DatabaseClient<'a, CUB>,
     user_agent: Option<&'a str>,
     activity_id: Option<&'a str>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> GetDatabaseBuilder<'a, CUB>
@@ -26,6 +28,7 @@ where
             database_client,
             user_agent: None,
             activity_id: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -71,6 +74,7 @@ where
             database_client: self.database_client,
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -86,6 +90,32 @@ where
             database_client: self.database_client,
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for GetDatabaseBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for GetDatabaseBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetDatabaseBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        GetDatabaseBuilder {
+            database_client: self.database_client,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -105,10 +135,11 @@ where
             &format!("dbs/{}", self.database_client().database_name().name()),
             hyper::Method::GET,
             ResourceType::Databases,
-        );
+        )?;
 
         let request = UserAgentOption::add_header(self, request);
         let request = ActivityIdOption::add_header(self, request);
+        let request = DiagnosticsContextOption::add_extension(self, request);
 
         let request = request.body(hyper::Body::empty())?;
 
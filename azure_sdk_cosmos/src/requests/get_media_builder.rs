@@ -0,0 +1,208 @@
+use crate::clients::CosmosUriBuilder;
+use crate::prelude::*;
+use crate::responses::GetMediaResponse;
+use crate::AttachmentClient;
+use crate::AttachmentClientRequired;
+use crate::DiagnosticsContext;
+use azure_sdk_core::errors::{AzureError, UnexpectedHTTPResult};
+use azure_sdk_core::prelude::*;
+use hyper::StatusCode;
+
+#[derive(Debug, Clone)]
+pub struct GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    attachment_client: &'a AttachmentClient<'a, CUB>,
+    media: Option<&'b str>,
+    user_agent: Option<&'b str>,
+    activity_id: Option<&'b str>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, CUB> GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        attachment_client: &'a AttachmentClient<'a, CUB>,
+    ) -> GetMediaBuilder<'a, 'b, CUB> {
+        GetMediaBuilder {
+            attachment_client,
+            media: None,
+            user_agent: None,
+            activity_id: None,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> AttachmentClientRequired<'a, CUB> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn attachment_client(&self) -> &'a AttachmentClient<'a, CUB> {
+        self.attachment_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, CUB> MediaOption<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn media(&self) -> Option<&'b str> {
+        self.media
+    }
+}
+
+impl<'a, 'b, CUB> UserAgentOption<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn user_agent(&self) -> Option<&'b str> {
+        self.user_agent
+    }
+}
+
+impl<'a, 'b, CUB> ActivityIdOption<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn activity_id(&self) -> Option<&'b str> {
+        self.activity_id
+    }
+}
+
+impl<'a, 'b, CUB> MediaSupport<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetMediaBuilder<'a, 'b, CUB>;
+
+    #[inline]
+    fn with_media(self, media: &'b str) -> Self::O {
+        GetMediaBuilder {
+            attachment_client: self.attachment_client,
+            media: Some(media),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> UserAgentSupport<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetMediaBuilder<'a, 'b, CUB>;
+
+    #[inline]
+    fn with_user_agent(self, user_agent: &'b str) -> Self::O {
+        GetMediaBuilder {
+            attachment_client: self.attachment_client,
+            media: self.media,
+            user_agent: Some(user_agent),
+            activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> ActivityIdSupport<'b> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetMediaBuilder<'a, 'b, CUB>;
+
+    #[inline]
+    fn with_activity_id(self, activity_id: &'b str) -> Self::O {
+        GetMediaBuilder {
+            attachment_client: self.attachment_client,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: Some(activity_id),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetMediaBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        GetMediaBuilder {
+            attachment_client: self.attachment_client,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, CUB> GetMediaBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    /// Downloads the media of a slug attachment. The media link is read
+    /// from the attachment unless passed with `with_media`. The service
+    /// has no separate API to list or delete media: the media links are
+    /// returned by `list_attachments` and the media is deleted along with
+    /// its attachment.
+    pub async fn execute(&self) -> Result<GetMediaResponse, AzureError> {
+        let media_id = self.attachment_client.media_id(self.media()).await?;
+
+        let mut req = self
+            .attachment_client
+            .prepare_media_request(&media_id, hyper::Method::GET)?;
+
+        // add trait headers
+        req = UserAgentOption::add_header(self, req);
+        req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
+        let req = req.body(hyper::Body::empty())?;
+
+        debug!("req == {:#?}", req);
+
+        let (head, body) = self
+            .attachment_client
+            .hyper_client()
+            .request(req)
+            .await?
+            .into_parts();
+
+        if head.status != StatusCode::OK {
+            let body = hyper::body::to_bytes(body).await?;
+            return Err(UnexpectedHTTPResult::new(
+                StatusCode::OK,
+                head.status,
+                std::str::from_utf8(&body)?,
+            )
+            .into());
+        }
+
+        GetMediaResponse::try_from(&head.headers, body)
+    }
+}
@@ -2,6 +2,7 @@ use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::*;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
@@ -21,6 +22,7 @@ where
     user_agent: Option<&'b str>,
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> GetPartitionKeyRangesBuilder<'a, 'b, CUB>
@@ -38,6 +40,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -120,6 +123,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -139,6 +143,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -158,6 +163,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -177,6 +183,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -196,6 +203,35 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for GetPartitionKeyRangesBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for GetPartitionKeyRangesBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetPartitionKeyRangesBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        GetPartitionKeyRangesBuilder {
+            collection_client: self.collection_client,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -216,13 +252,14 @@ where
             ),
             hyper::Method::GET,
             ResourceType::PartitionKeyRanges,
-        );
+        )?;
 
         let request = request.header(hyper::header::CONTENT_LENGTH, "0");
         let request = IfMatchConditionOption::add_header(self, request);
         let request = IfModifiedSinceOption::add_header(self, request);
         let request = UserAgentOption::add_header(self, request);
         let request = ActivityIdOption::add_header(self, request);
+        let request = DiagnosticsContextOption::add_extension(self, request);
         let request = ConsistencyLevelOption::add_header(self, request);
 
         let request = request.body(hyper::Body::empty())?;
@@ -52,7 +52,7 @@ where
             ),
             hyper::Method::GET,
             ResourceType::Permissions,
-        );
+        )?;
 
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:#?}", req);
@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType, UserClient};
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::UnexpectedHTTPResult;
 use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError};
 use hyper::StatusCode;
@@ -12,6 +13,7 @@ where
     CUB: CosmosUriBuilder,
 {
     user_client: &'a UserClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> GetUserBuilder<'a, CUB>
@@ -20,7 +22,10 @@ where
 {
     #[inline]
     pub(crate) fn new(user_client: &'a UserClient<'a, CUB>) -> GetUserBuilder<'a, CUB> {
-        GetUserBuilder { user_client }
+        GetUserBuilder {
+            user_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -34,6 +39,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for GetUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for GetUserBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = GetUserBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        GetUserBuilder {
+            user_client: self.user_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> GetUserBuilder<'a, CUB>
 where
@@ -50,8 +78,9 @@ where
             ),
             hyper::Method::GET,
             ResourceType::Users,
-        );
+        )?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:?}", req);
 
@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::ListAttachmentsResponse;
+use crate::DiagnosticsContext;
 use crate::DocumentClient;
 use crate::DocumentClientRequired;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
@@ -21,6 +22,7 @@ where
     continuation: Option<&'b str>,
     max_item_count: i32,
     a_im: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> ListAttachmentsBuilder<'a, 'b, CUB>
@@ -40,6 +42,7 @@ where
             continuation: None,
             max_item_count: -1,
             a_im: false,
+            diagnostics_context: None,
         }
     }
 }
@@ -144,6 +147,7 @@ where
             continuation: self.continuation,
             max_item_count: self.max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -165,6 +169,7 @@ where
             continuation: self.continuation,
             max_item_count: self.max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -186,6 +191,7 @@ where
             continuation: self.continuation,
             max_item_count: self.max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -207,6 +213,7 @@ where
             continuation: self.continuation,
             max_item_count: self.max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -228,6 +235,7 @@ where
             continuation: Some(continuation),
             max_item_count: self.max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -249,6 +257,7 @@ where
             continuation: self.continuation,
             max_item_count,
             a_im: self.a_im,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -270,6 +279,37 @@ where
             continuation: self.continuation,
             max_item_count: self.max_item_count,
             a_im,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for ListAttachmentsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for ListAttachmentsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListAttachmentsBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListAttachmentsBuilder {
+            document_client: self.document_client,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            a_im: self.a_im,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -289,12 +329,13 @@ where
             ),
             hyper::Method::GET,
             ResourceType::Attachments,
-        );
+        )?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
         req = ContinuationOption::add_header(self, req);
         req = MaxItemCountOption::add_header(self, req);
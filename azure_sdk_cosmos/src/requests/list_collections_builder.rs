@@ -3,6 +3,7 @@ use crate::prelude::*;
 use crate::responses::ListCollectionsResponse;
 use crate::DatabaseClientRequired;
 use crate::DatabaseTrait;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use futures::stream::{unfold, Stream};
@@ -20,6 +21,7 @@ where
     consistency_level: Option<ConsistencyLevel<'a>>,
     continuation: Option<&'a str>,
     max_item_count: i32,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ListCollectionsBuilder<'a, CUB>
@@ -36,6 +38,7 @@ where
             consistency_level: None,
             continuation: None,
             max_item_count: -1,
+            diagnostics_context: None,
         }
     }
 }
@@ -49,6 +52,7 @@ where
             database_client: self.database_client,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
             consistency_level: self.consistency_level.clone(),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
@@ -124,6 +128,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -142,6 +147,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -160,6 +166,7 @@ where
             consistency_level: Some(consistency_level),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -178,6 +185,7 @@ where
             consistency_level: self.consistency_level,
             continuation: Some(continuation),
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -196,6 +204,35 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for ListCollectionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for ListCollectionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListCollectionsBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListCollectionsBuilder {
+            database_client: self.database_client,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -211,10 +248,11 @@ where
             &format!("dbs/{}/colls", self.database_client.database_name().name()),
             hyper::Method::GET,
             ResourceType::Collections,
-        );
+        )?;
 
         let request = UserAgentOption::add_header(self, request);
         let request = ActivityIdOption::add_header(self, request);
+        let request = DiagnosticsContextOption::add_extension(self, request);
         let request = ConsistencyLevelOption::add_header(self, request);
         let request = ContinuationOption::add_header(self, request);
         let request = MaxItemCountOption::add_header(self, request);
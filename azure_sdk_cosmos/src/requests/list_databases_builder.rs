@@ -2,6 +2,7 @@ use crate::clients::{Client, CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::ListDatabasesResponse;
 use crate::ClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use futures::stream::{unfold, Stream};
@@ -19,6 +20,7 @@ where
     consistency_level: Option<ConsistencyLevel<'a>>,
     continuation: Option<&'a str>,
     max_item_count: i32,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ListDatabasesBuilder<'a, CUB>
@@ -33,6 +35,7 @@ where
             consistency_level: None,
             continuation: None,
             max_item_count: -1,
+            diagnostics_context: None,
         }
     }
 }
@@ -49,6 +52,7 @@ where
             consistency_level: self.consistency_level.clone(),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -121,6 +125,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -139,6 +144,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -157,6 +163,7 @@ where
             consistency_level: Some(consistency_level),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -175,6 +182,7 @@ where
             consistency_level: self.consistency_level,
             continuation: Some(continuation),
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -193,6 +201,35 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for ListDatabasesBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for ListDatabasesBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListDatabasesBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListDatabasesBuilder {
+            client: self.client,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -207,10 +244,11 @@ where
 
         let request =
             self.client
-                .prepare_request("dbs", hyper::Method::GET, ResourceType::Databases);
+                .prepare_request("dbs", hyper::Method::GET, ResourceType::Databases)?;
 
         let request = UserAgentOption::add_header(self, request);
         let request = ActivityIdOption::add_header(self, request);
+        let request = DiagnosticsContextOption::add_extension(self, request);
         let request = ConsistencyLevelOption::add_header(self, request);
         let request = ContinuationOption::add_header(self, request);
         let request = MaxItemCountOption::add_header(self, request);
@@ -2,6 +2,7 @@ use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::ListDocumentsResponse;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
@@ -25,6 +26,7 @@ where
     max_item_count: i32,
     a_im: bool,
     partition_range_id: Option<&'b str>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> Clone for ListDocumentsBuilder<'a, 'b, CUB>
@@ -42,6 +44,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -64,6 +67,7 @@ where
             max_item_count: -1,
             a_im: false,
             partition_range_id: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -179,6 +183,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -201,6 +206,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -223,6 +229,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -245,6 +252,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -267,6 +275,7 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -289,6 +298,7 @@ where
             max_item_count,
             a_im: self.a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -311,6 +321,7 @@ where
             max_item_count: self.max_item_count,
             a_im,
             partition_range_id: self.partition_range_id,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -333,6 +344,38 @@ where
             max_item_count: self.max_item_count,
             a_im: self.a_im,
             partition_range_id: Some(partition_range_id),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for ListDocumentsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for ListDocumentsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListDocumentsBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListDocumentsBuilder {
+            collection_client: self.collection_client,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            a_im: self.a_im,
+            partition_range_id: self.partition_range_id,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -354,12 +397,13 @@ where
             ),
             hyper::Method::GET,
             ResourceType::Documents,
-        );
+        )?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
         req = ContinuationOption::add_header(self, req);
         req = MaxItemCountOption::add_header(self, req);
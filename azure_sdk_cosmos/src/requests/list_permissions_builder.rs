@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType, UserClient};
 use crate::prelude::*;
 use crate::responses::ListPermissionsResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -11,6 +12,8 @@ where
     CUB: CosmosUriBuilder,
 {
     user_client: &'a UserClient<'a, CUB>,
+    expiry_seconds: u64,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ListPermissionsBuilder<'a, CUB>
@@ -19,7 +22,11 @@ where
 {
     #[inline]
     pub(crate) fn new(user_client: &'a UserClient<'a, CUB>) -> ListPermissionsBuilder<'a, CUB> {
-        ListPermissionsBuilder { user_client }
+        ListPermissionsBuilder {
+            user_client,
+            expiry_seconds: 3600,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -33,6 +40,56 @@ where
     }
 }
 
+impl<'a, CUB> ExpirySecondsOption for ListPermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn expiry_seconds(&self) -> u64 {
+        self.expiry_seconds
+    }
+}
+
+impl<'a, CUB> ExpirySecondsSupport for ListPermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListPermissionsBuilder<'a, CUB>;
+
+    #[inline]
+    fn with_expiry_seconds(self, expiry_seconds: u64) -> Self::O {
+        ListPermissionsBuilder {
+            user_client: self.user_client,
+            expiry_seconds,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for ListPermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for ListPermissionsBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListPermissionsBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListPermissionsBuilder {
+            user_client: self.user_client,
+            expiry_seconds: self.expiry_seconds,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> ListPermissionsBuilder<'a, CUB>
 where
@@ -49,8 +106,11 @@ where
             ),
             hyper::Method::GET,
             ResourceType::Permissions,
-        );
+        )?;
+
+        let req = ExpirySecondsOption::add_header(self, req);
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:#?}", req);
 
@@ -2,6 +2,7 @@ use crate::clients::{CollectionClient, CosmosUriBuilder};
 use crate::prelude::*;
 use crate::responses::ListTriggersResponse;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use crate::TriggerBuilderTrait;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
@@ -23,6 +24,7 @@ where
     consistency_level: Option<ConsistencyLevel<'b>>,
     continuation: Option<&'b str>,
     max_item_count: i32,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> Clone for ListTriggersBuilder<'a, 'b, CUB>
@@ -35,6 +37,7 @@ where
             if_match_condition: self.if_match_condition,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
             consistency_level: self.consistency_level.clone(),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
@@ -58,6 +61,7 @@ where
             consistency_level: None,
             continuation: None,
             max_item_count: -1,
+            diagnostics_context: None,
         }
     }
 }
@@ -151,6 +155,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -171,6 +176,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -191,6 +197,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -211,6 +218,7 @@ where
             consistency_level: Some(consistency_level),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -231,6 +239,7 @@ where
             consistency_level: self.consistency_level,
             continuation: Some(continuation),
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -251,6 +260,36 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for ListTriggersBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for ListTriggersBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListTriggersBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListTriggersBuilder {
+            collection_client: self.collection_client,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -266,12 +305,13 @@ where
         let req = self
             .collection_client
             .with_trigger(&"dummy")
-            .prepare_request(hyper::Method::GET, false);
+            .prepare_request(hyper::Method::GET, false)?;
 
         // add trait headers
         let req = IfMatchConditionOption::add_header(self, req);
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
         let req = ContinuationOption::add_header(self, req);
         let req = MaxItemCountOption::add_header(self, req);
@@ -2,6 +2,7 @@ use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::ListUserDefinedFunctionsResponse;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
@@ -22,6 +23,7 @@ where
     consistency_level: Option<ConsistencyLevel<'b>>,
     continuation: Option<&'b str>,
     max_item_count: i32,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> Clone for ListUserDefinedFunctionsBuilder<'a, 'b, CUB>
@@ -34,6 +36,7 @@ where
             if_match_condition: self.if_match_condition,
             user_agent: self.user_agent,
             activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
             consistency_level: self.consistency_level.clone(),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
@@ -57,6 +60,7 @@ where
             consistency_level: None,
             continuation: None,
             max_item_count: -1,
+            diagnostics_context: None,
         }
     }
 }
@@ -150,6 +154,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -170,6 +175,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -190,6 +196,7 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -210,6 +217,7 @@ where
             consistency_level: Some(consistency_level),
             continuation: self.continuation,
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -230,6 +238,7 @@ where
             consistency_level: self.consistency_level,
             continuation: Some(continuation),
             max_item_count: self.max_item_count,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -250,6 +259,36 @@ where
             consistency_level: self.consistency_level,
             continuation: self.continuation,
             max_item_count,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextOption<'a> for ListUserDefinedFunctionsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB> DiagnosticsContextSupport<'a> for ListUserDefinedFunctionsBuilder<'a, 'b, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListUserDefinedFunctionsBuilder<'a, 'b, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListUserDefinedFunctionsBuilder {
+            collection_client: self.collection_client,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -270,12 +309,13 @@ where
             ),
             hyper::Method::GET,
             ResourceType::UserDefinedFunctions,
-        );
+        )?;
 
         // add trait headers
         let req = IfMatchConditionOption::add_header(self, req);
         let req = UserAgentOption::add_header(self, req);
         let req = ActivityIdOption::add_header(self, req);
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = ConsistencyLevelOption::add_header(self, req);
         let req = ContinuationOption::add_header(self, req);
         let req = MaxItemCountOption::add_header(self, req);
@@ -2,6 +2,7 @@ use crate::clients::{CosmosUriBuilder, DatabaseClient, ResourceType};
 use crate::responses::ListUsersResponse;
 use crate::DatabaseClientRequired;
 use crate::DatabaseTrait;
+use crate::{DiagnosticsContext, DiagnosticsContextOption, DiagnosticsContextSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use hyper::StatusCode;
 use std::convert::TryInto;
@@ -12,6 +13,7 @@ where
     CUB: CosmosUriBuilder,
 {
     database_client: &'a DatabaseClient<'a, CUB>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ListUsersBuilder<'a, CUB>
@@ -19,7 +21,10 @@ where
     CUB: CosmosUriBuilder,
 {
     pub(crate) fn new(database_client: &'a DatabaseClient<'a, CUB>) -> ListUsersBuilder<'a, CUB> {
-        ListUsersBuilder { database_client }
+        ListUsersBuilder {
+            database_client,
+            diagnostics_context: None,
+        }
     }
 }
 
@@ -32,6 +37,29 @@ where
     }
 }
 
+impl<'a, CUB> DiagnosticsContextOption<'a> for ListUsersBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for ListUsersBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ListUsersBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ListUsersBuilder {
+            database_client: self.database_client,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
 // methods callable only when every mandatory field has been filled
 impl<'a, CUB> ListUsersBuilder<'a, CUB>
 where
@@ -44,8 +72,9 @@ where
             &format!("dbs/{}/users", self.database_client.database_name().name()),
             hyper::Method::GET,
             ResourceType::Users,
-        );
+        )?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::empty())?;
         debug!("\nreq == {:?}", req);
 
@@ -0,0 +1,414 @@
+use crate::clients::{CosmosUriBuilder, DocumentClient};
+use crate::prelude::*;
+use crate::responses::PatchDocumentResponse;
+use crate::DiagnosticsContext;
+use crate::DocumentBuilderTrait;
+use crate::DocumentClientRequired;
+use crate::PatchOperation;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::modify_conditions::IfMatchCondition;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{IfMatchConditionOption, IfMatchConditionSupport};
+use azure_sdk_core::{No, ToAssign, Yes};
+use hyper::StatusCode;
+use serde::de::DeserializeOwned;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    document_client: &'a DocumentClient<'a, CUB>,
+    p_patch_operations: PhantomData<PatchOperationsSet>,
+    patch_operations: Option<&'b [PatchOperation]>,
+    patch_condition: Option<&'b str>,
+    if_match_condition: Option<IfMatchCondition<'b>>,
+    user_agent: Option<&'b str>,
+    activity_id: Option<&'b str>,
+    consistency_level: Option<ConsistencyLevel<'b>>,
+    allow_tentative_writes: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, CUB> PatchDocumentBuilder<'a, 'b, CUB, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        document_client: &'a DocumentClient<'a, CUB>,
+    ) -> PatchDocumentBuilder<'a, 'b, CUB, No> {
+        PatchDocumentBuilder {
+            document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: None,
+            patch_condition: None,
+            if_match_condition: None,
+            user_agent: None,
+            activity_id: None,
+            consistency_level: None,
+            allow_tentative_writes: false,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> DocumentClientRequired<'a, CUB>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn document_client(&self) -> &'a DocumentClient<'a, CUB> {
+        self.document_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, CUB> PatchOperationsRequired<'b> for PatchDocumentBuilder<'a, 'b, CUB, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn patch_operations(&self) -> &'b [PatchOperation] {
+        self.patch_operations.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> PatchConditionOption<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn patch_condition(&self) -> Option<&'b str> {
+        self.patch_condition
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> IfMatchConditionOption<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'b>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> UserAgentOption<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn user_agent(&self) -> Option<&'b str> {
+        self.user_agent
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> ActivityIdOption<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn activity_id(&self) -> Option<&'b str> {
+        self.activity_id
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> ConsistencyLevelOption<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn consistency_level(&self) -> Option<ConsistencyLevel<'b>> {
+        self.consistency_level.clone()
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> AllowTentativeWritesOption
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn allow_tentative_writes(&self) -> bool {
+        self.allow_tentative_writes
+    }
+}
+
+impl<'a, 'b, CUB> PatchOperationsSupport<'b> for PatchDocumentBuilder<'a, 'b, CUB, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, Yes>;
+
+    #[inline]
+    fn with_patch_operations(self, patch_operations: &'b [PatchOperation]) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: Some(patch_operations),
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> PatchConditionSupport<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_patch_condition(self, patch_condition: &'b str) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: Some(patch_condition),
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> IfMatchConditionSupport<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'b>) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: Some(if_match_condition),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> UserAgentSupport<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_user_agent(self, user_agent: &'b str) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: Some(user_agent),
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> ActivityIdSupport<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_activity_id(self, activity_id: &'b str) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: Some(activity_id),
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> ConsistencyLevelSupport<'b>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_consistency_level(self, consistency_level: ConsistencyLevel<'b>) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: Some(consistency_level),
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> AllowTentativeWritesSupport
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    #[inline]
+    fn with_allow_tentative_writes(self, allow_tentative_writes: bool) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: PhantomData {},
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> DiagnosticsContextOption<'a>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, PatchOperationsSet> DiagnosticsContextSupport<'a>
+    for PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>
+where
+    PatchOperationsSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = PatchDocumentBuilder<'a, 'b, CUB, PatchOperationsSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        PatchDocumentBuilder {
+            document_client: self.document_client,
+            p_patch_operations: self.p_patch_operations,
+            patch_operations: self.patch_operations,
+            patch_condition: self.patch_condition,
+            if_match_condition: self.if_match_condition,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, CUB> PatchDocumentBuilder<'a, 'b, CUB, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute<T>(&self) -> Result<PatchDocumentResponse<T>, AzureError>
+    where
+        T: DeserializeOwned,
+    {
+        trace!("PatchDocumentBuilder::execute called");
+
+        let mut req = self.document_client.prepare_request(hyper::Method::PATCH)?;
+
+        req = req.header(http::header::CONTENT_TYPE, "application/json_patch+json");
+
+        // add trait headers
+        req = IfMatchConditionOption::add_header(self, req);
+        req = UserAgentOption::add_header(self, req);
+        req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
+        req = ConsistencyLevelOption::add_header(self, req);
+        req = AllowTentativeWritesOption::add_header(self, req);
+
+        req = crate::add_partition_keys_header(self.document_client.partition_keys(), req);
+
+        #[derive(Serialize)]
+        struct RequestBody<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            condition: Option<&'a str>,
+            operations: &'a [PatchOperation],
+        }
+
+        let request_body = RequestBody {
+            condition: self.patch_condition(),
+            operations: self.patch_operations(),
+        };
+        let request_body = serde_json::to_string(&request_body)?;
+        debug!("request_body == {}", request_body);
+
+        let req = req.body(hyper::Body::from(request_body))?;
+        debug!("\nreq == {:#?}", req);
+
+        let (headers, body) = check_status_extract_headers_and_body(
+            self.document_client.hyper_client().request(req),
+            StatusCode::OK,
+        )
+        .await?;
+
+        PatchDocumentResponse::try_from((&headers, &body as &[u8]))
+    }
+}
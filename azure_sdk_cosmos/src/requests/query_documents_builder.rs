@@ -2,7 +2,8 @@ use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
 use crate::prelude::*;
 use crate::responses::QueryDocumentsResponse;
 use crate::CollectionClientRequired;
-use crate::Query;
+use crate::DiagnosticsContext;
+use crate::{Query, QueryMetrics};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
@@ -34,6 +35,9 @@ where
     partition_keys: Option<&'b PartitionKeys>,
     query_cross_partition: bool,
     parallelize_cross_partition_query: bool,
+    populate_query_metrics: bool,
+    populate_index_metrics: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB, QuerySet> Clone for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
@@ -56,6 +60,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -82,6 +89,9 @@ where
             partition_keys: None,
             query_cross_partition: false,
             parallelize_cross_partition_query: false,
+            populate_query_metrics: false,
+            populate_index_metrics: false,
+            diagnostics_context: None,
         }
     }
 }
@@ -226,6 +236,30 @@ where
     }
 }
 
+impl<'a, 'b, CUB, QuerySet> PopulateQueryMetricsOption
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn populate_query_metrics(&self) -> bool {
+        self.populate_query_metrics
+    }
+}
+
+impl<'a, 'b, CUB, QuerySet> PopulateIndexMetricsOption
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn populate_index_metrics(&self) -> bool {
+        self.populate_index_metrics
+    }
+}
+
 impl<'a, 'b, CUB> QuerySupport<'b> for QueryDocumentsBuilder<'a, 'b, CUB, No>
 where
     CUB: CosmosUriBuilder,
@@ -248,6 +282,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -276,6 +313,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -304,6 +344,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -331,6 +374,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -358,6 +404,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -386,6 +435,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -413,6 +465,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -440,6 +495,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -468,6 +526,9 @@ where
             partition_keys: Some(partition_keys),
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -496,6 +557,9 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition,
             parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -527,6 +591,112 @@ where
             partition_keys: self.partition_keys,
             query_cross_partition: self.query_cross_partition,
             parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, QuerySet> PopulateQueryMetricsSupport
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>;
+
+    #[inline]
+    fn with_populate_query_metrics(self, populate_query_metrics: bool) -> Self::O {
+        QueryDocumentsBuilder {
+            collection_client: self.collection_client,
+            p_query: PhantomData {},
+            query: self.query,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            partition_keys: self.partition_keys,
+            query_cross_partition: self.query_cross_partition,
+            parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, QuerySet> PopulateIndexMetricsSupport
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>;
+
+    #[inline]
+    fn with_populate_index_metrics(self, populate_index_metrics: bool) -> Self::O {
+        QueryDocumentsBuilder {
+            collection_client: self.collection_client,
+            p_query: PhantomData {},
+            query: self.query,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            partition_keys: self.partition_keys,
+            query_cross_partition: self.query_cross_partition,
+            parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, QuerySet> DiagnosticsContextOption<'a>
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, QuerySet> DiagnosticsContextSupport<'a>
+    for QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>
+where
+    QuerySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = QueryDocumentsBuilder<'a, 'b, CUB, QuerySet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        QueryDocumentsBuilder {
+            collection_client: self.collection_client,
+            p_query: self.p_query,
+            query: self.query,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            continuation: self.continuation,
+            max_item_count: self.max_item_count,
+            partition_keys: self.partition_keys,
+            query_cross_partition: self.query_cross_partition,
+            parallelize_cross_partition_query: self.parallelize_cross_partition_query,
+            populate_query_metrics: self.populate_query_metrics,
+            populate_index_metrics: self.populate_index_metrics,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -550,7 +720,7 @@ where
             ),
             hyper::Method::POST,
             ResourceType::Documents,
-        );
+        )?;
 
         // signal that this is a query
         req = req.header(crate::headers::HEADER_DOCUMENTDB_ISQUERY, true.to_string());
@@ -561,11 +731,14 @@ where
         req = IfModifiedSinceOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
         req = ContinuationOption::add_header(self, req);
         req = MaxItemCountOption::add_header(self, req);
         req = PartitionKeysOption::add_header(self, req);
         req = QueryCrossPartitionOption::add_header(self, req);
+        req = PopulateQueryMetricsOption::add_header(self, req);
+        req = PopulateIndexMetricsOption::add_header(self, req);
 
         let body = serde_json::to_string(self.query())?;
         debug!("body == {}", body);
@@ -586,6 +759,9 @@ where
         Ok(resp)
     }
 
+    /// Pages through the query results. When query metrics are requested,
+    /// the `cumulative_query_metrics` of each page sum up the metrics of
+    /// every page returned so far.
     pub fn stream<T>(
         &self,
     ) -> impl Stream<Item = Result<QueryDocumentsResponse<T>, AzureError>> + '_
@@ -595,33 +771,39 @@ where
         #[derive(Debug, Clone, PartialEq)]
         enum States {
             Init,
-            Continuation(String),
-        };
+            Continuation(String, Option<QueryMetrics>),
+        }
 
         unfold(
             Some(States::Init),
             move |continuation_token: Option<States>| async move {
                 debug!("continuation_token == {:?}", &continuation_token);
-                let response = match continuation_token {
-                    Some(States::Init) => self.execute().await,
-                    Some(States::Continuation(continuation_token)) => {
+                let (response, previous_query_metrics) = match continuation_token {
+                    Some(States::Init) => (self.execute().await, None),
+                    Some(States::Continuation(continuation_token, previous_query_metrics)) => (
                         self.clone()
                             .with_continuation(&continuation_token)
                             .execute()
-                            .await
-                    }
+                            .await,
+                        previous_query_metrics,
+                    ),
                     None => return None,
                 };
 
-                let response = match response {
+                let mut response: QueryDocumentsResponse<T> = match response {
                     Ok(response) => response,
                     Err(err) => return Some((Err(err), None)),
                 };
 
-                let continuation_token = match &response.continuation_token {
-                    Some(ct) => Some(States::Continuation(ct.to_owned())),
-                    None => None,
-                };
+                if let Some(previous_query_metrics) = previous_query_metrics {
+                    response.cumulative_query_metrics = Some(
+                        previous_query_metrics + response.query_metrics.clone().unwrap_or_default(),
+                    );
+                }
+
+                let continuation_token = response.continuation_token.as_ref().map(|ct| {
+                    States::Continuation(ct.to_owned(), response.cumulative_query_metrics.clone())
+                });
 
                 Some((Ok(response), continuation_token))
             },
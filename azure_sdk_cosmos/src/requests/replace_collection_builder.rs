@@ -1,10 +1,11 @@
 use crate::clients::CosmosUriBuilder;
-use crate::collection::IndexingPolicy;
 use crate::collection::PartitionKey;
+use crate::collection::{ConflictResolutionPolicy, IndexingPolicy, UniqueKeyPolicy};
 use crate::prelude::*;
 use crate::responses::CreateCollectionResponse;
 use crate::CollectionBuilderTrait;
 use crate::CollectionClient;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -23,6 +24,10 @@ where
     p_indexing_policy: PhantomData<IndexingPolicySet>,
     partition_key: Option<&'a PartitionKey>,
     indexing_policy: Option<&'a IndexingPolicy>,
+    default_ttl: Option<i64>,
+    unique_key_policy: Option<&'a UniqueKeyPolicy>,
+    conflict_resolution_policy: Option<&'a ConflictResolutionPolicy>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ReplaceCollectionBuilder<'a, CUB, No, No>
@@ -38,6 +43,10 @@ where
             partition_key: None,
             p_indexing_policy: PhantomData {},
             indexing_policy: None,
+            default_ttl: None,
+            unique_key_policy: None,
+            conflict_resolution_policy: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -79,6 +88,42 @@ where
     }
 }
 
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> DefaultTtlOption
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn default_ttl(&self) -> Option<i64> {
+        self.default_ttl
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> UniqueKeyPolicyOption<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn unique_key_policy(&self) -> Option<&'a UniqueKeyPolicy> {
+        self.unique_key_policy
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> ConflictResolutionPolicyOption<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn conflict_resolution_policy(&self) -> Option<&'a ConflictResolutionPolicy> {
+        self.conflict_resolution_policy
+    }
+}
+
 impl<'a, CUB, IndexingPolicySet> PartitionKeySupport<'a>
     for ReplaceCollectionBuilder<'a, CUB, No, IndexingPolicySet>
 where
@@ -94,6 +139,10 @@ where
             p_indexing_policy: PhantomData {},
             partition_key: Some(partition_key),
             indexing_policy: self.indexing_policy,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -113,6 +162,121 @@ where
             p_indexing_policy: PhantomData {},
             partition_key: self.partition_key,
             indexing_policy: Some(indexing_policy),
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> DefaultTtlSupport
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>;
+
+    fn with_default_ttl(self, default_ttl: i64) -> Self::O {
+        ReplaceCollectionBuilder {
+            collection_client: self.collection_client,
+            p_partition_key: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            partition_key: self.partition_key,
+            indexing_policy: self.indexing_policy,
+            default_ttl: Some(default_ttl),
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> UniqueKeyPolicySupport<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>;
+
+    fn with_unique_key_policy(self, unique_key_policy: &'a UniqueKeyPolicy) -> Self::O {
+        ReplaceCollectionBuilder {
+            collection_client: self.collection_client,
+            p_partition_key: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            partition_key: self.partition_key,
+            indexing_policy: self.indexing_policy,
+            default_ttl: self.default_ttl,
+            unique_key_policy: Some(unique_key_policy),
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> ConflictResolutionPolicySupport<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>;
+
+    fn with_conflict_resolution_policy(
+        self,
+        conflict_resolution_policy: &'a ConflictResolutionPolicy,
+    ) -> Self::O {
+        ReplaceCollectionBuilder {
+            collection_client: self.collection_client,
+            p_partition_key: PhantomData {},
+            p_indexing_policy: PhantomData {},
+            partition_key: self.partition_key,
+            indexing_policy: self.indexing_policy,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: Some(conflict_resolution_policy),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> DiagnosticsContextOption<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, PartitionKeysSet, IndexingPolicySet> DiagnosticsContextSupport<'a>
+    for ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>
+where
+    PartitionKeysSet: ToAssign,
+    IndexingPolicySet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceCollectionBuilder<'a, CUB, PartitionKeysSet, IndexingPolicySet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ReplaceCollectionBuilder {
+            collection_client: self.collection_client,
+            p_partition_key: self.p_partition_key,
+            p_indexing_policy: self.p_indexing_policy,
+            partition_key: self.partition_key,
+            indexing_policy: self.indexing_policy,
+            default_ttl: self.default_ttl,
+            unique_key_policy: self.unique_key_policy,
+            conflict_resolution_policy: self.conflict_resolution_policy,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -135,7 +299,7 @@ where
     pub async fn execute(&self) -> Result<CreateCollectionResponse, AzureError> {
         trace!("ReplaceCollectionBuilder::execute called");
 
-        let mut req = self.collection_client.prepare_request(hyper::Method::PUT);
+        let mut req = self.collection_client.prepare_request(hyper::Method::PUT)?;
 
         req = req.header(http::header::CONTENT_TYPE, "application/json");
 
@@ -146,17 +310,33 @@ where
             indexing_policy: &'k IndexingPolicy,
             #[serde(rename = "partitionKey")]
             partition_key: &'k crate::collection::PartitionKey,
+            #[serde(rename = "defaultTtl")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_ttl: Option<i64>,
+            #[serde(rename = "uniqueKeyPolicy")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            unique_key_policy: Option<&'k UniqueKeyPolicy>,
+            #[serde(rename = "conflictResolutionPolicy")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            conflict_resolution_policy: Option<&'k ConflictResolutionPolicy>,
         };
 
+        // a missing defaultTtl disables the expiration. The unique key
+        // policy cannot be changed so, if passed, it must match the one
+        // specified at creation.
         let request = Request {
             id: self.collection_client().collection_name().name(),
             indexing_policy: self.indexing_policy(),
             partition_key: self.partition_key(),
+            default_ttl: self.default_ttl(),
+            unique_key_policy: self.unique_key_policy(),
+            conflict_resolution_policy: self.conflict_resolution_policy(),
         };
 
         let body = serde_json::to_string(&request)?;
         debug!("body == {}", body);
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::from(body))?;
         debug!("\nreq == {:?}", req);
 
@@ -3,6 +3,7 @@ use crate::document::Document;
 use crate::prelude::*;
 use crate::responses::ReplaceDocumentResponse;
 use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
@@ -30,6 +31,7 @@ where
     document: Option<&'b Document<T>>,
     partition_keys: Option<&'b PartitionKeys>,
     document_id: Option<&'b str>,
+    ttl: Option<i64>,
     indexing_directive: IndexingDirective,
     if_match_condition: Option<IfMatchCondition<'b>>,
     if_modified_since: Option<&'b DateTime<Utc>>,
@@ -37,6 +39,7 @@ where
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
     allow_tentative_writes: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, T, CUB> ReplaceDocumentBuilder<'a, 'b, T, CUB, No, No, No>
@@ -56,6 +59,7 @@ where
             partition_keys: None,
             p_document_id: PhantomData {},
             document_id: None,
+            ttl: None,
             indexing_directive: IndexingDirective::Default,
             if_match_condition: None,
             if_modified_since: None,
@@ -63,6 +67,7 @@ where
             activity_id: None,
             consistency_level: None,
             allow_tentative_writes: false,
+            diagnostics_context: None,
         }
     }
 }
@@ -127,6 +132,21 @@ where
     }
 }
 
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet> TtlOption
+    for ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    DocumentIdSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn ttl(&self) -> Option<i64> {
+        self.ttl
+    }
+}
+
 impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet> IndexingDirectiveOption
     for ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>
 where
@@ -252,6 +272,7 @@ where
             document: Some(document),
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -259,6 +280,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -283,6 +305,7 @@ where
             document: self.document,
             partition_keys: Some(partition_keys),
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -290,6 +313,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -314,6 +338,41 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: Some(document_id),
+            ttl: self.ttl,
+            indexing_directive: self.indexing_directive,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet> TtlSupport
+    for ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    DocumentIdSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>;
+
+    #[inline]
+    fn with_ttl(self, ttl: i64) -> Self::O {
+        ReplaceDocumentBuilder {
+            collection_client: self.collection_client,
+            p_document: PhantomData {},
+            p_partition_keys: PhantomData {},
+            p_document_id: PhantomData {},
+            document: self.document,
+            partition_keys: self.partition_keys,
+            document_id: self.document_id,
+            ttl: Some(ttl),
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -321,6 +380,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -346,6 +406,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -353,6 +414,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -378,6 +440,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: Some(if_match_condition),
             if_modified_since: self.if_modified_since,
@@ -385,6 +448,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -410,6 +474,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: Some(if_modified_since),
@@ -417,6 +482,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -442,6 +508,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -449,6 +516,7 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -474,6 +542,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -481,6 +550,7 @@ where
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -506,6 +576,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -513,6 +584,7 @@ where
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
             allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -538,6 +610,7 @@ where
             document: self.document,
             partition_keys: self.partition_keys,
             document_id: self.document_id,
+            ttl: self.ttl,
             indexing_directive: self.indexing_directive,
             if_match_condition: self.if_match_condition,
             if_modified_since: self.if_modified_since,
@@ -545,6 +618,54 @@ where
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
             allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet> DiagnosticsContextOption<'a>
+    for ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    DocumentIdSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet> DiagnosticsContextSupport<'a>
+    for ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>
+where
+    DocumentSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    DocumentIdSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceDocumentBuilder<'a, 'b, T, CUB, DocumentSet, PartitionKeysSet, DocumentIdSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ReplaceDocumentBuilder {
+            collection_client: self.collection_client,
+            p_document: self.p_document,
+            p_partition_keys: self.p_partition_keys,
+            p_document_id: self.p_document_id,
+            document: self.document,
+            partition_keys: self.partition_keys,
+            document_id: self.document_id,
+            ttl: self.ttl,
+            indexing_directive: self.indexing_directive,
+            if_match_condition: self.if_match_condition,
+            if_modified_since: self.if_modified_since,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -556,6 +677,36 @@ where
     CUB: CosmosUriBuilder,
 {
     pub async fn execute(&self) -> Result<ReplaceDocumentResponse, AzureError> {
+        self.send(self.document_id(), self.partition_keys()).await
+    }
+}
+
+// entities carry their own id and partition keys so they can be omitted
+impl<'a, 'b, T, CUB> ReplaceDocumentBuilder<'a, 'b, T, CUB, Yes, No, No>
+where
+    T: Serialize + CosmosEntity,
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute(&self) -> Result<ReplaceDocumentResponse, AzureError> {
+        let entity = &self.document().document;
+        let partition_keys = entity.partition_keys()?;
+        self.send(entity.id(), &partition_keys).await
+    }
+}
+
+impl<'a, 'b, T, CUB, PartitionKeysSet, DocumentIdSet>
+    ReplaceDocumentBuilder<'a, 'b, T, CUB, Yes, PartitionKeysSet, DocumentIdSet>
+where
+    PartitionKeysSet: ToAssign,
+    DocumentIdSet: ToAssign,
+    T: Serialize,
+    CUB: CosmosUriBuilder,
+{
+    async fn send(
+        &self,
+        document_id: &str,
+        partition_keys: &PartitionKeys,
+    ) -> Result<ReplaceDocumentResponse, AzureError> {
         trace!("ReplaceDocumentBuilder::execute() called");
 
         let mut req = self.collection_client.main_client().prepare_request(
@@ -563,11 +714,11 @@ where
                 "dbs/{}/colls/{}/docs/{}",
                 self.collection_client.database_name().name(),
                 self.collection_client.collection_name().name(),
-                self.document_id()
+                document_id
             ),
             hyper::Method::PUT,
             ResourceType::Documents,
-        );
+        )?;
 
         // add trait headers
         req = IndexingDirectiveOption::add_header(self, req);
@@ -575,11 +726,18 @@ where
         req = IfModifiedSinceOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
-        req = PartitionKeysRequired::add_header(self, req);
+        req = crate::add_partition_keys_header(partition_keys, req);
         req = AllowTentativeWritesOption::add_header(self, req);
 
-        let serialized = serde_json::to_string(self.document())?;
+        // the ttl passed to the builder takes precedence over a ttl field
+        // of the document
+        let mut serialized = serde_json::to_value(self.document())?;
+        if let (Some(ttl), Some(map)) = (self.ttl(), serialized.as_object_mut()) {
+            map.insert("ttl".to_owned(), ttl.into());
+        }
+        let serialized = serde_json::to_string(&serialized)?;
 
         let req = req.body(hyper::Body::from(serialized))?;
         debug!("request == {:#?}", req);
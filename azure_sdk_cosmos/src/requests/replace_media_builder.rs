@@ -0,0 +1,268 @@
+use crate::clients::CosmosUriBuilder;
+use crate::prelude::*;
+use crate::responses::ReplaceMediaResponse;
+use crate::AttachmentClient;
+use crate::AttachmentClientRequired;
+use crate::DiagnosticsContext;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use hyper::StatusCode;
+use std::convert::TryInto;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    attachment_client: &'a AttachmentClient<'a, CUB>,
+    p_content_type: PhantomData<ContentTypeSet>,
+    content_type: Option<&'b str>,
+    media: Option<&'b str>,
+    user_agent: Option<&'b str>,
+    activity_id: Option<&'b str>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, CUB> ReplaceMediaBuilder<'a, 'b, CUB, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        attachment_client: &'a AttachmentClient<'a, CUB>,
+    ) -> ReplaceMediaBuilder<'a, 'b, CUB, No> {
+        ReplaceMediaBuilder {
+            attachment_client,
+            p_content_type: PhantomData {},
+            content_type: None,
+            media: None,
+            user_agent: None,
+            activity_id: None,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> AttachmentClientRequired<'a, CUB>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn attachment_client(&self) -> &'a AttachmentClient<'a, CUB> {
+        self.attachment_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, CUB> ContentTypeRequired<'b> for ReplaceMediaBuilder<'a, 'b, CUB, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn content_type(&self) -> &'b str {
+        self.content_type.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> MediaOption<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn media(&self) -> Option<&'b str> {
+        self.media
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> UserAgentOption<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn user_agent(&self) -> Option<&'b str> {
+        self.user_agent
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> ActivityIdOption<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn activity_id(&self) -> Option<&'b str> {
+        self.activity_id
+    }
+}
+
+impl<'a, 'b, CUB> ContentTypeSupport<'b> for ReplaceMediaBuilder<'a, 'b, CUB, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceMediaBuilder<'a, 'b, CUB, Yes>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'b str) -> Self::O {
+        ReplaceMediaBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: PhantomData {},
+            content_type: Some(content_type),
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> MediaSupport<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>;
+
+    #[inline]
+    fn with_media(self, media: &'b str) -> Self::O {
+        ReplaceMediaBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: PhantomData {},
+            content_type: self.content_type,
+            media: Some(media),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> UserAgentSupport<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>;
+
+    #[inline]
+    fn with_user_agent(self, user_agent: &'b str) -> Self::O {
+        ReplaceMediaBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: PhantomData {},
+            content_type: self.content_type,
+            media: self.media,
+            user_agent: Some(user_agent),
+            activity_id: self.activity_id,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> ActivityIdSupport<'b>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>;
+
+    #[inline]
+    fn with_activity_id(self, activity_id: &'b str) -> Self::O {
+        ReplaceMediaBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: PhantomData {},
+            content_type: self.content_type,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: Some(activity_id),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> DiagnosticsContextOption<'a>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet> DiagnosticsContextSupport<'a>
+    for ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>
+where
+    ContentTypeSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceMediaBuilder<'a, 'b, CUB, ContentTypeSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ReplaceMediaBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: self.p_content_type,
+            content_type: self.content_type,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, CUB> ReplaceMediaBuilder<'a, 'b, CUB, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    /// Uploads the media of a slug attachment, replacing the current one.
+    /// The body can be anything convertible to `hyper::Body`, including a
+    /// stream of chunks wrapped with `hyper::Body::wrap_stream`. The media
+    /// link is read from the attachment unless passed with `with_media`.
+    pub async fn execute<B>(&self, body: B) -> Result<ReplaceMediaResponse, AzureError>
+    where
+        B: Into<hyper::Body>,
+    {
+        let media_id = self.attachment_client.media_id(self.media()).await?;
+
+        let mut req = self
+            .attachment_client
+            .prepare_media_request(&media_id, hyper::Method::PUT)?;
+
+        // add trait headers
+        req = UserAgentOption::add_header(self, req);
+        req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
+        req = ContentTypeRequired::add_header(self, req);
+
+        let req = req.body(body.into())?;
+
+        debug!("req == {:#?}", req);
+
+        let (headers, whole_body) = check_status_extract_headers_and_body(
+            self.attachment_client.hyper_client().request(req),
+            StatusCode::OK,
+        )
+        .await?;
+
+        debug!("\nheaders == {:?}", headers);
+        debug!("\nwhole body == {:#?}", whole_body);
+
+        (&headers, &whole_body as &[u8]).try_into()
+    }
+}
@@ -3,6 +3,7 @@ use crate::prelude::*;
 use crate::AttachmentBuilderTrait;
 use crate::AttachmentClient;
 use crate::AttachmentClientRequired;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::prelude::*;
 use azure_sdk_core::{No, ToAssign, Yes};
@@ -26,6 +27,7 @@ where
     user_agent: Option<&'b str>,
     activity_id: Option<&'b str>,
     consistency_level: Option<ConsistencyLevel<'b>>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, 'b, CUB> ReplaceReferenceAttachmentBuilder<'a, 'b, CUB, No, No>
@@ -46,6 +48,7 @@ where
             user_agent: None,
             activity_id: None,
             consistency_level: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -162,6 +165,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -187,6 +191,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -211,6 +216,7 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -236,6 +242,7 @@ where
             user_agent: Some(user_agent),
             activity_id: self.activity_id,
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -261,6 +268,7 @@ where
             user_agent: self.user_agent,
             activity_id: Some(activity_id),
             consistency_level: self.consistency_level,
+            diagnostics_context: self.diagnostics_context,
         }
     }
 }
@@ -286,6 +294,44 @@ where
             user_agent: self.user_agent,
             activity_id: self.activity_id,
             consistency_level: Some(consistency_level),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet, MediaSet> DiagnosticsContextOption<'a>
+    for ReplaceReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>
+where
+    ContentTypeSet: ToAssign,
+    MediaSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, ContentTypeSet, MediaSet> DiagnosticsContextSupport<'a>
+    for ReplaceReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>
+where
+    ContentTypeSet: ToAssign,
+    MediaSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceReferenceAttachmentBuilder<'a, 'b, CUB, ContentTypeSet, MediaSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ReplaceReferenceAttachmentBuilder {
+            attachment_client: self.attachment_client,
+            p_content_type: self.p_content_type,
+            p_media: self.p_media,
+            content_type: self.content_type,
+            if_match_condition: self.if_match_condition,
+            media: self.media,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -298,12 +344,13 @@ where
     pub async fn execute(
         &self,
     ) -> Result<crate::responses::ReplaceReferenceAttachmentResponse, AzureError> {
-        let mut req = self.attachment_client.prepare_request(hyper::Method::PUT);
+        let mut req = self.attachment_client.prepare_request(hyper::Method::PUT)?;
 
         // add trait headers
         req = IfMatchConditionOption::add_header(self, req);
         req = UserAgentOption::add_header(self, req);
         req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
         req = ConsistencyLevelOption::add_header(self, req);
 
         req = crate::add_partition_keys_header(
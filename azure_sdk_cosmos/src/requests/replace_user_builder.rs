@@ -1,6 +1,7 @@
 use crate::clients::{CosmosUriBuilder, ResourceType, UserClient};
 use crate::prelude::*;
 use crate::responses::CreateUserResponse;
+use crate::DiagnosticsContext;
 use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
 use azure_sdk_core::{No, ToAssign, Yes};
 use hyper::StatusCode;
@@ -16,6 +17,7 @@ where
     user_client: &'a UserClient<'a, CUB>,
     p_user_name: PhantomData<UserNameSet>,
     user_name: Option<&'a dyn UserName>,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
 }
 
 impl<'a, CUB> ReplaceUserBuilder<'a, CUB, No>
@@ -28,6 +30,7 @@ where
             user_client,
             p_user_name: PhantomData {},
             user_name: None,
+            diagnostics_context: None,
         }
     }
 }
@@ -68,6 +71,35 @@ where
             user_client: self.user_client,
             p_user_name: PhantomData {},
             user_name: Some(user_name),
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB, UserNameSet> DiagnosticsContextOption<'a> for ReplaceUserBuilder<'a, CUB, UserNameSet>
+where
+    UserNameSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB, UserNameSet> DiagnosticsContextSupport<'a>
+    for ReplaceUserBuilder<'a, CUB, UserNameSet>
+where
+    UserNameSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = ReplaceUserBuilder<'a, CUB, UserNameSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        ReplaceUserBuilder {
+            user_client: self.user_client,
+            p_user_name: self.p_user_name,
+            user_name: self.user_name,
+            diagnostics_context: Some(diagnostics_context),
         }
     }
 }
@@ -88,7 +120,7 @@ where
             ),
             hyper::Method::PUT,
             ResourceType::Users,
-        );
+        )?;
 
         #[derive(Serialize, Deserialize)]
         struct RequestBody<'a> {
@@ -99,6 +131,7 @@ where
         };
         let request_body = serde_json::to_string(&request_body)?;
 
+        let req = DiagnosticsContextOption::add_extension(self, req);
         let req = req.body(hyper::Body::from(request_body))?;
         debug!("\nreq == {:?}", req);
 
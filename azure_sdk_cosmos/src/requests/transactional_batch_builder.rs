@@ -0,0 +1,405 @@
+use crate::clients::{CollectionClient, CosmosUriBuilder, ResourceType};
+use crate::prelude::*;
+use crate::responses::TransactionalBatchResponse;
+use crate::transactional_batch::TRANSACTIONAL_BATCH_MAX_OPERATIONS;
+use crate::CollectionClientRequired;
+use crate::DiagnosticsContext;
+use crate::TransactionalBatch;
+use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use hyper::StatusCode;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    collection_client: &'a CollectionClient<'a, CUB>,
+    p_transactional_batch: PhantomData<TransactionalBatchSet>,
+    p_partition_keys: PhantomData<PartitionKeysSet>,
+    transactional_batch: Option<&'b TransactionalBatch>,
+    partition_keys: Option<&'b PartitionKeys>,
+    user_agent: Option<&'b str>,
+    activity_id: Option<&'b str>,
+    consistency_level: Option<ConsistencyLevel<'b>>,
+    allow_tentative_writes: bool,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, 'b, CUB> TransactionalBatchBuilder<'a, 'b, CUB, No, No>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        collection_client: &'a CollectionClient<'a, CUB>,
+    ) -> TransactionalBatchBuilder<'a, 'b, CUB, No, No> {
+        TransactionalBatchBuilder {
+            collection_client,
+            p_transactional_batch: PhantomData {},
+            transactional_batch: None,
+            p_partition_keys: PhantomData {},
+            partition_keys: None,
+            user_agent: None,
+            activity_id: None,
+            consistency_level: None,
+            allow_tentative_writes: false,
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> CollectionClientRequired<'a, CUB>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn collection_client(&self) -> &'a CollectionClient<'a, CUB> {
+        self.collection_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, 'b, CUB, PartitionKeysSet> TransactionalBatchRequired<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, Yes, PartitionKeysSet>
+where
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn transactional_batch(&self) -> &'b TransactionalBatch {
+        self.transactional_batch.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet> PartitionKeysRequired<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, Yes>
+where
+    TransactionalBatchSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn partition_keys(&self) -> &'b PartitionKeys {
+        self.partition_keys.unwrap()
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> UserAgentOption<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn user_agent(&self) -> Option<&'b str> {
+        self.user_agent
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> ActivityIdOption<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn activity_id(&self) -> Option<&'b str> {
+        self.activity_id
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> ConsistencyLevelOption<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn consistency_level(&self) -> Option<ConsistencyLevel<'b>> {
+        self.consistency_level.clone()
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> AllowTentativeWritesOption
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn allow_tentative_writes(&self) -> bool {
+        self.allow_tentative_writes
+    }
+}
+
+impl<'a, 'b, CUB, PartitionKeysSet> TransactionalBatchSupport<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, No, PartitionKeysSet>
+where
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, Yes, PartitionKeysSet>;
+
+    #[inline]
+    fn with_transactional_batch(self, transactional_batch: &'b TransactionalBatch) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: Some(transactional_batch),
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet> PartitionKeysSupport<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, No>
+where
+    TransactionalBatchSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, Yes>;
+
+    #[inline]
+    fn with_partition_keys(self, partition_keys: &'b PartitionKeys) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: self.transactional_batch,
+            partition_keys: Some(partition_keys),
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> UserAgentSupport<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_user_agent(self, user_agent: &'b str) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: self.transactional_batch,
+            partition_keys: self.partition_keys,
+            user_agent: Some(user_agent),
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> ActivityIdSupport<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_activity_id(self, activity_id: &'b str) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: self.transactional_batch,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: Some(activity_id),
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> ConsistencyLevelSupport<'b>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_consistency_level(self, consistency_level: ConsistencyLevel<'b>) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: self.transactional_batch,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: Some(consistency_level),
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> AllowTentativeWritesSupport
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>;
+
+    #[inline]
+    fn with_allow_tentative_writes(self, allow_tentative_writes: bool) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: PhantomData {},
+            p_partition_keys: PhantomData {},
+            transactional_batch: self.transactional_batch,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> DiagnosticsContextOption<'a>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet> DiagnosticsContextSupport<'a>
+    for TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>
+where
+    TransactionalBatchSet: ToAssign,
+    PartitionKeysSet: ToAssign,
+    CUB: CosmosUriBuilder,
+{
+    type O = TransactionalBatchBuilder<'a, 'b, CUB, TransactionalBatchSet, PartitionKeysSet>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        TransactionalBatchBuilder {
+            collection_client: self.collection_client,
+            p_transactional_batch: self.p_transactional_batch,
+            p_partition_keys: self.p_partition_keys,
+            transactional_batch: self.transactional_batch,
+            partition_keys: self.partition_keys,
+            user_agent: self.user_agent,
+            activity_id: self.activity_id,
+            consistency_level: self.consistency_level,
+            allow_tentative_writes: self.allow_tentative_writes,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, 'b, CUB> TransactionalBatchBuilder<'a, 'b, CUB, Yes, Yes>
+where
+    CUB: CosmosUriBuilder,
+{
+    pub async fn execute(&self) -> Result<TransactionalBatchResponse, AzureError> {
+        trace!("TransactionalBatchBuilder::execute called");
+
+        let operations = self.transactional_batch().operations();
+        if operations.is_empty() || operations.len() > TRANSACTIONAL_BATCH_MAX_OPERATIONS {
+            return Err(AzureError::InputParametersError(format!(
+                "a transactional batch must contain between 1 and {} operations, {} specified",
+                TRANSACTIONAL_BATCH_MAX_OPERATIONS,
+                operations.len()
+            )));
+        }
+
+        let mut req = self.collection_client.main_client().prepare_request(
+            &format!(
+                "dbs/{}/colls/{}/docs",
+                self.collection_client.database_name().name(),
+                self.collection_client.collection_name().name()
+            ),
+            hyper::Method::POST,
+            ResourceType::Documents,
+        )?;
+
+        // signal that this is an atomic batch
+        req = req.header(crate::headers::HEADER_COSMOS_IS_BATCH_REQUEST, "True");
+        req = req.header(crate::headers::HEADER_COSMOS_BATCH_ATOMIC, "True");
+        req = req.header(crate::headers::HEADER_COSMOS_BATCH_ORDERED, "True");
+        req = req.header(http::header::CONTENT_TYPE, "application/json");
+
+        // add trait headers
+        req = UserAgentOption::add_header(self, req);
+        req = ActivityIdOption::add_header(self, req);
+        req = DiagnosticsContextOption::add_extension(self, req);
+        req = ConsistencyLevelOption::add_header(self, req);
+        req = PartitionKeysRequired::add_header(self, req);
+        req = AllowTentativeWritesOption::add_header(self, req);
+
+        let body = serde_json::to_string(operations)?;
+        debug!("body == {}", body);
+
+        let req = req.body(hyper::Body::from(body))?;
+        debug!("\nreq == {:#?}", req);
+
+        let (status_code, headers, whole_body) =
+            extract_status_headers_and_body(self.collection_client.hyper_client().request(req))
+                .await?;
+
+        // a failed batch is rolled back by the server and answered with the
+        // status code of the failing operation. As long as the body carries
+        // the per-operation results we hand them back to the caller.
+        match TransactionalBatchResponse::try_from((status_code, &headers, &whole_body as &[u8])) {
+            Ok(response) => Ok(response),
+            Err(err) if status_code == StatusCode::OK => Err(err),
+            Err(_) => Err(UnexpectedHTTPResult::new(
+                StatusCode::OK,
+                status_code,
+                std::str::from_utf8(&whole_body)?,
+            )
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use crate::clients::{CosmosUriBuilder, DocumentClient};
+use crate::diagnostics::{retrying, with_diagnostics_context_of};
+use crate::prelude::*;
+use crate::responses::{GetDocumentResponse, UpdateDocumentResponse};
+use crate::DiagnosticsContext;
+use crate::DocumentClientRequired;
+use azure_sdk_core::errors::{AzureError, UnexpectedHTTPResult};
+use azure_sdk_core::modify_conditions::IfMatchCondition;
+use azure_sdk_core::prelude::*;
+use hyper::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    document_client: &'a DocumentClient<'a, CUB>,
+    max_attempts: u32,
+    retry_backoff: Duration,
+    diagnostics_context: Option<&'a DiagnosticsContext>,
+}
+
+impl<'a, CUB> UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    pub(crate) fn new(
+        document_client: &'a DocumentClient<'a, CUB>,
+    ) -> UpdateDocumentBuilder<'a, CUB> {
+        UpdateDocumentBuilder {
+            document_client,
+            max_attempts: 5,
+            retry_backoff: Duration::from_millis(50),
+            diagnostics_context: None,
+        }
+    }
+}
+
+impl<'a, CUB> DocumentClientRequired<'a, CUB> for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn document_client(&self) -> &'a DocumentClient<'a, CUB> {
+        self.document_client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, CUB> MaxAttemptsOption for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+}
+
+impl<'a, CUB> RetryBackoffOption for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    #[inline]
+    fn retry_backoff(&self) -> Duration {
+        self.retry_backoff
+    }
+}
+
+impl<'a, CUB> MaxAttemptsSupport for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = UpdateDocumentBuilder<'a, CUB>;
+
+    #[inline]
+    fn with_max_attempts(self, max_attempts: u32) -> Self::O {
+        UpdateDocumentBuilder {
+            document_client: self.document_client,
+            max_attempts,
+            retry_backoff: self.retry_backoff,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> RetryBackoffSupport for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = UpdateDocumentBuilder<'a, CUB>;
+
+    #[inline]
+    fn with_retry_backoff(self, retry_backoff: Duration) -> Self::O {
+        UpdateDocumentBuilder {
+            document_client: self.document_client,
+            max_attempts: self.max_attempts,
+            retry_backoff,
+            diagnostics_context: self.diagnostics_context,
+        }
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextOption<'a> for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    fn diagnostics_context(&self) -> Option<&'a DiagnosticsContext> {
+        self.diagnostics_context
+    }
+}
+
+impl<'a, CUB> DiagnosticsContextSupport<'a> for UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    type O = UpdateDocumentBuilder<'a, CUB>;
+
+    fn with_diagnostics_context(self, diagnostics_context: &'a DiagnosticsContext) -> Self::O {
+        UpdateDocumentBuilder {
+            document_client: self.document_client,
+            max_attempts: self.max_attempts,
+            retry_backoff: self.retry_backoff,
+            diagnostics_context: Some(diagnostics_context),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, CUB> UpdateDocumentBuilder<'a, CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    /// Reads the document, applies `mutate` and replaces it if its etag did
+    /// not change in the meantime. The whole cycle is retried, waiting
+    /// `retry_backoff` (doubled at every attempt) in between, as long as
+    /// another writer gets there first. Fails if the document does not
+    /// exist.
+    pub async fn execute<T, F>(&self, mut mutate: F) -> Result<UpdateDocumentResponse, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnMut(&mut T),
+    {
+        self.read_modify_write(|document: Option<T>| match document {
+            Some(mut document) => {
+                mutate(&mut document);
+                Ok(document)
+            }
+            None => Err(UnexpectedHTTPResult::new(
+                StatusCode::OK,
+                StatusCode::NOT_FOUND,
+                "document not found",
+            )
+            .into()),
+        })
+        .await
+    }
+
+    /// Like `execute`, but `mutate` receives `None` if the document does not
+    /// exist yet and the document it returns is created instead. The
+    /// returned document must carry the id of the document client.
+    pub async fn execute_or_create<T, F>(
+        &self,
+        mut mutate: F,
+    ) -> Result<UpdateDocumentResponse, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnMut(Option<T>) -> T,
+    {
+        self.read_modify_write(|document| Ok(mutate(document)))
+            .await
+    }
+
+    async fn read_modify_write<T, F>(
+        &self,
+        mut mutate: F,
+    ) -> Result<UpdateDocumentResponse, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnMut(Option<T>) -> Result<T, AzureError>,
+    {
+        if self.max_attempts() == 0 {
+            return Err(AzureError::InputParametersError(
+                "max_attempts must be greater than zero".to_owned(),
+            ));
+        }
+
+        let mut attempt = 1;
+        loop {
+            // errors of the read and of the mutation are not retried
+            let cycle = async {
+                let get_builder = with_diagnostics_context_of(
+                    self.document_client.get_document(),
+                    self.diagnostics_context(),
+                );
+                let result = match get_builder.execute::<T>().await? {
+                    GetDocumentResponse::Found(response) => {
+                        let response = *response;
+                        let document = Document::new(mutate(Some(response.document.document))?);
+                        let replace_builder = self
+                            .document_client
+                            .collection_client()
+                            .replace_document()
+                            .with_document(&document)
+                            .with_document_id(self.document_client.document_name().name())
+                            .with_partition_keys(self.document_client.partition_keys())
+                            .with_if_match_condition(IfMatchCondition::Match(&response.etag));
+                        // a replace without ttl would reset the document to the
+                        // collection default
+                        let replace_builder = match ttl_to_keep(response.ttl, &document.document)? {
+                            Some(ttl) => replace_builder.with_ttl(ttl),
+                            None => replace_builder,
+                        };
+                        with_diagnostics_context_of(replace_builder, self.diagnostics_context())
+                            .execute()
+                            .await
+                            .map(UpdateDocumentResponse::Replaced)
+                    }
+                    GetDocumentResponse::NotFound(_) => {
+                        let document = Document::new(mutate(None)?);
+                        let create_builder = self
+                            .document_client
+                            .collection_client()
+                            .create_document()
+                            .with_document(&document)
+                            .with_partition_keys(self.document_client.partition_keys());
+                        with_diagnostics_context_of(create_builder, self.diagnostics_context())
+                            .execute()
+                            .await
+                            .map(UpdateDocumentResponse::Created)
+                    }
+                };
+                Ok::<_, AzureError>(result)
+            };
+            let result = if attempt > 1 {
+                retrying(cycle).await?
+            } else {
+                cycle.await?
+            };
+
+            match result {
+                Err(ref error) if lost_race(error) && attempt < self.max_attempts() => {
+                    let backoff = self.retry_backoff() * (1 << (attempt - 1).min(6));
+                    debug!(
+                        "document {} changed concurrently, retrying in {:?} (attempt {})",
+                        self.document_client.document_name().name(),
+                        backoff,
+                        attempt
+                    );
+                    tokio::time::delay_for(backoff).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+// The ttl the document was read with, unless its type carries the ttl
+// itself: then the value set by the mutation wins.
+fn ttl_to_keep<T: Serialize>(
+    read_ttl: Option<i64>,
+    document: &T,
+) -> Result<Option<i64>, AzureError> {
+    match read_ttl {
+        Some(ttl) if serde_json::to_value(document)?.get("ttl").is_none() => Ok(Some(ttl)),
+        _ => Ok(None),
+    }
+}
+
+// Another writer modified (412), deleted (404) or created (409) the
+// document between our read and our write.
+fn lost_race(error: &AzureError) -> bool {
+    match error {
+        AzureError::UnexpectedHTTPResult(result) => {
+            result.status_code() == StatusCode::PRECONDITION_FAILED
+                || result.status_code() == StatusCode::NOT_FOUND
+                || result.status_code() == StatusCode::CONFLICT
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Counter {
+        id: String,
+        hello: u32,
+    }
+
+    #[derive(Serialize)]
+    struct Session {
+        id: String,
+        ttl: i64,
+    }
+
+    #[test]
+    fn ttl_is_kept() {
+        let counter = Counter {
+            id: "counter".to_owned(),
+            hello: 1,
+        };
+        assert_eq!(ttl_to_keep(Some(300), &counter).unwrap(), Some(300));
+        assert_eq!(ttl_to_keep(None, &counter).unwrap(), None);
+
+        let session = Session {
+            id: "session".to_owned(),
+            ttl: 60,
+        };
+        assert_eq!(ttl_to_keep(Some(300), &session).unwrap(), None);
+    }
+}
@@ -0,0 +1,426 @@
+use crate::clients::{Client, CosmosUriBuilder};
+use crate::prelude::*;
+use crate::{AuthorizationTokenProvider, Permission, PermissionMode, PermissionToken};
+use azure_sdk_core::errors::AzureError;
+use futures::lock::Mutex as AsyncMutex;
+use hyper::StatusCode;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+const DEFAULT_EXPIRY_SECONDS: u64 = 3600;
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+const RETRY_AFTER_FAILURE: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResourceToken {
+    pub resource: String,
+    pub permission_token: PermissionToken,
+}
+
+impl<'a> std::convert::From<Permission<'a, Cow<'a, str>>> for ResourceToken {
+    fn from(permission: Permission<'a, Cow<'a, str>>) -> Self {
+        Self {
+            resource: permission.permission_mode.to_elements().1.to_string(),
+            permission_token: permission.permission_token,
+        }
+    }
+}
+
+/// The resource tokens granted to a single user, along with the moment
+/// they stop being valid.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResourceTokens {
+    tokens: Vec<ResourceToken>,
+    expires_at: Option<Instant>,
+}
+
+impl ResourceTokens {
+    pub fn tokens(&self) -> &[ResourceToken] {
+        &self.tokens
+    }
+
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    // A margin too large to be added to the current time means that the
+    // tokens always need a refresh.
+    fn needs_refresh(&self, refresh_margin: Duration) -> bool {
+        match (self.expires_at, Instant::now().checked_add(refresh_margin)) {
+            (Some(expires_at), Some(refresh_at)) => refresh_at >= expires_at,
+            _ => true,
+        }
+    }
+
+    /// Returns the token of the most specific permission covering the
+    /// resource link, if any.
+    pub fn token_for(&self, resource_link: &str) -> Option<&PermissionToken> {
+        let resource_link = resource_link.trim_matches('/');
+
+        self.tokens
+            .iter()
+            .filter(|token| {
+                let resource = token.resource.trim_matches('/');
+                resource_link == resource
+                    || (resource_link.starts_with(resource)
+                        && resource_link[resource.len()..].starts_with('/'))
+            })
+            .max_by_key(|token| token.resource.trim_matches('/').len())
+            .map(|token| &token.permission_token)
+    }
+}
+
+/// Signs every request with the resource token of the permission
+/// covering the resource being accessed. The tokens are kept up to date by
+/// the `ResourceTokenBroker` that created the provider: a request for a
+/// resource no token covers, or made once the tokens have expired, fails
+/// before being sent.
+#[derive(Debug, Clone)]
+pub struct ResourceTokenProvider {
+    tokens: Arc<RwLock<ResourceTokens>>,
+}
+
+impl ResourceTokenProvider {
+    pub fn tokens(&self) -> ResourceTokens {
+        self.tokens.read().unwrap().clone()
+    }
+}
+
+impl AuthorizationTokenProvider for ResourceTokenProvider {
+    fn authorization_token(&self, resource_link: &str) -> Result<AuthorizationToken, AzureError> {
+        let tokens = self.tokens.read().unwrap();
+        if tokens.needs_refresh(Duration::default()) {
+            return Err(AzureError::GenericErrorWithText(
+                "the resource tokens have expired, keep the broker refreshing them".to_owned(),
+            ));
+        }
+        match tokens.token_for(resource_link) {
+            Some(permission_token) => Ok(permission_token.clone().into()),
+            None => Err(AzureError::GenericErrorWithText(format!(
+                "no resource token covers {}",
+                resource_link
+            ))),
+        }
+    }
+}
+
+/// The cached tokens of a user. The lock is held while refreshing so that
+/// concurrent callers wait for, and then reuse, the new tokens.
+#[derive(Debug, Default)]
+struct UserTokens {
+    tokens: Arc<RwLock<ResourceTokens>>,
+    refresh_lock: AsyncMutex<()>,
+}
+
+/// Hands out per-user resource tokens using a client authenticated with
+/// the master key. The permissions registered with `with_permission` are
+/// created for a user the first time its tokens are requested; existing
+/// permissions are read back. Tokens are cached and fetched again once they
+/// get within `refresh_margin` of their expiry. Concurrent refreshes of the
+/// tokens of a user are performed once.
+#[derive(Debug, Clone)]
+pub struct ResourceTokenBroker<CUB>
+where
+    CUB: CosmosUriBuilder,
+{
+    client: Client<CUB>,
+    database_name: String,
+    permissions: Vec<(String, PermissionMode<String>)>,
+    expiry_seconds: u64,
+    refresh_margin: Duration,
+    users: Arc<Mutex<HashMap<String, Arc<UserTokens>>>>,
+}
+
+impl<CUB> ResourceTokenBroker<CUB>
+where
+    CUB: CosmosUriBuilder + Clone,
+{
+    pub fn new(client: Client<CUB>, database_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            database_name: database_name.into(),
+            permissions: Vec::new(),
+            expiry_seconds: DEFAULT_EXPIRY_SECONDS,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            users: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_permission(
+        mut self,
+        permission_name: impl Into<String>,
+        permission_mode: PermissionMode<String>,
+    ) -> Self {
+        self.permissions
+            .push((permission_name.into(), permission_mode));
+        self
+    }
+
+    pub fn with_expiry_seconds(self, expiry_seconds: u64) -> Self {
+        Self {
+            expiry_seconds,
+            ..self
+        }
+    }
+
+    pub fn with_refresh_margin(self, refresh_margin: Duration) -> Self {
+        Self {
+            refresh_margin,
+            ..self
+        }
+    }
+
+    fn user_tokens(&self, user_name: &str) -> Arc<UserTokens> {
+        self.users
+            .lock()
+            .unwrap()
+            .entry(user_name.to_owned())
+            .or_default()
+            .clone()
+    }
+
+    /// Returns the cached tokens of the user, refreshing them first if
+    /// they are about to expire.
+    pub async fn resource_tokens(&self, user_name: &str) -> Result<ResourceTokens, AzureError> {
+        let tokens = self.user_tokens(user_name);
+
+        let cached = tokens.tokens.read().unwrap().clone();
+        if !cached.needs_refresh(self.refresh_margin) {
+            return Ok(cached);
+        }
+
+        self.refresh(user_name, &tokens).await
+    }
+
+    /// Returns a provider to be passed to `Client::with_token_provider`.
+    /// The provider shares the cache of the broker so it sees every
+    /// refresh, including the ones performed by `keep_fresh`.
+    pub async fn token_provider(
+        &self,
+        user_name: &str,
+    ) -> Result<ResourceTokenProvider, AzureError> {
+        self.resource_tokens(user_name).await?;
+
+        Ok(ResourceTokenProvider {
+            tokens: self.user_tokens(user_name).tokens.clone(),
+        })
+    }
+
+    /// Refreshes the tokens of every known user that are within
+    /// `refresh_margin` of their expiry.
+    pub async fn refresh_expiring(&self) -> Result<(), AzureError> {
+        let users: Vec<(String, Arc<UserTokens>)> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(user_name, tokens)| (user_name.clone(), tokens.clone()))
+            .collect();
+
+        for (user_name, tokens) in users {
+            if tokens
+                .tokens
+                .read()
+                .unwrap()
+                .needs_refresh(self.refresh_margin)
+            {
+                self.refresh(&user_name, &tokens).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Refreshes the cached tokens ahead of their expiry, forever. Drive it
+    /// alongside the application future (for example with
+    /// `futures::future::select`) so that providers never serve an expired
+    /// token. The future is not `Send`, like the rest of the clients.
+    pub async fn keep_fresh(self) {
+        loop {
+            let next_expiry = self
+                .users
+                .lock()
+                .unwrap()
+                .values()
+                .filter_map(|tokens| tokens.tokens.read().unwrap().expires_at())
+                .min();
+
+            // the margin can reach back before the origin of the
+            // monotonic clock, in which case the refresh is due now. With
+            // no tokens to refresh the wait is capped as well, since the
+            // timer cannot be set past the end of the clock.
+            let wait = match next_expiry {
+                Some(expires_at) => expires_at
+                    .checked_sub(self.refresh_margin)
+                    .map(|refresh_at| refresh_at.saturating_duration_since(Instant::now()))
+                    .unwrap_or_default(),
+                None => std::cmp::min(self.refresh_margin, DEFAULT_REFRESH_MARGIN),
+            };
+            tokio::time::delay_for(wait).await;
+
+            if let Err(error) = self.refresh_expiring().await {
+                warn!("resource token refresh failed: {}", error);
+                tokio::time::delay_for(RETRY_AFTER_FAILURE).await;
+            }
+        }
+    }
+
+    async fn refresh(
+        &self,
+        user_name: &str,
+        tokens: &UserTokens,
+    ) -> Result<ResourceTokens, AzureError> {
+        let _refreshing = tokens.refresh_lock.lock().await;
+        let cached = tokens.tokens.read().unwrap().clone();
+        if !cached.needs_refresh(self.refresh_margin) {
+            // refreshed while we were waiting for the lock
+            return Ok(cached);
+        }
+
+        debug!("refreshing resource tokens of user {}", user_name);
+
+        let requested_at = Instant::now();
+        let database_client = self.client.with_database(&self.database_name);
+        let user_client = database_client.with_user(&user_name);
+
+        let mut granted = match user_client
+            .list_permissions()
+            .with_expiry_seconds(self.expiry_seconds)
+            .execute()
+            .await
+        {
+            Ok(response) => response
+                .permissions
+                .into_iter()
+                .map(|permission| (permission.id.to_string(), permission.into()))
+                .collect(),
+            Err(AzureError::UnexpectedHTTPResult(ref result))
+                if result.status_code() == StatusCode::NOT_FOUND =>
+            {
+                user_client.create_user().execute().await?;
+                Vec::new()
+            }
+            Err(error) => return Err(error),
+        };
+
+        for (permission_name, permission_mode) in self.permissions.iter() {
+            if granted.iter().any(|(id, _)| id == permission_name) {
+                continue;
+            }
+
+            let permission_client = user_client.with_permission(permission_name);
+            let response = permission_client
+                .create_permission()
+                .with_permission_mode(permission_mode)
+                .with_expiry_seconds(self.expiry_seconds)
+                .execute()
+                .await?;
+            granted.push((permission_name.clone(), response.permission.into()));
+        }
+
+        let refreshed = ResourceTokens {
+            tokens: granted.into_iter().map(|(_, token)| token).collect(),
+            expires_at: Some(requested_at + Duration::from_secs(self.expiry_seconds)),
+        };
+
+        *tokens.tokens.write().unwrap() = refreshed.clone();
+        Ok(refreshed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource_token(resource: &str, signature: &str) -> ResourceToken {
+        ResourceToken {
+            resource: resource.to_owned(),
+            permission_token: PermissionToken {
+                permission_type: "resource".to_owned(),
+                version: "1".to_owned(),
+                signature: signature.to_owned(),
+            },
+        }
+    }
+
+    #[test]
+    fn token_for_picks_most_specific_permission() {
+        let tokens = ResourceTokens {
+            tokens: vec![
+                resource_token("dbs/db/colls/coll", "collection"),
+                resource_token("dbs/db/colls/coll/docs/doc", "document"),
+                resource_token("dbs/db/colls/coll2", "other"),
+            ],
+            expires_at: None,
+        };
+
+        assert_eq!(
+            tokens.token_for("dbs/db/colls/coll").unwrap().signature,
+            "collection"
+        );
+        assert_eq!(
+            tokens
+                .token_for("dbs/db/colls/coll/docs/doc")
+                .unwrap()
+                .signature,
+            "document"
+        );
+        assert_eq!(
+            tokens
+                .token_for("dbs/db/colls/coll/docs/another")
+                .unwrap()
+                .signature,
+            "collection"
+        );
+        assert!(tokens.token_for("dbs/db/colls/coll22").is_none());
+        assert!(tokens.token_for("dbs/db").is_none());
+    }
+
+    #[test]
+    fn tokens_need_refresh_within_margin() {
+        let margin = Duration::from_secs(60);
+
+        assert!(ResourceTokens::default().needs_refresh(margin));
+        assert!(ResourceTokens {
+            tokens: vec![],
+            expires_at: Some(Instant::now() + Duration::from_secs(30)),
+        }
+        .needs_refresh(margin));
+        assert!(!ResourceTokens {
+            tokens: vec![],
+            expires_at: Some(Instant::now() + Duration::from_secs(3600)),
+        }
+        .needs_refresh(margin));
+
+        // a margin that overflows the clock
+        assert!(ResourceTokens {
+            tokens: vec![],
+            expires_at: Some(Instant::now() + Duration::from_secs(3600)),
+        }
+        .needs_refresh(Duration::from_secs(u64::MAX)));
+    }
+
+    #[test]
+    fn provider_fails_without_a_valid_token() {
+        let provider = |expires_at| ResourceTokenProvider {
+            tokens: Arc::new(RwLock::new(ResourceTokens {
+                tokens: vec![resource_token("dbs/db/colls/coll", "collection")],
+                expires_at: Some(expires_at),
+            })),
+        };
+
+        let valid = provider(Instant::now() + Duration::from_secs(3600));
+        assert_eq!(
+            valid
+                .authorization_token("dbs/db/colls/coll/docs/doc")
+                .unwrap(),
+            AuthorizationToken::new_resource("collection".to_owned())
+        );
+        assert!(valid.authorization_token("dbs/db/colls/coll2").is_err());
+
+        let expired = provider(Instant::now());
+        assert!(expired.authorization_token("dbs/db/colls/coll").is_err());
+    }
+}
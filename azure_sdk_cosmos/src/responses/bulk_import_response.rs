@@ -0,0 +1,39 @@
+use azure_sdk_core::errors::AzureError;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct BulkImportFailure {
+    // position of the document in the slice passed to the builder
+    pub index: usize,
+    pub document_id: Option<String>,
+    pub error: AzureError,
+}
+
+#[derive(Debug)]
+pub struct BulkImportResponse {
+    pub documents_imported: usize,
+    pub failures: Vec<BulkImportFailure>,
+    pub charge: f64,
+    pub throttled_requests: u64,
+    pub partition_key_ranges: usize,
+    pub elapsed: Duration,
+}
+
+impl BulkImportResponse {
+    pub fn documents_per_second(&self) -> f64 {
+        per_second(self.documents_imported as f64, self.elapsed)
+    }
+
+    pub fn request_units_per_second(&self) -> f64 {
+        per_second(self.charge, self.elapsed)
+    }
+}
+
+fn per_second(value: f64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        value / seconds
+    } else {
+        0.0
+    }
+}
@@ -1,3 +1,4 @@
+use crate::document::ttl_from_body;
 use crate::document_attributes::DocumentAttributes;
 use crate::from_headers::*;
 use crate::ResourceQuota;
@@ -9,6 +10,8 @@ use http::{HeaderMap, StatusCode};
 #[derive(Debug, Clone)]
 pub struct CreateDocumentResponse {
     pub document_attributes: DocumentAttributes,
+    /// The time to live of the document, if it has one.
+    pub ttl: Option<i64>,
     pub is_update: bool,
 
     pub last_state_change: DateTime<Utc>,
@@ -47,6 +50,7 @@ impl std::convert::TryFrom<(StatusCode, &HeaderMap, &[u8])> for CreateDocumentRe
 
         Ok(CreateDocumentResponse {
             document_attributes: DocumentAttributes::try_from((headers, body))?,
+            ttl: ttl_from_body(body)?,
             is_update: status_code == StatusCode::OK,
 
             last_state_change: last_state_change_from_headers(headers)?,
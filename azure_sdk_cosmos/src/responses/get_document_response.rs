@@ -1,3 +1,4 @@
+use crate::document::ttl_from_body;
 use crate::from_headers::*;
 use crate::Document;
 use crate::ResourceQuota;
@@ -46,6 +47,8 @@ where
 #[derive(Debug, Clone)]
 pub struct FoundDocumentResponse<T> {
     pub document: Document<T>,
+    /// The time to live of the document, if it has one.
+    pub ttl: Option<i64>,
     pub content_location: String,
     pub last_state_change: DateTime<Utc>,
     pub etag: String,
@@ -81,6 +84,7 @@ where
 
         Ok(Self {
             document: Document::try_from((headers, body))?,
+            ttl: ttl_from_body(body)?,
 
             content_location: content_location_from_headers(headers)?.to_owned(),
             last_state_change: last_state_change_from_headers(headers)?,
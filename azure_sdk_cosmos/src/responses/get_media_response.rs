@@ -0,0 +1,38 @@
+use crate::from_headers::*;
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use hyper::body::Bytes;
+use hyper::header::HeaderMap;
+use hyper::Body;
+
+/// The media of an attachment. The content is not read upfront: `body`
+/// is a stream of chunks (see `hyper::Body`), or use `bytes` to collect it.
+#[derive(Debug)]
+pub struct GetMediaResponse {
+    pub content_type: String,
+    pub content_length: Option<u64>,
+    pub activity_id: uuid::Uuid,
+    pub date: DateTime<Utc>,
+    pub body: Body,
+}
+
+impl GetMediaResponse {
+    pub(crate) fn try_from(headers: &HeaderMap, body: Body) -> Result<Self, AzureError> {
+        debug!("headers == {:#?}", headers);
+
+        Ok(Self {
+            content_type: content_type_from_headers(headers)?.to_owned(),
+            content_length: match headers.get(http::header::CONTENT_LENGTH) {
+                Some(content_length) => Some(content_length.to_str()?.parse()?),
+                None => None,
+            },
+            activity_id: activity_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            body,
+        })
+    }
+
+    pub async fn bytes(self) -> Result<Bytes, AzureError> {
+        Ok(hyper::body::to_bytes(self.body).await?)
+    }
+}
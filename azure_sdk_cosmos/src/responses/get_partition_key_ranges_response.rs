@@ -1,5 +1,6 @@
 use crate::from_headers::*;
-use crate::PartitionKeyRange;
+use crate::partition_key_hash::{partition_keys_effective_partition_key, PartitionKeyHashVersion};
+use crate::{PartitionKeyRange, PartitionKeys};
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::session_token_from_headers;
 use chrono::{DateTime, Utc};
@@ -29,6 +30,30 @@ pub struct GetPartitionKeyRangesResponse {
     pub partition_key_ranges: Vec<PartitionKeyRange>,
 }
 
+impl GetPartitionKeyRangesResponse {
+    /// Returns the range owning the passed effective partition key.
+    pub fn partition_key_range_for_effective_partition_key(
+        &self,
+        effective_partition_key: &str,
+    ) -> Option<&PartitionKeyRange> {
+        self.partition_key_ranges
+            .iter()
+            .find(|range| range.contains(effective_partition_key))
+    }
+
+    /// Returns the range owning the passed partition keys. The hash version
+    /// must match the collection one (see `PartitionKey::hash_version`).
+    pub fn partition_key_range_for(
+        &self,
+        partition_keys: &PartitionKeys,
+        version: PartitionKeyHashVersion,
+    ) -> Result<Option<&PartitionKeyRange>, AzureError> {
+        let effective_partition_key =
+            partition_keys_effective_partition_key(partition_keys, version)?;
+        Ok(self.partition_key_range_for_effective_partition_key(&effective_partition_key))
+    }
+}
+
 impl std::convert::TryFrom<(&HeaderMap, &[u8])> for GetPartitionKeyRangesResponse {
     type Error = AzureError;
     fn try_from(value: (&HeaderMap, &[u8])) -> Result<Self, Self::Error> {
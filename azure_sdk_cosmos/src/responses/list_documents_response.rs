@@ -1,3 +1,4 @@
+use crate::document::ttl_from_value;
 use crate::from_headers::*;
 use crate::ResourceQuota;
 use crate::{Document, DocumentAttributes};
@@ -8,6 +9,7 @@ use azure_sdk_core::{
 use chrono::{DateTime, Utc};
 use hyper::header::HeaderMap;
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListDocumentsResponseAttributes {
@@ -85,22 +87,26 @@ where
 
         debug!("headers == {:#?}", headers);
 
-        // we will proceed in three steps:
+        // we will proceed in four steps:
         // 1- Deserialize the result as DocumentAttributes. The extra field will be ignored.
         // 2- Deserialize the result a type T. The extra fields will be ignored.
-        // 3- Zip 1 and 2 in the resulting structure.
+        // 3- Deserialize the result as raw values to read the ttl.
+        // 4- Zip 1, 2 and 3 in the resulting structure.
         // There is a lot of data movement here, let's hope the compiler is smarter than me :)
         let document_attributes = ListDocumentsResponseAttributes::try_from(body)?;
         debug!("document_attributes == {:?}", document_attributes);
         let entries = ListDocumentsResponseEntities::try_from(body)?;
+        let values = ListDocumentsResponseEntities::<Value>::try_from(body)?;
 
         let documents = document_attributes
             .documents
             .into_iter()
             .zip(entries.entities.into_iter())
-            .map(|(da, e)| Document {
+            .zip(values.entities.iter())
+            .map(|((da, e), value)| Document {
                 document_attributes: da,
                 document: e,
+                ttl: ttl_from_value(value),
             })
             .collect();
 
@@ -139,6 +145,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryFrom;
 
     const BODY: &'static str = "
 {
@@ -152,7 +159,8 @@ mod tests {
             \"_self\": \"dbs/3iNTAA==/colls/3iNTAJKxVCk=/docs/3iNTAJKxVCkBAAAAAAAAAA==/\",
             \"_etag\": \"\\\"0100eb0a-0000-0c00-0000-5ded4fe30000\\\"\",
             \"_attachments\": \"attachments/\",
-            \"_ts\": 1575833571
+            \"_ts\": 1575833571,
+            \"ttl\": 60
         },
         {
             \"color\": \"yellow\",
@@ -182,5 +190,9 @@ mod tests {
         let _entries =
             serde_json::from_slice::<ListDocumentsResponseEntities<MyStruct>>(BODY.as_bytes())
                 .unwrap();
+
+        let values = ListDocumentsResponseEntities::<Value>::try_from(BODY.as_bytes()).unwrap();
+        let ttls: Vec<Option<i64>> = values.entities.iter().map(ttl_from_value).collect();
+        assert_eq!(ttls, vec![Some(60), None]);
     }
 }
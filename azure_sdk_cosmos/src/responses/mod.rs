@@ -1,3 +1,4 @@
+mod bulk_import_response;
 mod create_collection_response;
 mod create_database_response;
 mod create_document_response;
@@ -10,6 +11,7 @@ mod create_user_defined_function_response;
 mod create_user_response;
 mod delete_attachment_response;
 mod delete_collection_response;
+mod delete_conflict_response;
 mod delete_database_response;
 mod delete_document_response;
 mod delete_permission_response;
@@ -19,13 +21,16 @@ mod delete_user_defined_function_response;
 mod delete_user_response;
 mod execute_stored_procedure_response;
 mod get_attachment_response;
+mod get_media_response;
 mod get_collection_response;
+mod get_conflict_response;
 mod get_database_response;
 mod get_document_response;
 mod get_partition_key_ranges_response;
 mod get_permission_response;
 mod list_attachments_response;
 mod list_collections_response;
+mod list_conflicts_response;
 mod list_databases_response;
 mod list_documents_response;
 mod list_permissions_response;
@@ -33,11 +38,16 @@ mod list_stored_procedures_response;
 mod list_triggers_response;
 mod list_user_defined_functions_response;
 mod list_users_response;
+mod patch_document_response;
 mod query_documents_response;
 mod replace_document_response;
+mod replace_media_response;
 mod replace_permission_response;
 mod replace_reference_attachment_response;
 mod replace_stored_procedure_response;
+mod transactional_batch_response;
+mod update_document_response;
+pub use self::bulk_import_response::{BulkImportFailure, BulkImportResponse};
 pub use self::create_collection_response::CreateCollectionResponse;
 pub use self::create_database_response::CreateDatabaseResponse;
 pub use self::create_document_response::CreateDocumentResponse;
@@ -50,6 +60,7 @@ pub use self::create_user_defined_function_response::CreateUserDefinedFunctionRe
 pub use self::create_user_response::CreateUserResponse;
 pub use self::delete_attachment_response::DeleteAttachmentResponse;
 pub use self::delete_collection_response::DeleteCollectionResponse;
+pub use self::delete_conflict_response::DeleteConflictResponse;
 pub use self::delete_database_response::DeleteDatabaseResponse;
 pub use self::delete_document_response::DeleteDocumentResponse;
 pub use self::delete_permission_response::DeletePermissionResponse;
@@ -59,13 +70,16 @@ pub use self::delete_user_defined_function_response::DeleteUserDefinedFunctionRe
 pub use self::delete_user_response::DeleteUserResponse;
 pub use self::execute_stored_procedure_response::ExecuteStoredProcedureResponse;
 pub use self::get_attachment_response::GetAttachmentResponse;
+pub use self::get_media_response::GetMediaResponse;
 pub use self::get_collection_response::GetCollectionResponse;
+pub use self::get_conflict_response::GetConflictResponse;
 pub use self::get_database_response::GetDatabaseResponse;
 pub use self::get_document_response::GetDocumentResponse;
 pub use self::get_partition_key_ranges_response::GetPartitionKeyRangesResponse;
 pub use self::get_permission_response::GetPermissionResponse;
 pub use self::list_attachments_response::ListAttachmentsResponse;
 pub use self::list_collections_response::ListCollectionsResponse;
+pub use self::list_conflicts_response::ListConflictsResponse;
 pub use self::list_databases_response::ListDatabasesResponse;
 pub use self::list_documents_response::{
     ListDocumentsResponse, ListDocumentsResponseAttributes, ListDocumentsResponseEntities,
@@ -75,11 +89,17 @@ pub use self::list_stored_procedures_response::ListStoredProceduresResponse;
 pub use self::list_triggers_response::ListTriggersResponse;
 pub use self::list_user_defined_functions_response::ListUserDefinedFunctionsResponse;
 pub use self::list_users_response::ListUsersResponse;
+pub use self::patch_document_response::PatchDocumentResponse;
 pub use self::query_documents_response::{
     QueryDocumentsResponse, QueryDocumentsResponseDocuments, QueryDocumentsResponseRaw,
     QueryResponseMeta, QueryResult,
 };
 pub use self::replace_document_response::ReplaceDocumentResponse;
+pub use self::replace_media_response::ReplaceMediaResponse;
 pub use self::replace_permission_response::ReplacePermissionResponse;
 pub use self::replace_reference_attachment_response::ReplaceReferenceAttachmentResponse;
 pub use self::replace_stored_procedure_response::ReplaceStoredProcedureResponse;
+pub use self::transactional_batch_response::{
+    TransactionalBatchOperationResult, TransactionalBatchResponse,
+};
+pub use self::update_document_response::UpdateDocumentResponse;
@@ -1,7 +1,8 @@
+use crate::document::ttl_from_value;
 use crate::document_attributes::DocumentAttributes;
 use crate::errors::ConversionToDocumentError;
 use crate::from_headers::*;
-use crate::ResourceQuota;
+use crate::{IndexMetrics, QueryMetrics, ResourceQuota};
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::{
     continuation_token_from_headers_optional, session_token_from_headers, SessionToken,
@@ -18,6 +19,10 @@ pub struct DocumentQueryResult<T> {
     pub document_attributes: DocumentAttributes,
     #[serde(flatten)]
     pub result: T,
+    // Read from the document but not deserialized along with the
+    // flattened fields, which would hide it from the result type.
+    #[serde(skip)]
+    pub ttl: Option<i64>,
 }
 
 impl<T> std::convert::TryFrom<(&HeaderMap, &[u8])> for DocumentQueryResult<T>
@@ -29,7 +34,12 @@ where
         let _headers = value.0;
         let body = value.1;
 
-        Ok(serde_json::from_slice(body)?)
+        let document: Value = serde_json::from_slice(body)?;
+        let ttl = ttl_from_value(&document);
+        Ok(Self {
+            ttl,
+            ..serde_json::from_value(document)?
+        })
     }
 }
 
@@ -86,6 +96,11 @@ pub struct QueryDocumentsResponse<T> {
     pub gateway_version: String,
     pub date: DateTime<Utc>,
     pub continuation_token: Option<String>,
+    pub query_metrics: Option<QueryMetrics>,
+    /// The query metrics of this page summed with the ones of the
+    /// previous pages, when paging with `QueryDocumentsBuilder::stream`.
+    pub cumulative_query_metrics: Option<QueryMetrics>,
+    pub index_metrics: Option<IndexMetrics>,
 }
 
 impl<T> QueryDocumentsResponse<T> {
@@ -129,6 +144,7 @@ where
                         results.push(QueryResult::Document(DocumentQueryResult {
                             document_attributes,
                             result,
+                            ttl: ttl_from_value(doc),
                         }))
                     }
                     Err(error) => {
@@ -139,6 +155,8 @@ where
             }
         }
 
+        let query_metrics = query_metrics_from_headers_optional(headers)?;
+
         Ok(QueryDocumentsResponse {
             query_response_meta: value.try_into()?,
             results,
@@ -166,6 +184,9 @@ where
             gateway_version: gateway_version_from_headers(headers)?.to_owned(),
             continuation_token: continuation_token_from_headers_optional(headers)?,
             date: date_from_headers(headers)?,
+            cumulative_query_metrics: query_metrics.clone(),
+            query_metrics,
+            index_metrics: index_metrics_from_headers_optional(headers)?,
         })
     }
 }
@@ -199,6 +220,9 @@ pub struct QueryDocumentsResponseRaw<T> {
     pub gateway_version: String,
     pub date: DateTime<Utc>,
     pub continuation_token: Option<String>,
+    pub query_metrics: Option<QueryMetrics>,
+    pub cumulative_query_metrics: Option<QueryMetrics>,
+    pub index_metrics: Option<IndexMetrics>,
 }
 
 impl<T> std::convert::From<QueryDocumentsResponse<T>> for QueryDocumentsResponseRaw<T> {
@@ -238,6 +262,9 @@ impl<T> std::convert::From<QueryDocumentsResponse<T>> for QueryDocumentsResponse
             gateway_version: q.gateway_version,
             continuation_token: q.continuation_token,
             date: q.date,
+            query_metrics: q.query_metrics,
+            cumulative_query_metrics: q.cumulative_query_metrics,
+            index_metrics: q.index_metrics,
         }
     }
 }
@@ -271,6 +298,9 @@ pub struct QueryDocumentsResponseDocuments<T> {
     pub gateway_version: String,
     pub date: DateTime<Utc>,
     pub continuation_token: Option<String>,
+    pub query_metrics: Option<QueryMetrics>,
+    pub cumulative_query_metrics: Option<QueryMetrics>,
+    pub index_metrics: Option<IndexMetrics>,
 }
 
 impl<T> std::convert::TryFrom<QueryDocumentsResponse<T>> for QueryDocumentsResponseDocuments<T> {
@@ -322,6 +352,30 @@ impl<T> std::convert::TryFrom<QueryDocumentsResponse<T>> for QueryDocumentsRespo
             gateway_version: q.gateway_version,
             continuation_token: q.continuation_token,
             date: q.date,
+            query_metrics: q.query_metrics,
+            cumulative_query_metrics: q.cumulative_query_metrics,
+            index_metrics: q.index_metrics,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn document_query_result_ttl() {
+        #[derive(Debug, Clone, PartialEq, Deserialize)]
+        struct Session {
+            id: String,
+            ttl: i64,
+        }
+
+        let body = br#"{"id":"session","ttl":300,"_rid":"rid","_ts":100,"_self":"_self","_etag":"etag","_attachments":"attachments/"}"#;
+        let result =
+            DocumentQueryResult::<Session>::try_from((&HeaderMap::new(), &body[..])).unwrap();
+        assert_eq!(result.ttl, Some(300));
+        assert_eq!(result.result.ttl, 300);
+    }
+}
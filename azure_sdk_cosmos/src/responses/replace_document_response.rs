@@ -1,3 +1,4 @@
+use crate::document::ttl_from_body;
 use crate::document_attributes::DocumentAttributes;
 use crate::from_headers::*;
 use crate::ResourceQuota;
@@ -10,6 +11,8 @@ use std::convert::TryInto;
 #[derive(Debug, Clone)]
 pub struct ReplaceDocumentResponse {
     pub document_attributes: DocumentAttributes,
+    /// The time to live of the document, if it has one.
+    pub ttl: Option<i64>,
 
     pub content_location: String,
     pub last_state_change: DateTime<Utc>,
@@ -47,6 +50,7 @@ impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ReplaceDocumentResponse {
 
         Ok(Self {
             document_attributes: value.try_into()?,
+            ttl: ttl_from_body(body)?,
 
             content_location: content_location_from_headers(headers)?.to_owned(),
             last_state_change: last_state_change_from_headers(headers)?,
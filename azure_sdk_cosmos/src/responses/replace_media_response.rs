@@ -0,0 +1,26 @@
+use crate::from_headers::*;
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use hyper::header::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplaceMediaResponse {
+    pub activity_id: uuid::Uuid,
+    pub date: DateTime<Utc>,
+}
+
+impl std::convert::TryFrom<(&HeaderMap, &[u8])> for ReplaceMediaResponse {
+    type Error = AzureError;
+    fn try_from(value: (&HeaderMap, &[u8])) -> Result<Self, Self::Error> {
+        let headers = value.0;
+        let body = value.1;
+
+        debug!("headers == {:#?}", headers);
+        debug!("body == {:#?}", std::str::from_utf8(body)?);
+
+        Ok(Self {
+            activity_id: activity_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,111 @@
+use crate::from_headers::*;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{session_token_from_headers, SessionToken};
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TransactionalBatchOperationResult {
+    #[serde(rename = "statusCode")]
+    pub status_code: u16,
+    #[serde(rename = "subStatusCode")]
+    pub sub_status_code: Option<u32>,
+    #[serde(rename = "requestCharge")]
+    #[serde(default)]
+    pub request_charge: f64,
+    #[serde(rename = "eTag")]
+    pub etag: Option<String>,
+    #[serde(rename = "resourceBody")]
+    pub resource_body: Option<Value>,
+    #[serde(rename = "retryAfterMilliseconds")]
+    pub retry_after_milliseconds: Option<u64>,
+}
+
+impl TransactionalBatchOperationResult {
+    pub fn is_success(&self) -> bool {
+        self.status_code >= 200 && self.status_code < 300
+    }
+
+    /// Deserializes the resource returned by the operation, if any. Delete
+    /// operations and failed operations do not return a resource.
+    pub fn resource<T>(&self) -> Result<Option<T>, AzureError>
+    where
+        T: DeserializeOwned,
+    {
+        match &self.resource_body {
+            Some(resource_body) => Ok(Some(serde_json::from_value(resource_body.clone())?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TransactionalBatchResponse {
+    pub status_code: StatusCode,
+    pub results: Vec<TransactionalBatchOperationResult>,
+
+    pub session_token: SessionToken,
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub date: DateTime<Utc>,
+}
+
+impl TransactionalBatchResponse {
+    /// Returns true if every operation has been applied. When false, none of
+    /// the operations has been persisted and `results` reports the failing
+    /// operation (the others are marked as failed dependencies).
+    pub fn is_success(&self) -> bool {
+        self.status_code.is_success()
+    }
+}
+
+impl std::convert::TryFrom<(StatusCode, &HeaderMap, &[u8])> for TransactionalBatchResponse {
+    type Error = AzureError;
+    fn try_from(value: (StatusCode, &HeaderMap, &[u8])) -> Result<Self, Self::Error> {
+        let status_code = value.0;
+        let headers = value.1;
+        let body = value.2;
+
+        debug!("status_code == {:?}", status_code);
+        debug!("headers == {:#?}", headers);
+        debug!("body == {:#?}", std::str::from_utf8(body)?);
+
+        Ok(Self {
+            status_code,
+            results: serde_json::from_slice(body)?,
+
+            session_token: session_token_from_headers(headers)?,
+            charge: request_charge_from_headers(headers)?,
+            activity_id: activity_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_transactional_batch_results() {
+        let body = r#"[
+            {"statusCode":201,"requestCharge":6.29,"eTag":"\"00000000-0000-0000-0000-000000000000\"","resourceBody":{"id":"a","hello":42}},
+            {"statusCode":409,"requestCharge":1.24},
+            {"statusCode":424,"requestCharge":0}
+        ]"#;
+
+        let results: Vec<TransactionalBatchOperationResult> = serde_json::from_str(body).unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_success());
+        assert_eq!(
+            results[0].resource::<Value>().unwrap().unwrap()["hello"],
+            42
+        );
+        assert!(!results[1].is_success());
+        assert_eq!(results[1].resource::<Value>().unwrap(), None);
+        assert_eq!(results[2].status_code, 424);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::responses::{CreateDocumentResponse, ReplaceDocumentResponse};
+use crate::{ConsistencyLevel, DocumentAttributes};
+
+/// The outcome of `UpdateDocumentBuilder`: the document is replaced if it
+/// already existed, created otherwise.
+#[derive(Debug, Clone)]
+pub enum UpdateDocumentResponse {
+    Replaced(ReplaceDocumentResponse),
+    Created(CreateDocumentResponse),
+}
+
+impl UpdateDocumentResponse {
+    pub fn document_attributes(&self) -> &DocumentAttributes {
+        match self {
+            UpdateDocumentResponse::Replaced(response) => &response.document_attributes,
+            UpdateDocumentResponse::Created(response) => &response.document_attributes,
+        }
+    }
+
+    pub fn ttl(&self) -> Option<i64> {
+        match self {
+            UpdateDocumentResponse::Replaced(response) => response.ttl,
+            UpdateDocumentResponse::Created(response) => response.ttl,
+        }
+    }
+
+    pub fn charge(&self) -> f64 {
+        match self {
+            UpdateDocumentResponse::Replaced(response) => response.charge,
+            UpdateDocumentResponse::Created(response) => response.charge,
+        }
+    }
+}
+
+impl<'a> From<&'a UpdateDocumentResponse> for ConsistencyLevel<'a> {
+    fn from(response: &'a UpdateDocumentResponse) -> Self {
+        match response {
+            UpdateDocumentResponse::Replaced(response) => response.into(),
+            UpdateDocumentResponse::Created(response) => response.into(),
+        }
+    }
+}
+
+impl<'a> From<UpdateDocumentResponse> for ConsistencyLevel<'a> {
+    fn from(response: UpdateDocumentResponse) -> Self {
+        match response {
+            UpdateDocumentResponse::Replaced(response) => response.into(),
+            UpdateDocumentResponse::Created(response) => response.into(),
+        }
+    }
+}
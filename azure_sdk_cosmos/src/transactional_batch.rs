@@ -0,0 +1,187 @@
+use azure_sdk_core::errors::AzureError;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Cosmos refuses batches with more than this number of operations.
+pub const TRANSACTIONAL_BATCH_MAX_OPERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BatchOperationType {
+    Create,
+    Upsert,
+    Replace,
+    Delete,
+    Read,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BatchOperation {
+    #[serde(rename = "operationType")]
+    operation_type: BatchOperationType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(rename = "resourceBody")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_body: Option<Value>,
+    #[serde(rename = "ifMatch")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    if_match: Option<String>,
+}
+
+impl BatchOperation {
+    fn new(
+        operation_type: BatchOperationType,
+        id: Option<String>,
+        resource_body: Option<Value>,
+    ) -> Self {
+        Self {
+            operation_type,
+            id,
+            resource_body,
+            if_match: None,
+        }
+    }
+
+    pub fn create<T: Serialize>(document: &T) -> Result<Self, AzureError> {
+        Ok(Self::new(
+            BatchOperationType::Create,
+            None,
+            Some(serde_json::to_value(document)?),
+        ))
+    }
+
+    pub fn upsert<T: Serialize>(document: &T) -> Result<Self, AzureError> {
+        Ok(Self::new(
+            BatchOperationType::Upsert,
+            None,
+            Some(serde_json::to_value(document)?),
+        ))
+    }
+
+    pub fn replace<T: Serialize>(document_id: &str, document: &T) -> Result<Self, AzureError> {
+        Ok(Self::new(
+            BatchOperationType::Replace,
+            Some(document_id.to_owned()),
+            Some(serde_json::to_value(document)?),
+        ))
+    }
+
+    pub fn delete(document_id: &str) -> Self {
+        Self::new(
+            BatchOperationType::Delete,
+            Some(document_id.to_owned()),
+            None,
+        )
+    }
+
+    pub fn read(document_id: &str) -> Self {
+        Self::new(BatchOperationType::Read, Some(document_id.to_owned()), None)
+    }
+
+    /// Makes the operation conditional on the document etag. If it does
+    /// not match the whole batch is rolled back.
+    pub fn with_if_match<E: Into<String>>(self, etag: E) -> Self {
+        Self {
+            if_match: Some(etag.into()),
+            ..self
+        }
+    }
+
+    pub fn operation_type(&self) -> BatchOperationType {
+        self.operation_type
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+/// The operations of a transactional batch. All the operations must target
+/// documents sharing the same partition key: they are executed in order and
+/// either all succeed or none is applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransactionalBatch {
+    operations: Vec<BatchOperation>,
+}
+
+impl TransactionalBatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, operation: BatchOperation) -> &mut Self {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn create<T: Serialize>(&mut self, document: &T) -> Result<&mut Self, AzureError> {
+        Ok(self.push(BatchOperation::create(document)?))
+    }
+
+    pub fn upsert<T: Serialize>(&mut self, document: &T) -> Result<&mut Self, AzureError> {
+        Ok(self.push(BatchOperation::upsert(document)?))
+    }
+
+    pub fn replace<T: Serialize>(
+        &mut self,
+        document_id: &str,
+        document: &T,
+    ) -> Result<&mut Self, AzureError> {
+        Ok(self.push(BatchOperation::replace(document_id, document)?))
+    }
+
+    pub fn delete(&mut self, document_id: &str) -> &mut Self {
+        self.push(BatchOperation::delete(document_id))
+    }
+
+    pub fn read(&mut self, document_id: &str) -> &mut Self {
+        self.push(BatchOperation::read(document_id))
+    }
+
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct MyDocument {
+        id: String,
+        hello: u32,
+    }
+
+    #[test]
+    fn serialize_transactional_batch() {
+        let doc = MyDocument {
+            id: "a".to_owned(),
+            hello: 42,
+        };
+
+        let mut batch = TransactionalBatch::new();
+        batch
+            .create(&doc)
+            .unwrap()
+            .replace("a", &doc)
+            .unwrap()
+            .delete("b")
+            .read("c")
+            .push(BatchOperation::upsert(&doc).unwrap().with_if_match("etag"));
+
+        assert_eq!(batch.len(), 5);
+        assert_eq!(
+            serde_json::to_string(batch.operations()).unwrap(),
+            r#"[{"operationType":"Create","resourceBody":{"hello":42,"id":"a"}},{"operationType":"Replace","id":"a","resourceBody":{"hello":42,"id":"a"}},{"operationType":"Delete","id":"b"},{"operationType":"Read","id":"c"},{"operationType":"Upsert","resourceBody":{"hello":42,"id":"a"},"ifMatch":"etag"}]"#
+        );
+    }
+}
@@ -41,23 +41,7 @@ async fn attachment() -> Result<(), Box<dyn Error>> {
 
     // create a temp collection
     let _create_collection_response = {
-        let indexes = IncludedPathIndex {
-            kind: KeyKind::Hash,
-            data_type: DataType::String,
-            precision: Some(3),
-        };
-
-        let ip = IncludedPath {
-            path: "/*".to_owned(),
-            indexes: Some(vec![indexes]),
-        };
-
-        let ip = IndexingPolicy {
-            automatic: true,
-            indexing_mode: IndexingMode::Consistent,
-            included_paths: vec![ip],
-            excluded_paths: vec![],
-        };
+        let ip = IndexingPolicy::default().with_included_path("/*");
 
         database_client
             .create_collection()
@@ -163,6 +147,33 @@ async fn attachment() -> Result<(), Box<dyn Error>> {
         .unwrap();
     assert_eq!("text/plain", slug_attachment.attachment.content_type);
 
+    // read the slug media back
+    let media = attachment_client.get_media().execute().await?;
+    assert_eq!("text/plain", media.content_type);
+    assert_eq!(&b"something cool here"[..], &media.bytes().await?[..]);
+
+    // replace the slug media, passing the known media link
+    attachment_client
+        .replace_media()
+        .with_media(&slug_attachment.attachment.media)
+        .with_content_type("text/plain")
+        .execute(&b"something even cooler"[..])
+        .await?;
+    let media = attachment_client
+        .get_media()
+        .with_media(&slug_attachment.attachment.media)
+        .execute()
+        .await?;
+    assert_eq!(&b"something even cooler"[..], &media.bytes().await?[..]);
+
+    // reference attachments have no media stored in Cosmos DB
+    assert!(document_client
+        .with_attachment(&"reference")
+        .get_media()
+        .execute()
+        .await
+        .is_err());
+
     // delete slug attachment
     let resp_delete = attachment_client
         .delete()
@@ -26,6 +26,8 @@ async fn create_and_delete_collection() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
     let collection = database_client
         .create_collection()
@@ -46,12 +48,23 @@ async fn create_and_delete_collection() {
     assert!(collection.collection.rid == collection_after_get.collection.rid);
 
     // check GetPartitionKeyRanges: https://docs.microsoft.com/en-us/rest/api/cosmos-db/get-partition-key-ranges
-    collection_client
+    let partition_key_ranges = collection_client
         .get_partition_key_ranges()
         .execute()
         .await
         .unwrap();
 
+    // every partition key must belong to exactly one range
+    let mut partition_keys = PartitionKeys::new();
+    partition_keys.push("any value").unwrap();
+    assert!(partition_key_ranges
+        .partition_key_range_for(
+            &partition_keys,
+            collection_after_get.collection.parition_key.hash_version()
+        )
+        .unwrap()
+        .is_some());
+
     // delete the collection
     collection_client
         .delete_collection()
@@ -85,6 +98,8 @@ async fn replace_collection() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
     let collection = database_client
         .create_collection()
@@ -106,23 +121,7 @@ async fn replace_collection() {
     );
 
     // Let's change the indexing mode!
-    let indexes = IncludedPathIndex {
-        kind: KeyKind::Hash,
-        data_type: DataType::String,
-        precision: Some(3),
-    };
-
-    let ip = IncludedPath {
-        path: "/*".to_owned(),
-        indexes: Some(vec![indexes]),
-    };
-
-    let mut new_ip = IndexingPolicy {
-        automatic: true,
-        indexing_mode: IndexingMode::Consistent,
-        included_paths: vec![ip],
-        excluded_paths: vec![],
-    };
+    let mut new_ip = IndexingPolicy::default().with_included_path("/*");
 
     new_ip
         .excluded_paths
@@ -148,3 +147,132 @@ async fn replace_collection() {
 
     database_client.delete_database().execute().await.unwrap();
 }
+
+#[tokio::test]
+async fn collection_policies() {
+    const DATABASE_NAME: &str = "test-cosmos-db-collection-policies";
+    const COLLECTION_NAME: &str = "test-collection-policies";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec!["/*".to_owned().into()],
+        excluded_paths: vec![],
+        composite_indexes: vec![vec![
+            CompositeIndexPath::new("/name", CompositeIndexOrder::Ascending),
+            CompositeIndexPath::new("/age", CompositeIndexOrder::Descending),
+        ]],
+        spatial_indexes: vec![SpatialIndex {
+            path: "/location/*".to_owned(),
+            types: vec![SpatialType::Point],
+        }],
+    };
+    let unique_key_policy = UniqueKeyPolicy {
+        unique_keys: vec!["/email".into()],
+    };
+
+    let collection = database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&PartitionKey::new_v2("/tenant"))
+        .with_indexing_policy(&indexing_policy)
+        .with_default_ttl(3600)
+        .with_unique_key_policy(&unique_key_policy)
+        .execute()
+        .await
+        .unwrap()
+        .collection;
+
+    assert_eq!(collection.default_ttl, Some(3600));
+    assert_eq!(collection.parition_key.version, Some(2));
+    assert_eq!(
+        collection.unique_key_policy,
+        Some(unique_key_policy.clone())
+    );
+    assert_eq!(
+        collection.indexing_policy.composite_indexes,
+        indexing_policy.composite_indexes
+    );
+
+    // disable the expiration by default but let the documents override it
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+    let collection = collection_client
+        .replace_collection()
+        .with_indexing_policy(&indexing_policy)
+        .with_partition_key(&PartitionKey::new_v2("/tenant"))
+        .with_unique_key_policy(&unique_key_policy)
+        .with_default_ttl(-1)
+        .execute()
+        .await
+        .unwrap()
+        .collection;
+    assert_eq!(collection.default_ttl, Some(-1));
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn list_conflicts() {
+    const DATABASE_NAME: &str = "test-cosmos-db-list-conflicts";
+    const COLLECTION_NAME: &str = "test-collection-list-conflicts";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/id".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    // a single region account never records conflicts
+    let conflicts = collection_client.list_conflicts().execute().await.unwrap();
+    assert!(conflicts.conflicts.is_empty());
+    assert!(conflicts.continuation_token.is_none());
+
+    let mut partition_keys = PartitionKeys::new();
+    partition_keys.push("missing").unwrap();
+    assert!(collection_client
+        .get_conflict()
+        .with_conflict_id("missing")
+        .with_partition_keys(&partition_keys)
+        .execute()
+        .await
+        .is_err());
+
+    database_client.delete_database().execute().await.unwrap();
+}
@@ -4,9 +4,12 @@ extern crate serde_derive;
 use azure_sdk_core::prelude::*;
 use azure_sdk_cosmos::collection::*;
 use azure_sdk_cosmos::prelude::*;
-use azure_sdk_cosmos::responses::GetDocumentResponse;
+use azure_sdk_cosmos::responses::{GetDocumentResponse, UpdateDocumentResponse};
+use azure_sdk_cosmos::DiagnosticsContext;
 use azure_sdk_cosmos::Offer;
 use azure_sdk_cosmos::Query;
+use azure_sdk_cosmos::TransactionalBatch;
+use futures::stream::StreamExt;
 mod setup;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -38,6 +41,8 @@ async fn create_and_delete_document() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
 
     database_client
@@ -125,6 +130,8 @@ async fn query_documents() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
 
     database_client
@@ -176,6 +183,90 @@ async fn query_documents() {
     assert!(query_result[0].document_attributes.rid() == documents[0].document_attributes.rid());
     assert_eq!(query_result[0].result, document_data.document);
 
+    // the same using the query builder
+    let sql_query = QueryBuilder::from("c")
+        .filter(
+            Field::new("c.hello")
+                .eq(42)
+                .and(Field::new("c.id").starts_with("test-")),
+        )
+        .order_by("c.id", SortOrder::Ascending)
+        .build()
+        .unwrap();
+    let query_result = collection_client
+        .query_documents()
+        .with_query(&sql_query.query())
+        .with_query_cross_partition(true)
+        .execute::<MyDocument>()
+        .await
+        .unwrap()
+        .into_documents()
+        .unwrap()
+        .results;
+
+    assert!(query_result.len() == 1);
+    assert_eq!(query_result[0].result, document_data.document);
+
+    // ask for the query and index metrics
+    let response = collection_client
+        .query_documents()
+        .with_query(&Query::new("SELECT * FROM c WHERE c.hello = 42"))
+        .with_query_cross_partition(true)
+        .with_populate_query_metrics(true)
+        .with_populate_index_metrics(true)
+        .execute::<MyDocument>()
+        .await
+        .unwrap();
+
+    let query_metrics = response.query_metrics.unwrap();
+    assert_eq!(query_metrics.output_document_count, 1);
+    assert_eq!(response.cumulative_query_metrics, Some(query_metrics));
+    assert!(response.index_metrics.is_some());
+
+    // page through the results recording the request diagnostics
+    let diagnostics_context = DiagnosticsContext::new();
+    let diagnosed_client = client.with_diagnostics_context(diagnostics_context.clone());
+    let diagnosed_database_client = diagnosed_client.with_database(&DATABASE_NAME);
+    let diagnosed_collection_client = diagnosed_database_client.with_collection(&COLLECTION_NAME);
+
+    let query = Query::new("SELECT * FROM c");
+    let query_builder = diagnosed_collection_client
+        .query_documents()
+        .with_query(&query)
+        .with_query_cross_partition(true)
+        .with_max_item_count(1);
+    let mut stream = Box::pin(query_builder.stream::<MyDocument>());
+    let mut request_charge = 0.0;
+    while let Some(response) = stream.next().await {
+        request_charge += response.unwrap().charge;
+    }
+
+    assert!(diagnostics_context.round_trips() >= 1);
+    assert_eq!(
+        diagnostics_context.activity_ids().len(),
+        diagnostics_context.round_trips()
+    );
+    assert!((diagnostics_context.total_request_charge() - request_charge).abs() < 0.001);
+
+    // the same, with the context attached to the builder only
+    let builder_diagnostics_context = DiagnosticsContext::new();
+    let query_builder = collection_client
+        .query_documents()
+        .with_query(&query)
+        .with_query_cross_partition(true)
+        .with_max_item_count(1)
+        .with_diagnostics_context(&builder_diagnostics_context);
+    let mut stream = Box::pin(query_builder.stream::<MyDocument>());
+    while let Some(response) = stream.next().await {
+        response.unwrap();
+    }
+
+    assert_eq!(
+        builder_diagnostics_context.round_trips(),
+        diagnostics_context.round_trips()
+    );
+    assert_eq!(builder_diagnostics_context.retries(), 0);
+
     database_client.delete_database().execute().await.unwrap();
 }
 
@@ -201,6 +292,8 @@ async fn replace_document() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
 
     database_client
@@ -267,3 +360,585 @@ async fn replace_document() {
 
     database_client.delete_database().execute().await.unwrap();
 }
+
+#[tokio::test]
+async fn patch_document() {
+    const DATABASE_NAME: &str = "test-cosmos-db-patch-documents";
+    const COLLECTION_NAME: &str = "test-collection-patch-documents";
+    const DOCUMENT_NAME: &str = "test-document-name-patch-documents";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    // create a new collection
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/id".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    // create a new document
+    let document_data = Document::new(MyDocument {
+        id: DOCUMENT_NAME.to_owned(),
+        hello: 42,
+    });
+    collection_client
+        .create_document()
+        .with_document(&document_data)
+        .with_partition_keys(&(&document_data.document.id).into())
+        .execute()
+        .await
+        .unwrap();
+
+    let partition_keys = DOCUMENT_NAME.into();
+    let document_client = collection_client.with_document(&DOCUMENT_NAME, &partition_keys);
+
+    // increment the counter only if it still has the value we expect
+    let patched = document_client
+        .patch_document()
+        .with_patch_operations(&[PatchOperation::increment("/hello", 8)])
+        .with_patch_condition("from c where c.hello = 42")
+        .execute::<MyDocument>()
+        .await
+        .unwrap();
+    assert_eq!(patched.document.document.hello, 50);
+
+    // the condition does not match anymore so the patch must fail
+    assert!(document_client
+        .patch_document()
+        .with_patch_operations(&[PatchOperation::set("/hello", 0)])
+        .with_patch_condition("from c where c.hello = 42")
+        .execute::<MyDocument>()
+        .await
+        .is_err());
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn transactional_batch() {
+    const DATABASE_NAME: &str = "test-cosmos-db-transactional-batch";
+    const COLLECTION_NAME: &str = "test-collection-transactional-batch";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    // create a new collection
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/hello".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    let mut partition_keys = PartitionKeys::new();
+    partition_keys.push(42).unwrap();
+
+    let mut batch = TransactionalBatch::new();
+    batch
+        .create(&MyDocument {
+            id: "first".to_owned(),
+            hello: 42,
+        })
+        .unwrap()
+        .create(&MyDocument {
+            id: "second".to_owned(),
+            hello: 42,
+        })
+        .unwrap()
+        .read("first");
+
+    let response = collection_client
+        .transactional_batch()
+        .with_transactional_batch(&batch)
+        .with_partition_keys(&partition_keys)
+        .execute()
+        .await
+        .unwrap();
+    assert!(response.is_success());
+    assert_eq!(response.results.len(), 3);
+    assert_eq!(
+        response.results[2]
+            .resource::<MyDocument>()
+            .unwrap()
+            .unwrap()
+            .id,
+        "first"
+    );
+
+    // the second create conflicts so the delete must be rolled back
+    let mut batch = TransactionalBatch::new();
+    batch
+        .delete("first")
+        .create(&MyDocument {
+            id: "second".to_owned(),
+            hello: 42,
+        })
+        .unwrap();
+
+    let response = collection_client
+        .transactional_batch()
+        .with_transactional_batch(&batch)
+        .with_partition_keys(&partition_keys)
+        .execute()
+        .await
+        .unwrap();
+    assert!(!response.is_success());
+    assert_eq!(response.results[1].status_code, 409);
+
+    let documents = collection_client
+        .list_documents()
+        .execute::<MyDocument>()
+        .await
+        .unwrap()
+        .documents;
+    assert!(documents.len() == 2);
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn bulk_import() {
+    const DATABASE_NAME: &str = "test-cosmos-db-bulk-import";
+    const COLLECTION_NAME: &str = "test-collection-bulk-import";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    // create a new collection
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/hello".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    let documents: Vec<MyDocument> = (0..100)
+        .map(|i| MyDocument {
+            id: format!("document-{}", i),
+            hello: i,
+        })
+        .collect();
+
+    let response = collection_client
+        .bulk_import()
+        .with_documents(&documents)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.documents_imported, 100);
+    assert!(response.failures.is_empty());
+    assert!(response.charge > 0.0);
+
+    // importing again without upsert must fail with conflicts
+    let response = collection_client
+        .bulk_import()
+        .with_documents(&documents[..10])
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.documents_imported, 0);
+    assert_eq!(response.failures.len(), 10);
+    assert_eq!(response.failures[0].index, 0);
+
+    let response = collection_client
+        .bulk_import()
+        .with_documents(&documents[..10])
+        .with_is_upsert(true)
+        .with_max_concurrency_per_range(2)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.documents_imported, 10);
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn document_ttl() {
+    const DATABASE_NAME: &str = "test-cosmos-db-document-ttl";
+    const COLLECTION_NAME: &str = "test-collection-document-ttl";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    // expiration enabled but off by default
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/id".into()))
+        .with_indexing_policy(&indexing_policy)
+        .with_default_ttl(-1)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    // the document type carries its own ttl
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    struct Session {
+        id: String,
+        ttl: i64,
+    }
+
+    let document_data = Document::new(Session {
+        id: "session".to_owned(),
+        ttl: 300,
+    });
+    let response = collection_client
+        .create_document()
+        .with_partition_keys(&(&document_data.document.id).into())
+        .with_document(&document_data)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.ttl, Some(300));
+
+    let partition_keys = (&document_data.document.id).into();
+    let document_client =
+        collection_client.with_document(&document_data.document.id, &partition_keys);
+    match document_client
+        .get_document()
+        .execute::<Session>()
+        .await
+        .unwrap()
+    {
+        GetDocumentResponse::Found(response) => {
+            assert_eq!(response.document.document.ttl, 300);
+            assert_eq!(response.document.ttl, Some(300));
+            assert_eq!(response.ttl, Some(300));
+        }
+        GetDocumentResponse::NotFound(_) => panic!("document not found"),
+    }
+
+    // the builder ttl overrides the document one
+    let response = collection_client
+        .replace_document()
+        .with_document_id(&document_data.document.id)
+        .with_partition_keys(&(&document_data.document.id).into())
+        .with_document(&document_data)
+        .with_ttl(60)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.ttl, Some(60));
+
+    let document_data = Document::new(MyDocument {
+        id: "other".to_owned(),
+        hello: 42,
+    });
+    let response = collection_client
+        .create_document()
+        .with_partition_keys(&(&document_data.document.id).into())
+        .with_document(&document_data)
+        .with_is_upsert(true)
+        .with_ttl(-1)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(response.ttl, Some(-1));
+
+    // updates keep the ttl of the document
+    let partition_keys = (&document_data.document.id).into();
+    let response = collection_client
+        .with_document(&document_data.document.id, &partition_keys)
+        .update_document()
+        .execute(|document: &mut MyDocument| document.hello += 1)
+        .await
+        .unwrap();
+    assert_eq!(response.ttl(), Some(-1));
+
+    // the ttl is read back by list and query too
+    let documents = collection_client
+        .list_documents()
+        .execute::<serde_json::Value>()
+        .await
+        .unwrap()
+        .documents;
+    let ttls: Vec<Option<i64>> = documents.iter().map(|document| document.ttl).collect();
+    assert_eq!(ttls.len(), 2);
+    assert!(ttls.contains(&Some(60)));
+    assert!(ttls.contains(&Some(-1)));
+
+    let query_result = collection_client
+        .query_documents()
+        .with_query(&Query::new("SELECT * FROM c WHERE c.id = 'other'"))
+        .with_query_cross_partition(true)
+        .execute::<MyDocument>()
+        .await
+        .unwrap()
+        .into_documents()
+        .unwrap()
+        .results;
+    assert_eq!(query_result.len(), 1);
+    assert_eq!(query_result[0].ttl, Some(-1));
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct MyEntity {
+    id: String,
+    tenant: String,
+    hello: u32,
+}
+
+impl CosmosEntity for MyEntity {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn partition_keys(&self) -> Result<PartitionKeys, azure_sdk_core::errors::AzureError> {
+        let mut partition_keys = PartitionKeys::new();
+        partition_keys.push(&self.tenant)?;
+        Ok(partition_keys)
+    }
+}
+
+#[tokio::test]
+async fn cosmos_entity() {
+    const DATABASE_NAME: &str = "test-cosmos-db-cosmos-entity";
+    const COLLECTION_NAME: &str = "test-collection-cosmos-entity";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::Throughput(400))
+        .with_partition_key(&("/tenant".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+
+    // neither the partition keys nor the document id are passed explicitly
+    let mut entity = MyEntity {
+        id: "entity".to_owned(),
+        tenant: "contoso".to_owned(),
+        hello: 1,
+    };
+    collection_client
+        .create_document()
+        .with_document(&Document::new(entity.clone()))
+        .execute()
+        .await
+        .unwrap();
+
+    entity.hello = 2;
+    collection_client
+        .replace_document()
+        .with_document(&Document::new(entity.clone()))
+        .execute()
+        .await
+        .unwrap();
+
+    let entity_client = collection_client.with_entity(&entity).unwrap();
+    match entity_client
+        .get_document()
+        .execute::<MyEntity>()
+        .await
+        .unwrap()
+    {
+        GetDocumentResponse::Found(document) => {
+            assert_eq!(document.document.document, entity)
+        }
+        GetDocumentResponse::NotFound(_) => panic!("entity not found"),
+    }
+
+    entity_client.delete_document().execute().await.unwrap();
+
+    database_client.delete_database().execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_document() {
+    const DATABASE_NAME: &str = "test-cosmos-db-update-document";
+    const COLLECTION_NAME: &str = "test-collection-update-document";
+    const DOCUMENT_NAME: &str = "test-document-name-update-document";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::S2)
+        .with_partition_key(&("/id".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    let collection_client = database_client.with_collection(&COLLECTION_NAME);
+    let partition_keys = DOCUMENT_NAME.into();
+    let document_client = collection_client.with_document(&DOCUMENT_NAME, &partition_keys);
+
+    // updating a missing document fails...
+    assert!(document_client
+        .update_document()
+        .execute(|document: &mut MyDocument| document.hello += 1)
+        .await
+        .is_err());
+
+    // ...unless we ask to create it
+    let response = document_client
+        .update_document()
+        .execute_or_create(|document: Option<MyDocument>| match document {
+            Some(mut document) => {
+                document.hello += 1;
+                document
+            }
+            None => MyDocument {
+                id: DOCUMENT_NAME.to_owned(),
+                hello: 1,
+            },
+        })
+        .await
+        .unwrap();
+    assert!(matches!(response, UpdateDocumentResponse::Created(_)));
+
+    // concurrent updates are retried until they all get through
+    let update_builder = document_client
+        .update_document()
+        .with_max_attempts(10)
+        .with_retry_backoff(std::time::Duration::from_millis(10));
+    let updates =
+        (0..3).map(|_| update_builder.execute(|document: &mut MyDocument| document.hello += 1));
+    for response in futures::future::join_all(updates).await {
+        assert!(matches!(
+            response.unwrap(),
+            UpdateDocumentResponse::Replaced(_)
+        ));
+    }
+
+    match document_client
+        .get_document()
+        .execute::<MyDocument>()
+        .await
+        .unwrap()
+    {
+        GetDocumentResponse::Found(document) => assert_eq!(document.document.document.hello, 4),
+        GetDocumentResponse::NotFound(_) => panic!("document not found"),
+    }
+
+    database_client.delete_database().execute().await.unwrap();
+}
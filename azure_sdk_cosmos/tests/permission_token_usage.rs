@@ -1,6 +1,6 @@
 #![cfg(all(test, feature = "test_e2e"))]
 use azure_sdk_cosmos::prelude::*;
-use azure_sdk_cosmos::PermissionMode;
+use azure_sdk_cosmos::{PermissionMode, ResourceTokenBroker};
 
 mod setup;
 
@@ -29,6 +29,8 @@ async fn permissions() {
         indexing_mode: IndexingMode::Consistent,
         included_paths: vec![],
         excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
     };
 
     let create_collection_response = database_client
@@ -138,3 +140,78 @@ async fn permissions() {
     // cleanup
     database_client.delete_database().execute().await.unwrap();
 }
+
+#[tokio::test]
+async fn resource_token_broker() {
+    const DATABASE_NAME: &str = "cosmos-test-db-token-broker";
+    const COLLECTION_NAME: &str = "cosmos-test-db-token-broker";
+    const USER_NAME: &str = "someone@cool.net";
+    const PERMISSION: &str = "sdktest";
+
+    let client = setup::initialize().unwrap();
+
+    client
+        .create_database()
+        .with_database_name(&DATABASE_NAME)
+        .execute()
+        .await
+        .unwrap();
+
+    let database_client = client.with_database(&DATABASE_NAME);
+
+    let indexing_policy = IndexingPolicy {
+        automatic: true,
+        indexing_mode: IndexingMode::Consistent,
+        included_paths: vec![],
+        excluded_paths: vec![],
+        composite_indexes: vec![],
+        spatial_indexes: vec![],
+    };
+
+    database_client
+        .create_collection()
+        .with_collection_name(&COLLECTION_NAME)
+        .with_offer(Offer::Throughput(400))
+        .with_partition_key(&("/id".into()))
+        .with_indexing_policy(&indexing_policy)
+        .execute()
+        .await
+        .unwrap();
+
+    // the broker creates both the user and its permission on first use
+    let broker = ResourceTokenBroker::new(client.clone(), DATABASE_NAME)
+        .with_permission(
+            PERMISSION,
+            PermissionMode::Read(format!("dbs/{}/colls/{}", DATABASE_NAME, COLLECTION_NAME)),
+        )
+        .with_expiry_seconds(3600);
+
+    let tokens = broker.resource_tokens(USER_NAME).await.unwrap();
+    assert_eq!(tokens.tokens().len(), 1);
+
+    // tokens are served from the cache until they get close to expiry
+    let cached_tokens = broker.resource_tokens(USER_NAME).await.unwrap();
+    assert_eq!(tokens, cached_tokens);
+
+    let token_provider = broker.token_provider(USER_NAME).await.unwrap();
+    let user_client = client.with_token_provider(std::sync::Arc::new(token_provider));
+    let user_database_client = user_client.with_database(&DATABASE_NAME);
+    let user_collection_client = user_database_client.with_collection(&COLLECTION_NAME);
+
+    // the refresh loop never completes so the request always wins
+    let list_documents = user_collection_client.list_documents();
+    let list_documents = list_documents.execute::<serde_json::Value>();
+    match futures::future::select(
+        Box::pin(list_documents),
+        Box::pin(broker.clone().keep_fresh()),
+    )
+    .await
+    {
+        futures::future::Either::Left((response, _)) => {
+            response.unwrap();
+        }
+        futures::future::Either::Right(_) => unreachable!(),
+    }
+
+    database_client.delete_database().execute().await.unwrap();
+}
@@ -56,23 +56,7 @@ async fn trigger() -> Result<(), Box<dyn Error>> {
 
     // create a temp collection
     let _create_collection_response = {
-        let indexes = IncludedPathIndex {
-            kind: KeyKind::Hash,
-            data_type: DataType::String,
-            precision: Some(3),
-        };
-
-        let ip = IncludedPath {
-            path: "/*".to_owned(),
-            indexes: Some(vec![indexes]),
-        };
-
-        let ip = IndexingPolicy {
-            automatic: true,
-            indexing_mode: IndexingMode::Consistent,
-            included_paths: vec![ip],
-            excluded_paths: vec![],
-        };
+        let ip = IndexingPolicy::default().with_included_path("/*");
 
         database_client
             .create_collection()
@@ -0,0 +1,134 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Meta, NestedMeta};
+
+const ATTRIBUTE: &str = "cosmos";
+const ID: &str = "id";
+const PARTITION_KEY: &str = "partition_key";
+
+/// Implements `azure_sdk_cosmos::CosmosEntity` for a struct. The field
+/// holding the document id is marked with `#[cosmos(id)]` and the partition
+/// key field(s) with `#[cosmos(partition_key)]`, in the order of the
+/// collection partition key paths.
+#[proc_macro_derive(CosmosEntity, attributes(cosmos))]
+pub fn derive_cosmos_entity(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match cosmos_entity(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn cosmos_entity(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "CosmosEntity can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "CosmosEntity can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut id: Option<&Ident> = None;
+    let mut partition_keys: Vec<&Ident> = Vec::new();
+
+    for field in fields.iter() {
+        for marker in markers(&field.attrs)? {
+            // named fields always have an ident
+            let ident = field.ident.as_ref().unwrap();
+
+            if marker == ID {
+                if id.is_some() {
+                    return Err(Error::new(
+                        marker.span(),
+                        "only one field can be marked with #[cosmos(id)]",
+                    ));
+                }
+                id = Some(ident);
+            } else if marker == PARTITION_KEY {
+                partition_keys.push(ident);
+            } else {
+                return Err(Error::new(
+                    marker.span(),
+                    "expected #[cosmos(id)] or #[cosmos(partition_key)]",
+                ));
+            }
+        }
+    }
+
+    let id = id.ok_or_else(|| {
+        Error::new(
+            input.span(),
+            "CosmosEntity requires a field marked with #[cosmos(id)]",
+        )
+    })?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::azure_sdk_cosmos::CosmosEntity for #name #ty_generics #where_clause {
+            fn id(&self) -> &str {
+                ::std::convert::AsRef::<str>::as_ref(&self.#id)
+            }
+
+            fn partition_keys(
+                &self,
+            ) -> ::std::result::Result<
+                ::azure_sdk_cosmos::PartitionKeys,
+                ::azure_sdk_cosmos::AzureError,
+            > {
+                let mut partition_keys = ::azure_sdk_cosmos::PartitionKeys::new();
+                #(partition_keys.push(&self.#partition_keys)?;)*
+                Ok(partition_keys)
+            }
+        }
+    })
+}
+
+// Collects the idents listed in the #[cosmos(...)] attributes of a field.
+fn markers(attrs: &[syn::Attribute]) -> Result<Vec<Ident>, Error> {
+    let mut markers = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(ATTRIBUTE)) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path)) if path.get_ident().is_some() => {
+                            markers.push(path.get_ident().unwrap().clone())
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                nested.span(),
+                                "expected #[cosmos(id)] or #[cosmos(partition_key)]",
+                            ))
+                        }
+                    }
+                }
+            }
+            meta => {
+                return Err(Error::new(
+                    meta.span(),
+                    "expected #[cosmos(id)] or #[cosmos(partition_key)]",
+                ))
+            }
+        }
+    }
+
+    Ok(markers)
+}
@@ -0,0 +1,58 @@
+use azure_sdk_cosmos::{CosmosEntity, DocumentName};
+
+#[derive(Debug, azure_sdk_cosmos_derive::CosmosEntity)]
+struct MySampleStruct {
+    #[cosmos(id)]
+    id: String,
+    #[cosmos(partition_key)]
+    tenant: String,
+    value: u64,
+}
+
+#[derive(Debug, azure_sdk_cosmos_derive::CosmosEntity)]
+struct MyHierarchicalStruct<'a> {
+    #[cosmos(id)]
+    name: &'a str,
+    #[cosmos(partition_key)]
+    tenant: &'a str,
+    #[cosmos(partition_key)]
+    region: u32,
+}
+
+#[test]
+fn derive_single_partition_key() {
+    let entity = MySampleStruct {
+        id: "unique_id".to_owned(),
+        tenant: "contoso".to_owned(),
+        value: 42,
+    };
+
+    assert_eq!(entity.id(), "unique_id");
+    assert_eq!(entity.name(), "unique_id");
+    assert_eq!(
+        entity.partition_keys().unwrap(),
+        *azure_sdk_cosmos::PartitionKeys::new()
+            .push("contoso")
+            .unwrap()
+    );
+    assert_eq!(entity.value, 42);
+}
+
+#[test]
+fn derive_multiple_partition_keys() {
+    let entity = MyHierarchicalStruct {
+        name: "unique_id",
+        tenant: "contoso",
+        region: 7,
+    };
+
+    assert_eq!(entity.id(), "unique_id");
+    assert_eq!(
+        entity.partition_keys().unwrap(),
+        *azure_sdk_cosmos::PartitionKeys::new()
+            .push("contoso")
+            .unwrap()
+            .push(7)
+            .unwrap()
+    );
+}
@@ -0,0 +1,42 @@
+use azure_sdk_storage_account::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // The access token must be issued for https://management.azure.com/, for
+    // example by azure_sdk_auth_aad or with
+    // az account get-access-token --query accessToken
+    let subscription_id =
+        std::env::var("SUBSCRIPTION_ID").expect("Set env variable SUBSCRIPTION_ID first!");
+    let access_token = std::env::var("ACCESS_TOKEN").expect("Set env variable ACCESS_TOKEN first!");
+
+    let resource_group = std::env::args()
+        .nth(1)
+        .expect("please specify the resource group as command line parameter");
+    let account = std::env::args()
+        .nth(2)
+        .expect("please specify the storage account as command line parameter");
+
+    let management_client = ManagementClient::new(&subscription_id, &access_token);
+
+    let storage_account = management_client
+        .get_storage_account(&resource_group, &account)
+        .finalize()
+        .await?
+        .storage_account;
+    println!("{:?}", storage_account);
+
+    // Regenerate the secondary key and switch to it.
+    let keys = management_client
+        .regenerate_key(&resource_group, &account, "key2")
+        .finalize()
+        .await?;
+    let key = keys.key("key2").expect("key2 missing from the response");
+
+    let client = Client::from_connection_string(&key.connection_string(&storage_account))?;
+    let response = client.get_account_information().finalize().await?;
+    println!("{:?}", response);
+
+    Ok(())
+}
@@ -0,0 +1,436 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse, traverse_single_optional};
+use xml::{escape, Element};
+
+/// How long logs, metrics or deleted blobs are kept. `days` is required
+/// when the policy is enabled.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RetentionPolicy {
+    pub enabled: bool,
+    pub days: Option<u64>,
+}
+
+impl RetentionPolicy {
+    pub fn disabled() -> RetentionPolicy {
+        RetentionPolicy::default()
+    }
+
+    pub fn days(days: u64) -> RetentionPolicy {
+        RetentionPolicy {
+            enabled: true,
+            days: Some(days),
+        }
+    }
+
+    fn parse(elem: &Element) -> Result<RetentionPolicy, AzureError> {
+        Ok(RetentionPolicy {
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            days: cast_optional::<u64>(elem, &["Days"])?,
+        })
+    }
+
+    fn to_xml(&self, name: &str) -> String {
+        let mut s = format!("<{}><Enabled>{}</Enabled>", name, self.enabled);
+        if let Some(days) = self.days {
+            s.push_str(&format!("<Days>{}</Days>", days));
+        }
+        s.push_str(&format!("</{}>", name));
+        s
+    }
+}
+
+/// Storage Analytics logging of the requests to the blob service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logging {
+    pub version: String,
+    pub delete: bool,
+    pub read: bool,
+    pub write: bool,
+    pub retention_policy: RetentionPolicy,
+}
+
+impl Default for Logging {
+    fn default() -> Logging {
+        Logging {
+            version: "1.0".to_owned(),
+            delete: false,
+            read: false,
+            write: false,
+            retention_policy: RetentionPolicy::disabled(),
+        }
+    }
+}
+
+impl Logging {
+    fn parse(elem: &Element) -> Result<Logging, AzureError> {
+        Ok(Logging {
+            version: cast_must::<String>(elem, &["Version"])?,
+            delete: cast_must::<bool>(elem, &["Delete"])?,
+            read: cast_must::<bool>(elem, &["Read"])?,
+            write: cast_must::<bool>(elem, &["Write"])?,
+            retention_policy: RetentionPolicy::parse(
+                traverse(elem, &["RetentionPolicy"], false)?[0],
+            )?,
+        })
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<Logging><Version>{}</Version><Delete>{}</Delete><Read>{}</Read><Write>{}</Write>{}</Logging>",
+            escape(&self.version),
+            self.delete,
+            self.read,
+            self.write,
+            self.retention_policy.to_xml("RetentionPolicy")
+        )
+    }
+}
+
+/// Storage Analytics hour or minute metrics. `include_apis` is only
+/// meaningful, and required, when the metrics are enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    pub version: String,
+    pub enabled: bool,
+    pub include_apis: Option<bool>,
+    pub retention_policy: RetentionPolicy,
+}
+
+impl Default for Metrics {
+    fn default() -> Metrics {
+        Metrics {
+            version: "1.0".to_owned(),
+            enabled: false,
+            include_apis: None,
+            retention_policy: RetentionPolicy::disabled(),
+        }
+    }
+}
+
+impl Metrics {
+    fn parse(elem: &Element) -> Result<Metrics, AzureError> {
+        Ok(Metrics {
+            version: cast_must::<String>(elem, &["Version"])?,
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            include_apis: cast_optional::<bool>(elem, &["IncludeAPIs"])?,
+            retention_policy: RetentionPolicy::parse(
+                traverse(elem, &["RetentionPolicy"], false)?[0],
+            )?,
+        })
+    }
+
+    fn to_xml(&self, name: &str) -> String {
+        let mut s = format!(
+            "<{}><Version>{}</Version><Enabled>{}</Enabled>",
+            name,
+            escape(&self.version),
+            self.enabled
+        );
+        if let Some(include_apis) = self.include_apis {
+            s.push_str(&format!("<IncludeAPIs>{}</IncludeAPIs>", include_apis));
+        }
+        s.push_str(&self.retention_policy.to_xml("RetentionPolicy"));
+        s.push_str(&format!("</{}>", name));
+        s
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub exposed_headers: Vec<String>,
+    pub max_age_in_seconds: u64,
+}
+
+impl CorsRule {
+    fn parse(elem: &Element) -> Result<CorsRule, AzureError> {
+        let list = |name: &str| -> Result<Vec<String>, AzureError> {
+            Ok(cast_optional::<String>(elem, &[name])?
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_owned)
+                .collect())
+        };
+
+        Ok(CorsRule {
+            allowed_origins: list("AllowedOrigins")?,
+            allowed_methods: list("AllowedMethods")?,
+            allowed_headers: list("AllowedHeaders")?,
+            exposed_headers: list("ExposedHeaders")?,
+            max_age_in_seconds: cast_must::<u64>(elem, &["MaxAgeInSeconds"])?,
+        })
+    }
+
+    fn to_xml(&self) -> String {
+        format!(
+            "<CorsRule><AllowedOrigins>{}</AllowedOrigins><AllowedMethods>{}</AllowedMethods><AllowedHeaders>{}</AllowedHeaders><ExposedHeaders>{}</ExposedHeaders><MaxAgeInSeconds>{}</MaxAgeInSeconds></CorsRule>",
+            escape(&self.allowed_origins.join(",")),
+            escape(&self.allowed_methods.join(",")),
+            escape(&self.allowed_headers.join(",")),
+            escape(&self.exposed_headers.join(",")),
+            self.max_age_in_seconds
+        )
+    }
+}
+
+/// Serves the `$web` container as a static website.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticWebsite {
+    pub enabled: bool,
+    pub index_document: Option<String>,
+    pub error_document_404_path: Option<String>,
+}
+
+impl StaticWebsite {
+    fn parse(elem: &Element) -> Result<StaticWebsite, AzureError> {
+        Ok(StaticWebsite {
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            index_document: cast_optional::<String>(elem, &["IndexDocument"])?,
+            error_document_404_path: cast_optional::<String>(elem, &["ErrorDocument404Path"])?,
+        })
+    }
+
+    fn to_xml(&self) -> String {
+        let mut s = format!("<StaticWebsite><Enabled>{}</Enabled>", self.enabled);
+        if let Some(index_document) = &self.index_document {
+            s.push_str(&format!(
+                "<IndexDocument>{}</IndexDocument>",
+                escape(index_document)
+            ));
+        }
+        if let Some(error_document_404_path) = &self.error_document_404_path {
+            s.push_str(&format!(
+                "<ErrorDocument404Path>{}</ErrorDocument404Path>",
+                escape(error_document_404_path)
+            ));
+        }
+        s.push_str("</StaticWebsite>");
+        s
+    }
+}
+
+/// The properties of the blob service of a storage account. When setting
+/// them, the fields left to `None` keep their current value; `cors` set to
+/// an empty list removes all the CORS rules.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlobServiceProperties {
+    pub logging: Option<Logging>,
+    pub hour_metrics: Option<Metrics>,
+    pub minute_metrics: Option<Metrics>,
+    pub cors: Option<Vec<CorsRule>>,
+    pub default_service_version: Option<String>,
+    pub delete_retention_policy: Option<RetentionPolicy>,
+    pub static_website: Option<StaticWebsite>,
+}
+
+impl BlobServiceProperties {
+    pub fn new() -> BlobServiceProperties {
+        BlobServiceProperties::default()
+    }
+
+    pub fn from_xml(xml: &str) -> Result<BlobServiceProperties, AzureError> {
+        // The service prepends a byte order mark the parser chokes on.
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        let cors = match traverse_single_optional(&elem, &["Cors"])? {
+            Some(cors) => Some(
+                traverse(cors, &["CorsRule"], true)?
+                    .into_iter()
+                    .map(CorsRule::parse)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            None => None,
+        };
+
+        Ok(BlobServiceProperties {
+            logging: traverse_single_optional(&elem, &["Logging"])?
+                .map(Logging::parse)
+                .transpose()?,
+            hour_metrics: traverse_single_optional(&elem, &["HourMetrics"])?
+                .map(Metrics::parse)
+                .transpose()?,
+            minute_metrics: traverse_single_optional(&elem, &["MinuteMetrics"])?
+                .map(Metrics::parse)
+                .transpose()?,
+            cors,
+            default_service_version: cast_optional::<String>(&elem, &["DefaultServiceVersion"])?,
+            delete_retention_policy: traverse_single_optional(&elem, &["DeleteRetentionPolicy"])?
+                .map(RetentionPolicy::parse)
+                .transpose()?,
+            static_website: traverse_single_optional(&elem, &["StaticWebsite"])?
+                .map(StaticWebsite::parse)
+                .transpose()?,
+        })
+    }
+
+    pub fn to_xml(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<StorageServiceProperties>");
+        if let Some(logging) = &self.logging {
+            s.push_str(&logging.to_xml());
+        }
+        if let Some(hour_metrics) = &self.hour_metrics {
+            s.push_str(&hour_metrics.to_xml("HourMetrics"));
+        }
+        if let Some(minute_metrics) = &self.minute_metrics {
+            s.push_str(&minute_metrics.to_xml("MinuteMetrics"));
+        }
+        if let Some(cors) = &self.cors {
+            s.push_str("<Cors>");
+            for cors_rule in cors {
+                s.push_str(&cors_rule.to_xml());
+            }
+            s.push_str("</Cors>");
+        }
+        if let Some(default_service_version) = &self.default_service_version {
+            s.push_str(&format!(
+                "<DefaultServiceVersion>{}</DefaultServiceVersion>",
+                escape(default_service_version)
+            ));
+        }
+        if let Some(delete_retention_policy) = &self.delete_retention_policy {
+            s.push_str(&delete_retention_policy.to_xml("DeleteRetentionPolicy"));
+        }
+        if let Some(static_website) = &self.static_website {
+            s.push_str(&static_website.to_xml());
+        }
+        s.push_str("</StorageServiceProperties>");
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const XML: &str = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<StorageServiceProperties>
+    <Logging>
+        <Version>1.0</Version>
+        <Read>false</Read>
+        <Write>true</Write>
+        <Delete>true</Delete>
+        <RetentionPolicy>
+            <Enabled>true</Enabled>
+            <Days>7</Days>
+        </RetentionPolicy>
+    </Logging>
+    <HourMetrics>
+        <Version>1.0</Version>
+        <Enabled>true</Enabled>
+        <IncludeAPIs>false</IncludeAPIs>
+        <RetentionPolicy>
+            <Enabled>true</Enabled>
+            <Days>7</Days>
+        </RetentionPolicy>
+    </HourMetrics>
+    <MinuteMetrics>
+        <Version>1.0</Version>
+        <Enabled>false</Enabled>
+        <RetentionPolicy>
+            <Enabled>false</Enabled>
+        </RetentionPolicy>
+    </MinuteMetrics>
+    <Cors>
+        <CorsRule>
+            <AllowedMethods>GET,PUT</AllowedMethods>
+            <AllowedOrigins>http://www.fabrikam.com,http://www.contoso.com</AllowedOrigins>
+            <AllowedHeaders>x-ms-meta-target*,x-ms-meta-customheader</AllowedHeaders>
+            <ExposedHeaders>x-ms-meta-data*</ExposedHeaders>
+            <MaxAgeInSeconds>500</MaxAgeInSeconds>
+        </CorsRule>
+    </Cors>
+    <DefaultServiceVersion>2018-03-28</DefaultServiceVersion>
+    <DeleteRetentionPolicy>
+        <Enabled>true</Enabled>
+        <Days>5</Days>
+    </DeleteRetentionPolicy>
+    <StaticWebsite>
+        <Enabled>true</Enabled>
+        <IndexDocument>index.html</IndexDocument>
+        <ErrorDocument404Path>error/404.html</ErrorDocument404Path>
+    </StaticWebsite>
+</StorageServiceProperties>";
+
+    #[test]
+    fn parse_and_serialize() {
+        let properties = BlobServiceProperties::from_xml(XML).unwrap();
+        assert_eq!(
+            properties.logging,
+            Some(Logging {
+                version: "1.0".to_owned(),
+                delete: true,
+                read: false,
+                write: true,
+                retention_policy: RetentionPolicy::days(7),
+            })
+        );
+        assert_eq!(
+            properties.hour_metrics.as_ref().unwrap().include_apis,
+            Some(false)
+        );
+        assert_eq!(properties.minute_metrics, Some(Metrics::default()));
+        assert_eq!(
+            properties.cors,
+            Some(vec![CorsRule {
+                allowed_origins: vec![
+                    "http://www.fabrikam.com".to_owned(),
+                    "http://www.contoso.com".to_owned()
+                ],
+                allowed_methods: vec!["GET".to_owned(), "PUT".to_owned()],
+                allowed_headers: vec![
+                    "x-ms-meta-target*".to_owned(),
+                    "x-ms-meta-customheader".to_owned()
+                ],
+                exposed_headers: vec!["x-ms-meta-data*".to_owned()],
+                max_age_in_seconds: 500,
+            }])
+        );
+        assert_eq!(
+            properties.default_service_version.as_deref(),
+            Some("2018-03-28")
+        );
+        assert_eq!(
+            properties.delete_retention_policy,
+            Some(RetentionPolicy::days(5))
+        );
+        assert_eq!(
+            properties
+                .static_website
+                .as_ref()
+                .unwrap()
+                .error_document_404_path,
+            Some("error/404.html".to_owned())
+        );
+
+        // what we send is what we read back
+        assert_eq!(
+            BlobServiceProperties::from_xml(&properties.to_xml()).unwrap(),
+            properties
+        );
+    }
+
+    #[test]
+    fn only_set_properties_are_sent() {
+        let properties = BlobServiceProperties {
+            cors: Some(Vec::new()),
+            static_website: Some(StaticWebsite {
+                enabled: true,
+                index_document: Some("index.html".to_owned()),
+                error_document_404_path: Some("404 & more.html".to_owned()),
+            }),
+            ..BlobServiceProperties::new()
+        };
+        assert_eq!(
+            properties.to_xml(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<StorageServiceProperties><Cors></Cors>\
+<StaticWebsite><Enabled>true</Enabled><IndexDocument>index.html</IndexDocument>\
+<ErrorDocument404Path>404 &amp; more.html</ErrorDocument404Path></StaticWebsite>\
+</StorageServiceProperties>"
+        );
+    }
+}
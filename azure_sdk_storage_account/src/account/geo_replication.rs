@@ -0,0 +1,68 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, cast_optional, from_azure_time, traverse_single_must};
+use chrono::{DateTime, Utc};
+use xml::Element;
+
+create_enum!(
+    GeoReplicationStatus,
+    (Live, "live"),
+    (Bootstrap, "bootstrap"),
+    (Unavailable, "unavailable")
+);
+
+/// The replication of the account to its secondary location. Writes made
+/// before `last_sync_time` are readable from the secondary location; it is
+/// `None` while the replication is bootstrapping or unavailable.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoReplication {
+    pub status: GeoReplicationStatus,
+    pub last_sync_time: Option<DateTime<Utc>>,
+}
+
+impl GeoReplication {
+    pub(crate) fn from_xml(xml: &str) -> Result<GeoReplication, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+        let geo_replication = traverse_single_must(&elem, &["GeoReplication"])?;
+
+        let last_sync_time = match cast_optional::<String>(geo_replication, &["LastSyncTime"])? {
+            Some(ref last_sync_time) if !last_sync_time.is_empty() => {
+                Some(from_azure_time(last_sync_time)?)
+            }
+            _ => None,
+        };
+
+        Ok(GeoReplication {
+            status: cast_must::<GeoReplicationStatus>(geo_replication, &["Status"])?,
+            last_sync_time,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_geo_replication() {
+        let geo_replication = GeoReplication::from_xml(
+            "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><StorageServiceStats><GeoReplication><Status>live</Status><LastSyncTime>Wed, 20 May 2020 08:11:37 GMT</LastSyncTime></GeoReplication></StorageServiceStats>",
+        )
+        .unwrap();
+        assert_eq!(geo_replication.status, GeoReplicationStatus::Live);
+        assert_eq!(
+            geo_replication.last_sync_time,
+            Some(
+                DateTime::parse_from_rfc3339("2020-05-20T08:11:37Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            )
+        );
+
+        let geo_replication = GeoReplication::from_xml(
+            "<StorageServiceStats><GeoReplication><Status>bootstrap</Status><LastSyncTime /></GeoReplication></StorageServiceStats>",
+        )
+        .unwrap();
+        assert_eq!(geo_replication.status, GeoReplicationStatus::Bootstrap);
+        assert_eq!(geo_replication.last_sync_time, None);
+    }
+}
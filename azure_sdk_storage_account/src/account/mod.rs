@@ -1,8 +1,26 @@
+mod blob_service_properties;
+mod geo_replication;
 pub mod requests;
 pub mod responses;
+pub use self::blob_service_properties::{
+    BlobServiceProperties, CorsRule, Logging, Metrics, RetentionPolicy, StaticWebsite,
+};
+pub use self::geo_replication::{GeoReplication, GeoReplicationStatus};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Account {
     pub sku_name: String,
     pub kind: String,
 }
+
+pub trait BlobServicePropertiesSupport<'a> {
+    type O;
+    fn with_blob_service_properties(
+        self,
+        blob_service_properties: &'a BlobServiceProperties,
+    ) -> Self::O;
+}
+
+pub trait BlobServicePropertiesRequired<'a> {
+    fn blob_service_properties(&self) -> &'a BlobServiceProperties;
+}
@@ -0,0 +1,93 @@
+use crate::account::responses::GetBlobServicePropertiesResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServicePropertiesBuilder<'a> {
+    client: &'a Client,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetBlobServicePropertiesBuilder<'a> {
+    pub(crate) fn new(client: &'a Client) -> GetBlobServicePropertiesBuilder<'a> {
+        GetBlobServicePropertiesBuilder {
+            client,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a> ClientRequired<'a> for GetBlobServicePropertiesBuilder<'a> {
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TimeoutOption for GetBlobServicePropertiesBuilder<'a> {
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a> ClientRequestIdOption<'a> for GetBlobServicePropertiesBuilder<'a> {
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a> TimeoutSupport for GetBlobServicePropertiesBuilder<'a> {
+    type O = GetBlobServicePropertiesBuilder<'a>;
+
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetBlobServicePropertiesBuilder {
+            client: self.client,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> ClientRequestIdSupport<'a> for GetBlobServicePropertiesBuilder<'a> {
+    type O = GetBlobServicePropertiesBuilder<'a>;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetBlobServicePropertiesBuilder {
+            client: self.client,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a> GetBlobServicePropertiesBuilder<'a> {
+    pub async fn finalize(self) -> Result<GetBlobServicePropertiesResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=properties",
+            self.client.blob_uri()
+        );
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        trace!("uri == {:?}", uri);
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |mut request| {
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        );
+        let (headers, body) = check_status_extract_headers_and_body(req?, StatusCode::OK).await?;
+        GetBlobServicePropertiesResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::account::responses::GetBlobServiceStatsResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::{
+    ClientRequestIdOption, ClientRequestIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure_sdk_storage_core::client::Client;
+use azure_sdk_storage_core::ClientRequired;
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServiceStatsBuilder<'a> {
+    client: &'a Client,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a> GetBlobServiceStatsBuilder<'a> {
+    pub(crate) fn new(client: &'a Client) -> GetBlobServiceStatsBuilder<'a> {
+        GetBlobServiceStatsBuilder {
+            client,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a> ClientRequired<'a> for GetBlobServiceStatsBuilder<'a> {
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> TimeoutOption for GetBlobServiceStatsBuilder<'a> {
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a> ClientRequestIdOption<'a> for GetBlobServiceStatsBuilder<'a> {
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a> TimeoutSupport for GetBlobServiceStatsBuilder<'a> {
+    type O = GetBlobServiceStatsBuilder<'a>;
+
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetBlobServiceStatsBuilder {
+            client: self.client,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a> ClientRequestIdSupport<'a> for GetBlobServiceStatsBuilder<'a> {
+    type O = GetBlobServiceStatsBuilder<'a>;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetBlobServiceStatsBuilder {
+            client: self.client,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a> GetBlobServiceStatsBuilder<'a> {
+    /// The statistics are only available from the secondary location,
+    /// regardless of the location mode of the client.
+    pub async fn finalize(self) -> Result<GetBlobServiceStatsResponse, AzureError> {
+        let secondary_uri = self.client.blob_secondary_uri().ok_or_else(|| {
+            AzureError::GenericErrorWithText(
+                "The secondary location is unknown: specify it in the connection string to get the service stats."
+                    .to_owned(),
+            )
+        })?;
+        let mut uri = format!("{}/?restype=service&comp=stats", secondary_uri);
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        trace!("uri == {:?}", uri);
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            |mut request| {
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        );
+        let (headers, body) = check_status_extract_headers_and_body(req?, StatusCode::OK).await?;
+        GetBlobServiceStatsResponse::from_response(&headers, &body)
+    }
+}
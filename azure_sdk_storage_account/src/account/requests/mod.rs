@@ -1,2 +1,8 @@
 mod get_account_information_builder;
+mod get_blob_service_properties_builder;
+mod get_blob_service_stats_builder;
+mod set_blob_service_properties_builder;
 pub use self::get_account_information_builder::GetAccountInformationBuilder;
+pub use self::get_blob_service_properties_builder::GetBlobServicePropertiesBuilder;
+pub use self::get_blob_service_stats_builder::GetBlobServiceStatsBuilder;
+pub use self::set_blob_service_properties_builder::SetBlobServicePropertiesBuilder;
@@ -0,0 +1,29 @@
+use crate::account::BlobServiceProperties;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServicePropertiesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub blob_service_properties: BlobServiceProperties,
+}
+
+impl GetBlobServicePropertiesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetBlobServicePropertiesResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let blob_service_properties = BlobServiceProperties::from_xml(std::str::from_utf8(body)?)?;
+
+        Ok(GetBlobServicePropertiesResponse {
+            request_id,
+            date,
+            blob_service_properties,
+        })
+    }
+}
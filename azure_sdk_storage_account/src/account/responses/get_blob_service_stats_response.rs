@@ -0,0 +1,29 @@
+use crate::account::GeoReplication;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct GetBlobServiceStatsResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub geo_replication: GeoReplication,
+}
+
+impl GetBlobServiceStatsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetBlobServiceStatsResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let geo_replication = GeoReplication::from_xml(std::str::from_utf8(body)?)?;
+
+        Ok(GetBlobServiceStatsResponse {
+            request_id,
+            date,
+            geo_replication,
+        })
+    }
+}
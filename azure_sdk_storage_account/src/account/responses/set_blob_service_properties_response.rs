@@ -0,0 +1,21 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobServicePropertiesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl SetBlobServicePropertiesResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<SetBlobServicePropertiesResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(SetBlobServicePropertiesResponse { request_id, date })
+    }
+}
@@ -2,7 +2,12 @@
 
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate azure_sdk_core;
 pub mod account;
+pub mod management;
 pub mod prelude;
 
 use azure_sdk_storage_core::client::Client;
@@ -11,6 +16,19 @@ pub trait Account {
     #[allow(clippy::needless_lifetimes)]
     fn get_account_information<'a>(&'a self)
         -> account::requests::GetAccountInformationBuilder<'a>;
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_properties<'a>(
+        &'a self,
+    ) -> account::requests::GetBlobServicePropertiesBuilder<'a>;
+
+    #[allow(clippy::needless_lifetimes)]
+    fn set_blob_service_properties<'a>(
+        &'a self,
+    ) -> account::requests::SetBlobServicePropertiesBuilder<'a, azure_sdk_core::No>;
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_stats<'a>(&'a self) -> account::requests::GetBlobServiceStatsBuilder<'a>;
 }
 
 impl Account for Client {
@@ -20,4 +38,23 @@ impl Account for Client {
     ) -> account::requests::GetAccountInformationBuilder<'a> {
         account::requests::GetAccountInformationBuilder::new(self)
     }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_properties<'a>(
+        &'a self,
+    ) -> account::requests::GetBlobServicePropertiesBuilder<'a> {
+        account::requests::GetBlobServicePropertiesBuilder::new(self)
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn set_blob_service_properties<'a>(
+        &'a self,
+    ) -> account::requests::SetBlobServicePropertiesBuilder<'a, azure_sdk_core::No> {
+        account::requests::SetBlobServicePropertiesBuilder::new(self)
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    fn get_blob_service_stats<'a>(&'a self) -> account::requests::GetBlobServiceStatsBuilder<'a> {
+        account::requests::GetBlobServiceStatsBuilder::new(self)
+    }
 }
@@ -0,0 +1,235 @@
+use crate::management::requests::*;
+use azure_sdk_core::errors::AzureError;
+use http::header;
+use hyper::{self, Method};
+use hyper_rustls::HttpsConnector;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub(crate) const API_VERSION: &str = "2019-06-01";
+const DEFAULT_ENDPOINT: &str = "https://management.azure.com";
+
+type GetBearerToken =
+    dyn Fn() -> Pin<Box<dyn Future<Output = Result<String, AzureError>> + Send>> + Send + Sync;
+
+/// Manages storage accounts through Azure Resource Manager. Requests are
+/// authorized with an Azure Active Directory bearer token for
+/// `https://management.azure.com/`, for example one obtained with
+/// `azure_sdk_auth_aad`. The principal needs a role granting the requested
+/// operations, such as `Storage Account Contributor`, on the accounts.
+///
+/// `with_token_provider` asks for the token before every request, so a
+/// `TokenCredential` of `azure_sdk_auth_aad` keeps a long lived client
+/// authorized:
+///
+/// ```ignore
+/// let client = ManagementClient::with_token_provider(&subscription_id, move || {
+///     let credential = credential.clone();
+///     async move {
+///         let token = credential
+///             .get_token(&["https://management.azure.com/.default"])
+///             .await?;
+///         Ok(token.access_token().secret().to_owned())
+///     }
+/// });
+///
+/// let storage_account = client
+///     .get_storage_account(&resource_group, &account_name)
+///     .finalize()
+///     .await?
+///     .storage_account;
+/// let keys = client
+///     .regenerate_key(&resource_group, &account_name, "key1")
+///     .finalize()
+///     .await?;
+/// let key = keys.key("key1").unwrap();
+/// let storage_client = Client::from_connection_string(&key.connection_string(&storage_account))?;
+/// ```
+#[derive(Clone)]
+pub struct ManagementClient {
+    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    subscription_id: String,
+    get_bearer_token: Arc<GetBearerToken>,
+    endpoint: String,
+}
+
+impl std::fmt::Debug for ManagementClient {
+    //! We provide a custom implementation to hide the bearer token.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ManagementClient")
+            .field("subscription_id", &self.subscription_id)
+            .field("bearer_token", &"***hidden***")
+            .field("endpoint", &self.endpoint)
+            .finish()
+    }
+}
+
+impl ManagementClient {
+    /// Authorizes every request with `bearer_token`. The client stops
+    /// working when the token expires: see `with_token_provider`.
+    pub fn new(subscription_id: &str, bearer_token: &str) -> ManagementClient {
+        Self::with_token_provider(subscription_id, static_token(bearer_token))
+    }
+
+    /// Authorizes every request with a token returned by `get_bearer_token`,
+    /// which is called before each request and is expected to cache the
+    /// token until it is about to expire.
+    pub fn with_token_provider<F, Fut>(
+        subscription_id: &str,
+        get_bearer_token: F,
+    ) -> ManagementClient
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, AzureError>> + Send + 'static,
+    {
+        ManagementClient {
+            hc: hyper::Client::builder().build(HttpsConnector::new()),
+            subscription_id: subscription_id.to_owned(),
+            get_bearer_token: Arc::new(move || Box::pin(get_bearer_token())),
+            endpoint: DEFAULT_ENDPOINT.to_owned(),
+        }
+    }
+
+    /// Uses another Azure Resource Manager endpoint, for example
+    /// `https://management.chinacloudapi.cn` for Azure China.
+    pub fn with_endpoint(self, endpoint: &str) -> ManagementClient {
+        ManagementClient {
+            endpoint: endpoint.trim_end_matches('/').to_owned(),
+            ..self
+        }
+    }
+
+    /// Replaces the bearer token, once the previous one is about to expire.
+    pub fn with_bearer_token(self, bearer_token: &str) -> ManagementClient {
+        let get_bearer_token = static_token(bearer_token);
+        ManagementClient {
+            get_bearer_token: Arc::new(move || Box::pin(get_bearer_token())),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn subscription_id(&self) -> &str {
+        &self.subscription_id
+    }
+
+    #[inline]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub fn list_storage_accounts(&self) -> ListStorageAccountsBuilder<'_> {
+        ListStorageAccountsBuilder::new(self)
+    }
+
+    pub fn get_storage_account<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+    ) -> GetStorageAccountBuilder<'a> {
+        GetStorageAccountBuilder::new(self, resource_group, account_name)
+    }
+
+    pub fn create_storage_account<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+        location: &'a str,
+    ) -> CreateStorageAccountBuilder<'a> {
+        CreateStorageAccountBuilder::new(self, resource_group, account_name, location)
+    }
+
+    pub fn delete_storage_account<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+    ) -> DeleteStorageAccountBuilder<'a> {
+        DeleteStorageAccountBuilder::new(self, resource_group, account_name)
+    }
+
+    pub fn list_keys<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+    ) -> ListKeysBuilder<'a> {
+        ListKeysBuilder::new(self, resource_group, account_name)
+    }
+
+    /// Regenerates `key1` or `key2`. Clients signing with the old key stop
+    /// working immediately: rotate the key they do not use.
+    pub fn regenerate_key<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+        key_name: &'a str,
+    ) -> RegenerateKeyBuilder<'a> {
+        RegenerateKeyBuilder::new(self, resource_group, account_name, key_name)
+    }
+
+    pub fn update_network_rules<'a>(
+        &'a self,
+        resource_group: &'a str,
+        account_name: &'a str,
+        network_rule_set: &'a crate::management::NetworkRuleSet,
+    ) -> UpdateNetworkRulesBuilder<'a> {
+        UpdateNetworkRulesBuilder::new(self, resource_group, account_name, network_rule_set)
+    }
+
+    /// The storage accounts of the subscription, or of `resource_group`.
+    pub(crate) fn storage_accounts_uri(&self, resource_group: Option<&str>) -> String {
+        match resource_group {
+            Some(resource_group) => format!(
+                "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Storage/storageAccounts",
+                self.endpoint, self.subscription_id, resource_group
+            ),
+            None => format!(
+                "{}/subscriptions/{}/providers/Microsoft.Storage/storageAccounts",
+                self.endpoint, self.subscription_id
+            ),
+        }
+    }
+
+    pub(crate) fn storage_account_uri(&self, resource_group: &str, account_name: &str) -> String {
+        format!(
+            "{}/{}",
+            self.storage_accounts_uri(Some(resource_group)),
+            account_name
+        )
+    }
+
+    pub(crate) async fn perform_request(
+        &self,
+        uri: &str,
+        method: &Method,
+        request_body: Option<&[u8]>,
+    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+        trace!("{} {}", method, uri);
+
+        let bearer_token = (self.get_bearer_token)().await?;
+
+        let request = hyper::Request::builder()
+            .method(method.clone())
+            .uri(uri)
+            .header(header::AUTHORIZATION, format!("Bearer {}", bearer_token))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(
+                header::CONTENT_LENGTH,
+                request_body.map(|b| b.len()).unwrap_or(0),
+            )
+            .body(
+                request_body
+                    .map(|b| Vec::from(b).into())
+                    .unwrap_or_else(hyper::Body::empty),
+            )?;
+
+        Ok(self.hc.request(request))
+    }
+}
+
+fn static_token(
+    bearer_token: &str,
+) -> impl Fn() -> futures::future::Ready<Result<String, AzureError>> + Send + Sync + 'static {
+    let bearer_token = bearer_token.to_owned();
+    move || futures::future::ready(Ok(bearer_token.clone()))
+}
@@ -0,0 +1,10 @@
+//! Storage account management through Azure Resource Manager, as opposed
+//! to the data plane operations signed with the account keys.
+mod management_client;
+mod network_rule_set;
+pub mod requests;
+pub mod responses;
+mod storage_account;
+pub use self::management_client::ManagementClient;
+pub use self::network_rule_set::*;
+pub use self::storage_account::*;
@@ -0,0 +1,83 @@
+create_enum!(NetworkRuleAction, (Allow, "Allow"), (Deny, "Deny"));
+
+/// Allows an IPv4 address or CIDR range, for example `203.0.113.0/24`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IpRule {
+    #[serde(rename = "value")]
+    pub ip_address_or_range: String,
+    pub action: NetworkRuleAction,
+}
+
+impl IpRule {
+    pub fn new<S: Into<String>>(ip_address_or_range: S) -> Self {
+        Self {
+            ip_address_or_range: ip_address_or_range.into(),
+            action: NetworkRuleAction::Allow,
+        }
+    }
+}
+
+/// Allows a virtual network subnet, by ARM resource id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VirtualNetworkRule {
+    #[serde(rename = "id")]
+    pub virtual_network_resource_id: String,
+    pub action: NetworkRuleAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
+impl VirtualNetworkRule {
+    pub fn new<S: Into<String>>(virtual_network_resource_id: S) -> Self {
+        Self {
+            virtual_network_resource_id: virtual_network_resource_id.into(),
+            action: NetworkRuleAction::Allow,
+            state: None,
+        }
+    }
+}
+
+/// The firewall of a storage account. `bypass` lists the traffic let through
+/// regardless of the rules, comma separated: `None` or a combination of
+/// `AzureServices`, `Logging` and `Metrics`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkRuleSet {
+    pub bypass: String,
+    pub default_action: NetworkRuleAction,
+    #[serde(default)]
+    pub ip_rules: Vec<IpRule>,
+    #[serde(default)]
+    pub virtual_network_rules: Vec<VirtualNetworkRule>,
+}
+
+impl Default for NetworkRuleSet {
+    fn default() -> Self {
+        Self {
+            bypass: "AzureServices".to_owned(),
+            default_action: NetworkRuleAction::Allow,
+            ip_rules: Vec::new(),
+            virtual_network_rules: Vec::new(),
+        }
+    }
+}
+
+impl NetworkRuleSet {
+    /// Denies everything but the Azure services, add rules to allow more.
+    pub fn deny_by_default() -> Self {
+        Self {
+            default_action: NetworkRuleAction::Deny,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_ip_rule(mut self, ip_rule: IpRule) -> Self {
+        self.ip_rules.push(ip_rule);
+        self
+    }
+
+    pub fn with_virtual_network_rule(mut self, virtual_network_rule: VirtualNetworkRule) -> Self {
+        self.virtual_network_rules.push(virtual_network_rule);
+        self
+    }
+}
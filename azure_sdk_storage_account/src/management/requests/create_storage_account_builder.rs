@@ -0,0 +1,245 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::responses::StorageAccountResponse;
+use crate::management::{AccessTier, Kind, ManagementClient, NetworkRuleSet, SkuName};
+use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_sdk_core::util::HeaderMapExt;
+use http::header;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct CreateStorageAccountBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: &'a str,
+    account_name: &'a str,
+    location: &'a str,
+    kind: Kind,
+    sku_name: SkuName,
+    access_tier: Option<AccessTier>,
+    https_traffic_only: Option<bool>,
+    network_rule_set: Option<&'a NetworkRuleSet>,
+    tags: Option<&'a HashMap<String, String>>,
+}
+
+impl<'a> CreateStorageAccountBuilder<'a> {
+    pub(crate) fn new(
+        management_client: &'a ManagementClient,
+        resource_group: &'a str,
+        account_name: &'a str,
+        location: &'a str,
+    ) -> CreateStorageAccountBuilder<'a> {
+        CreateStorageAccountBuilder {
+            management_client,
+            resource_group,
+            account_name,
+            location,
+            kind: Kind::StorageV2,
+            sku_name: SkuName::StandardLRS,
+            access_tier: None,
+            https_traffic_only: None,
+            network_rule_set: None,
+            tags: None,
+        }
+    }
+}
+
+impl<'a> CreateStorageAccountBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> &'a str {
+        self.resource_group
+    }
+
+    #[inline]
+    pub fn account_name(&self) -> &'a str {
+        self.account_name
+    }
+
+    #[inline]
+    pub fn location(&self) -> &'a str {
+        self.location
+    }
+
+    #[inline]
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    #[inline]
+    pub fn sku_name(&self) -> SkuName {
+        self.sku_name
+    }
+
+    #[inline]
+    pub fn access_tier(&self) -> Option<AccessTier> {
+        self.access_tier
+    }
+
+    #[inline]
+    pub fn https_traffic_only(&self) -> Option<bool> {
+        self.https_traffic_only
+    }
+
+    #[inline]
+    pub fn network_rule_set(&self) -> Option<&'a NetworkRuleSet> {
+        self.network_rule_set
+    }
+
+    #[inline]
+    pub fn tags(&self) -> Option<&'a HashMap<String, String>> {
+        self.tags
+    }
+
+    /// `StorageV2` by default.
+    pub fn with_kind(self, kind: Kind) -> Self {
+        CreateStorageAccountBuilder { kind, ..self }
+    }
+
+    /// `Standard_LRS` by default.
+    pub fn with_sku_name(self, sku_name: SkuName) -> Self {
+        CreateStorageAccountBuilder { sku_name, ..self }
+    }
+
+    pub fn with_access_tier(self, access_tier: AccessTier) -> Self {
+        CreateStorageAccountBuilder {
+            access_tier: Some(access_tier),
+            ..self
+        }
+    }
+
+    pub fn with_https_traffic_only(self, https_traffic_only: bool) -> Self {
+        CreateStorageAccountBuilder {
+            https_traffic_only: Some(https_traffic_only),
+            ..self
+        }
+    }
+
+    pub fn with_network_rule_set(self, network_rule_set: &'a NetworkRuleSet) -> Self {
+        CreateStorageAccountBuilder {
+            network_rule_set: Some(network_rule_set),
+            ..self
+        }
+    }
+
+    pub fn with_tags(self, tags: &'a HashMap<String, String>) -> Self {
+        CreateStorageAccountBuilder {
+            tags: Some(tags),
+            ..self
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SkuRequest {
+    name: SkuName,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PropertiesRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_tier: Option<AccessTier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supports_https_traffic_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_acls: Option<&'a NetworkRuleSet>,
+}
+
+#[derive(Serialize)]
+struct CreateStorageAccountRequest<'a> {
+    sku: SkuRequest,
+    kind: Kind,
+    location: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<&'a HashMap<String, String>>,
+    properties: PropertiesRequest<'a>,
+}
+
+// methods callable regardless
+impl<'a> CreateStorageAccountBuilder<'a> {
+    /// Creates the account and waits for Azure Resource Manager to finish
+    /// provisioning it. Account names must be globally unique.
+    pub async fn finalize(self) -> Result<StorageAccountResponse, AzureError> {
+        let uri = format!(
+            "{}?api-version={}",
+            self.management_client
+                .storage_account_uri(self.resource_group, self.account_name),
+            API_VERSION
+        );
+        let request_body = serde_json::to_string(&CreateStorageAccountRequest {
+            sku: SkuRequest {
+                name: self.sku_name,
+            },
+            kind: self.kind,
+            location: self.location,
+            tags: self.tags,
+            properties: PropertiesRequest {
+                access_tier: self.access_tier,
+                supports_https_traffic_only: self.https_traffic_only,
+                network_acls: self.network_rule_set,
+            },
+        })?;
+
+        let req = self
+            .management_client
+            .perform_request(&uri, &Method::PUT, Some(request_body.as_bytes()))
+            .await;
+        let (mut status, mut headers, mut body) = extract_status_headers_and_body(req?).await?;
+
+        // The creation is asynchronous: 202 means it is still in progress
+        // and the Location header tells where to poll for its completion.
+        while status == StatusCode::ACCEPTED {
+            let location = headers
+                .get_as_str(header::LOCATION)
+                .ok_or_else(|| AzureError::HeaderNotFound(header::LOCATION.to_string()))?
+                .to_owned();
+            let retry_after = headers
+                .get_as_str(header::RETRY_AFTER)
+                .and_then(|retry_after| retry_after.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_POLLING_INTERVAL);
+            debug!(
+                "storage account {} still being created, polling in {:?}",
+                self.account_name, retry_after
+            );
+            tokio::time::delay_for(retry_after).await;
+
+            let req = self
+                .management_client
+                .perform_request(&location, &Method::GET, None)
+                .await;
+            let response = extract_status_headers_and_body(req?).await?;
+            status = response.0;
+            headers = response.1;
+            body = response.2;
+        }
+
+        if status != StatusCode::OK {
+            return Err(AzureError::UnexpectedHTTPResult(
+                UnexpectedHTTPResult::new_multiple(
+                    vec![StatusCode::OK, StatusCode::ACCEPTED],
+                    status,
+                    std::str::from_utf8(&body)?,
+                ),
+            ));
+        }
+
+        // The final poll may return an empty body, so the account is
+        // read back in that case.
+        if body.is_empty() {
+            self.management_client
+                .get_storage_account(self.resource_group, self.account_name)
+                .finalize()
+                .await
+        } else {
+            StorageAccountResponse::from_json(std::str::from_utf8(&body)?)
+        }
+    }
+}
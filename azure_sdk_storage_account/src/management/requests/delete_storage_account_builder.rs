@@ -0,0 +1,72 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::ManagementClient;
+use azure_sdk_core::errors::{extract_status_and_body, AzureError, UnexpectedHTTPResult};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct DeleteStorageAccountBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: &'a str,
+    account_name: &'a str,
+}
+
+impl<'a> DeleteStorageAccountBuilder<'a> {
+    pub(crate) fn new(
+        management_client: &'a ManagementClient,
+        resource_group: &'a str,
+        account_name: &'a str,
+    ) -> DeleteStorageAccountBuilder<'a> {
+        DeleteStorageAccountBuilder {
+            management_client,
+            resource_group,
+            account_name,
+        }
+    }
+}
+
+impl<'a> DeleteStorageAccountBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> &'a str {
+        self.resource_group
+    }
+
+    #[inline]
+    pub fn account_name(&self) -> &'a str {
+        self.account_name
+    }
+}
+
+// methods callable regardless
+impl<'a> DeleteStorageAccountBuilder<'a> {
+    /// Deletes the account and all its data. Deleting an account that does
+    /// not exist succeeds.
+    pub async fn finalize(self) -> Result<(), AzureError> {
+        let uri = format!(
+            "{}?api-version={}",
+            self.management_client
+                .storage_account_uri(self.resource_group, self.account_name),
+            API_VERSION
+        );
+
+        let req = self
+            .management_client
+            .perform_request(&uri, &Method::DELETE, None)
+            .await;
+        let (status, body) = extract_status_and_body(req?).await?;
+        match status {
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(()),
+            _ => Err(AzureError::UnexpectedHTTPResult(
+                UnexpectedHTTPResult::new_multiple(
+                    vec![StatusCode::OK, StatusCode::NO_CONTENT],
+                    status,
+                    &body,
+                ),
+            )),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::responses::StorageAccountResponse;
+use crate::management::ManagementClient;
+use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetStorageAccountBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: &'a str,
+    account_name: &'a str,
+}
+
+impl<'a> GetStorageAccountBuilder<'a> {
+    pub(crate) fn new(
+        management_client: &'a ManagementClient,
+        resource_group: &'a str,
+        account_name: &'a str,
+    ) -> GetStorageAccountBuilder<'a> {
+        GetStorageAccountBuilder {
+            management_client,
+            resource_group,
+            account_name,
+        }
+    }
+}
+
+impl<'a> GetStorageAccountBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> &'a str {
+        self.resource_group
+    }
+
+    #[inline]
+    pub fn account_name(&self) -> &'a str {
+        self.account_name
+    }
+}
+
+// methods callable regardless
+impl<'a> GetStorageAccountBuilder<'a> {
+    pub async fn finalize(self) -> Result<StorageAccountResponse, AzureError> {
+        let uri = format!(
+            "{}?api-version={}",
+            self.management_client
+                .storage_account_uri(self.resource_group, self.account_name),
+            API_VERSION
+        );
+
+        let req = self
+            .management_client
+            .perform_request(&uri, &Method::GET, None)
+            .await;
+        let body = check_status_extract_body(req?, StatusCode::OK).await?;
+        StorageAccountResponse::from_json(&body)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::responses::ListStorageAccountsResponse;
+use crate::management::ManagementClient;
+use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct ListStorageAccountsBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: Option<&'a str>,
+}
+
+impl<'a> ListStorageAccountsBuilder<'a> {
+    pub(crate) fn new(management_client: &'a ManagementClient) -> ListStorageAccountsBuilder<'a> {
+        ListStorageAccountsBuilder {
+            management_client,
+            resource_group: None,
+        }
+    }
+}
+
+impl<'a> ListStorageAccountsBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> Option<&'a str> {
+        self.resource_group
+    }
+
+    /// Lists only the accounts of `resource_group` instead of the whole
+    /// subscription.
+    pub fn with_resource_group(self, resource_group: &'a str) -> Self {
+        ListStorageAccountsBuilder {
+            management_client: self.management_client,
+            resource_group: Some(resource_group),
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a> ListStorageAccountsBuilder<'a> {
+    /// Returns every account, following the continuation links.
+    pub async fn finalize(self) -> Result<ListStorageAccountsResponse, AzureError> {
+        let mut uri = Some(format!(
+            "{}?api-version={}",
+            self.management_client
+                .storage_accounts_uri(self.resource_group),
+            API_VERSION
+        ));
+
+        let mut storage_accounts = Vec::new();
+        while let Some(next_uri) = uri {
+            let req = self
+                .management_client
+                .perform_request(&next_uri, &Method::GET, None)
+                .await;
+            let body = check_status_extract_body(req?, StatusCode::OK).await?;
+            let page: ListStorageAccountsResponse = serde_json::from_str(&body)?;
+
+            storage_accounts.extend(page.storage_accounts);
+            uri = page.next_link;
+        }
+
+        Ok(ListStorageAccountsResponse {
+            storage_accounts,
+            next_link: None,
+        })
+    }
+}
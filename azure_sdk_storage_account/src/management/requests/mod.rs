@@ -0,0 +1,14 @@
+mod create_storage_account_builder;
+mod delete_storage_account_builder;
+mod get_storage_account_builder;
+mod list_keys_builder;
+mod list_storage_accounts_builder;
+mod regenerate_key_builder;
+mod update_network_rules_builder;
+pub use self::create_storage_account_builder::CreateStorageAccountBuilder;
+pub use self::delete_storage_account_builder::DeleteStorageAccountBuilder;
+pub use self::get_storage_account_builder::GetStorageAccountBuilder;
+pub use self::list_keys_builder::ListKeysBuilder;
+pub use self::list_storage_accounts_builder::ListStorageAccountsBuilder;
+pub use self::regenerate_key_builder::RegenerateKeyBuilder;
+pub use self::update_network_rules_builder::UpdateNetworkRulesBuilder;
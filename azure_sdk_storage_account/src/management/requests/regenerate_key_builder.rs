@@ -0,0 +1,80 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::responses::ListKeysResponse;
+use crate::management::ManagementClient;
+use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct RegenerateKeyBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: &'a str,
+    account_name: &'a str,
+    key_name: &'a str,
+}
+
+impl<'a> RegenerateKeyBuilder<'a> {
+    pub(crate) fn new(
+        management_client: &'a ManagementClient,
+        resource_group: &'a str,
+        account_name: &'a str,
+        key_name: &'a str,
+    ) -> RegenerateKeyBuilder<'a> {
+        RegenerateKeyBuilder {
+            management_client,
+            resource_group,
+            account_name,
+            key_name,
+        }
+    }
+}
+
+impl<'a> RegenerateKeyBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> &'a str {
+        self.resource_group
+    }
+
+    #[inline]
+    pub fn account_name(&self) -> &'a str {
+        self.account_name
+    }
+
+    #[inline]
+    pub fn key_name(&self) -> &'a str {
+        self.key_name
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RegenerateKeyRequest<'a> {
+    key_name: &'a str,
+}
+
+// methods callable regardless
+impl<'a> RegenerateKeyBuilder<'a> {
+    /// Returns all the keys of the account, the regenerated one included.
+    pub async fn finalize(self) -> Result<ListKeysResponse, AzureError> {
+        let uri = format!(
+            "{}/regenerateKey?api-version={}",
+            self.management_client
+                .storage_account_uri(self.resource_group, self.account_name),
+            API_VERSION
+        );
+        let request_body = serde_json::to_string(&RegenerateKeyRequest {
+            key_name: self.key_name,
+        })?;
+
+        let req = self
+            .management_client
+            .perform_request(&uri, &Method::POST, Some(request_body.as_bytes()))
+            .await;
+        let body = check_status_extract_body(req?, StatusCode::OK).await?;
+        Ok(serde_json::from_str(&body)?)
+    }
+}
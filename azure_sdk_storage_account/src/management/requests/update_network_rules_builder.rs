@@ -0,0 +1,87 @@
+use crate::management::management_client::API_VERSION;
+use crate::management::responses::StorageAccountResponse;
+use crate::management::{ManagementClient, NetworkRuleSet};
+use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct UpdateNetworkRulesBuilder<'a> {
+    management_client: &'a ManagementClient,
+    resource_group: &'a str,
+    account_name: &'a str,
+    network_rule_set: &'a NetworkRuleSet,
+}
+
+impl<'a> UpdateNetworkRulesBuilder<'a> {
+    pub(crate) fn new(
+        management_client: &'a ManagementClient,
+        resource_group: &'a str,
+        account_name: &'a str,
+        network_rule_set: &'a NetworkRuleSet,
+    ) -> UpdateNetworkRulesBuilder<'a> {
+        UpdateNetworkRulesBuilder {
+            management_client,
+            resource_group,
+            account_name,
+            network_rule_set,
+        }
+    }
+}
+
+impl<'a> UpdateNetworkRulesBuilder<'a> {
+    #[inline]
+    pub fn management_client(&self) -> &'a ManagementClient {
+        self.management_client
+    }
+
+    #[inline]
+    pub fn resource_group(&self) -> &'a str {
+        self.resource_group
+    }
+
+    #[inline]
+    pub fn account_name(&self) -> &'a str {
+        self.account_name
+    }
+
+    #[inline]
+    pub fn network_rule_set(&self) -> &'a NetworkRuleSet {
+        self.network_rule_set
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NetworkAcls<'a> {
+    network_acls: &'a NetworkRuleSet,
+}
+
+#[derive(Serialize)]
+struct UpdateNetworkRulesRequest<'a> {
+    properties: NetworkAcls<'a>,
+}
+
+// methods callable regardless
+impl<'a> UpdateNetworkRulesBuilder<'a> {
+    /// Replaces the whole rule set of the account.
+    pub async fn finalize(self) -> Result<StorageAccountResponse, AzureError> {
+        let uri = format!(
+            "{}?api-version={}",
+            self.management_client
+                .storage_account_uri(self.resource_group, self.account_name),
+            API_VERSION
+        );
+        let request_body = serde_json::to_string(&UpdateNetworkRulesRequest {
+            properties: NetworkAcls {
+                network_acls: self.network_rule_set,
+            },
+        })?;
+
+        let req = self
+            .management_client
+            .perform_request(&uri, &Method::PATCH, Some(request_body.as_bytes()))
+            .await;
+        let body = check_status_extract_body(req?, StatusCode::OK).await?;
+        StorageAccountResponse::from_json(&body)
+    }
+}
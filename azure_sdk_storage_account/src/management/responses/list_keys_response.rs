@@ -0,0 +1,13 @@
+use crate::management::StorageAccountKey;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListKeysResponse {
+    pub keys: Vec<StorageAccountKey>,
+}
+
+impl ListKeysResponse {
+    /// The key named `key_name`, `key1` or `key2`.
+    pub fn key(&self, key_name: &str) -> Option<&StorageAccountKey> {
+        self.keys.iter().find(|key| key.key_name == key_name)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::management::StorageAccount;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListStorageAccountsResponse {
+    #[serde(rename = "value")]
+    pub storage_accounts: Vec<StorageAccount>,
+    #[serde(rename = "nextLink")]
+    pub(crate) next_link: Option<String>,
+}
@@ -0,0 +1,6 @@
+mod list_keys_response;
+mod list_storage_accounts_response;
+mod storage_account_response;
+pub use list_keys_response::ListKeysResponse;
+pub use list_storage_accounts_response::ListStorageAccountsResponse;
+pub use storage_account_response::StorageAccountResponse;
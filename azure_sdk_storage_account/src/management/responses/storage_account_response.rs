@@ -0,0 +1,15 @@
+use crate::management::StorageAccount;
+use azure_sdk_core::errors::AzureError;
+
+#[derive(Debug, Clone)]
+pub struct StorageAccountResponse {
+    pub storage_account: StorageAccount,
+}
+
+impl StorageAccountResponse {
+    pub(crate) fn from_json(body: &str) -> Result<StorageAccountResponse, AzureError> {
+        Ok(StorageAccountResponse {
+            storage_account: serde_json::from_str(body)?,
+        })
+    }
+}
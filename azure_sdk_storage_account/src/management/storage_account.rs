@@ -0,0 +1,289 @@
+use crate::management::NetworkRuleSet;
+use azure_sdk_storage_core::{ConnectionStringBuilder, EndpointProtocol};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+create_enum!(
+    Kind,
+    (Storage, "Storage"),
+    (StorageV2, "StorageV2"),
+    (BlobStorage, "BlobStorage"),
+    (FileStorage, "FileStorage"),
+    (BlockBlobStorage, "BlockBlobStorage")
+);
+
+create_enum!(AccessTier, (Hot, "Hot"), (Cool, "Cool"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SkuName {
+    #[serde(rename = "Standard_LRS")]
+    StandardLRS,
+    #[serde(rename = "Standard_GRS")]
+    StandardGRS,
+    #[serde(rename = "Standard_RAGRS")]
+    StandardRAGRS,
+    #[serde(rename = "Standard_ZRS")]
+    StandardZRS,
+    #[serde(rename = "Standard_GZRS")]
+    StandardGZRS,
+    #[serde(rename = "Standard_RAGZRS")]
+    StandardRAGZRS,
+    #[serde(rename = "Premium_LRS")]
+    PremiumLRS,
+    #[serde(rename = "Premium_ZRS")]
+    PremiumZRS,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sku {
+    pub name: SkuName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Endpoints {
+    pub blob: Option<String>,
+    pub queue: Option<String>,
+    pub table: Option<String>,
+    pub file: Option<String>,
+    pub dfs: Option<String>,
+    pub web: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAccountProperties {
+    pub provisioning_state: Option<String>,
+    pub primary_endpoints: Option<Endpoints>,
+    pub primary_location: Option<String>,
+    pub status_of_primary: Option<String>,
+    pub secondary_endpoints: Option<Endpoints>,
+    pub secondary_location: Option<String>,
+    pub status_of_secondary: Option<String>,
+    pub creation_time: Option<DateTime<Utc>>,
+    pub access_tier: Option<AccessTier>,
+    pub supports_https_traffic_only: Option<bool>,
+    pub network_acls: Option<NetworkRuleSet>,
+}
+
+/// A storage account, as described by Azure Resource Manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageAccount {
+    pub id: String,
+    pub name: String,
+    pub location: String,
+    pub kind: Kind,
+    pub sku: Sku,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    pub properties: StorageAccountProperties,
+}
+
+impl StorageAccount {
+    /// The resource group, taken from the account id.
+    pub fn resource_group(&self) -> Option<&str> {
+        let mut segments = self.id.split('/');
+        segments
+            .by_ref()
+            .find(|segment| segment.eq_ignore_ascii_case("resourceGroups"))?;
+        segments.next()
+    }
+}
+
+// The service answers in upper case, unlike what its specification says.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum KeyPermission {
+    #[serde(alias = "READ")]
+    Read,
+    #[serde(alias = "FULL")]
+    Full,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageAccountKey {
+    pub key_name: String,
+    pub value: String,
+    pub permissions: KeyPermission,
+}
+
+impl std::fmt::Debug for StorageAccountKey {
+    //! We provide a custom implementation to hide the key value.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("StorageAccountKey")
+            .field("key_name", &self.key_name)
+            .field("value", &"***hidden***")
+            .field("permissions", &self.permissions)
+            .finish()
+    }
+}
+
+impl StorageAccountKey {
+    /// A connection string for `storage_account` signed with this key,
+    /// suitable for `Client::from_connection_string`. The endpoints are the
+    /// ones reported by the account, so that accounts outside of the public
+    /// cloud are reached where they live.
+    pub fn connection_string(&self, storage_account: &StorageAccount) -> String {
+        let mut builder = ConnectionStringBuilder::new();
+        builder
+            .with_default_endpoints_protocol(EndpointProtocol::Https)
+            .with_account_name(&storage_account.name)
+            .with_account_key(&self.value);
+
+        if let Some(endpoints) = &storage_account.properties.primary_endpoints {
+            if let Some(blob) = &endpoints.blob {
+                builder.with_blob_endpoint(blob);
+            }
+            if let Some(queue) = &endpoints.queue {
+                builder.with_queue_endpoint(queue);
+            }
+            if let Some(table) = &endpoints.table {
+                builder.with_table_endpoint(table);
+            }
+            if let Some(file) = &endpoints.file {
+                builder.with_file_endpoint(file);
+            }
+        }
+        if let Some(endpoints) = &storage_account.properties.secondary_endpoints {
+            if let Some(blob) = &endpoints.blob {
+                builder.with_blob_secondary_endpoint(blob);
+            }
+            if let Some(queue) = &endpoints.queue {
+                builder.with_queue_secondary_endpoint(queue);
+            }
+            if let Some(table) = &endpoints.table {
+                builder.with_table_secondary_endpoint(table);
+            }
+            if let Some(file) = &endpoints.file {
+                builder.with_file_secondary_endpoint(file);
+            }
+        }
+
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::management::{IpRule, NetworkRuleAction};
+    use azure_sdk_storage_core::ConnectionString;
+
+    const STORAGE_ACCOUNT: &str = r#"{
+        "id": "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/my-rg/providers/Microsoft.Storage/storageAccounts/myaccount",
+        "name": "myaccount",
+        "type": "Microsoft.Storage/storageAccounts",
+        "location": "westeurope",
+        "tags": { "env": "test" },
+        "sku": { "name": "Standard_RAGRS", "tier": "Standard" },
+        "kind": "StorageV2",
+        "properties": {
+            "provisioningState": "Succeeded",
+            "primaryEndpoints": {
+                "blob": "https://myaccount.blob.core.windows.net/",
+                "queue": "https://myaccount.queue.core.windows.net/",
+                "table": "https://myaccount.table.core.windows.net/",
+                "file": "https://myaccount.file.core.windows.net/"
+            },
+            "primaryLocation": "westeurope",
+            "statusOfPrimary": "available",
+            "secondaryLocation": "northeurope",
+            "statusOfSecondary": "available",
+            "creationTime": "2020-05-04T10:20:30.1234567Z",
+            "accessTier": "Hot",
+            "supportsHttpsTrafficOnly": true,
+            "networkAcls": {
+                "bypass": "AzureServices",
+                "virtualNetworkRules": [],
+                "ipRules": [ { "value": "203.0.113.0/24", "action": "Allow" } ],
+                "defaultAction": "Deny"
+            },
+            "encryption": { "keySource": "Microsoft.Storage" }
+        }
+    }"#;
+
+    #[test]
+    fn parse_storage_account() {
+        let storage_account: StorageAccount = serde_json::from_str(STORAGE_ACCOUNT).unwrap();
+        assert_eq!(storage_account.name, "myaccount");
+        assert_eq!(storage_account.resource_group(), Some("my-rg"));
+        assert_eq!(storage_account.kind, Kind::StorageV2);
+        assert_eq!(storage_account.sku.name, SkuName::StandardRAGRS);
+        assert_eq!(storage_account.tags["env"], "test");
+        assert_eq!(
+            storage_account.properties.secondary_location.as_deref(),
+            Some("northeurope")
+        );
+
+        let network_rule_set = storage_account.properties.network_acls.unwrap();
+        assert_eq!(
+            network_rule_set,
+            NetworkRuleSet::deny_by_default().with_ip_rule(IpRule::new("203.0.113.0/24"))
+        );
+        assert_eq!(
+            serde_json::to_value(&network_rule_set).unwrap(),
+            serde_json::json!({
+                "bypass": "AzureServices",
+                "defaultAction": "Deny",
+                "ipRules": [ { "value": "203.0.113.0/24", "action": "Allow" } ],
+                "virtualNetworkRules": []
+            })
+        );
+        assert_eq!(network_rule_set.default_action, NetworkRuleAction::Deny);
+    }
+
+    #[test]
+    fn key_connection_string() {
+        let key: StorageAccountKey = serde_json::from_str(
+            r#"{ "keyName": "key1", "value": "c2VjcmV0", "permissions": "FULL" }"#,
+        )
+        .unwrap();
+        assert_eq!(key.permissions, KeyPermission::Full);
+        assert!(!format!("{:?}", key).contains("c2VjcmV0"));
+
+        let mut storage_account: StorageAccount = serde_json::from_str(STORAGE_ACCOUNT).unwrap();
+        let connection_string = key.connection_string(&storage_account);
+        let parsed = ConnectionString::new(&connection_string).unwrap();
+        assert_eq!(parsed.account_name, Some("myaccount"));
+        assert_eq!(parsed.account_key, Some("c2VjcmV0"));
+        assert_eq!(
+            parsed.blob_endpoint,
+            Some("https://myaccount.blob.core.windows.net/")
+        );
+        assert_eq!(parsed.blob_secondary_endpoint, None);
+
+        // An Azure China account.
+        storage_account.properties.primary_endpoints = Some(Endpoints {
+            blob: Some("https://myaccount.blob.core.chinacloudapi.cn/".to_owned()),
+            queue: None,
+            table: Some("https://myaccount.table.core.chinacloudapi.cn/".to_owned()),
+            file: None,
+            dfs: None,
+            web: None,
+        });
+        storage_account.properties.secondary_endpoints = Some(Endpoints {
+            blob: Some("https://myaccount-secondary.blob.core.chinacloudapi.cn/".to_owned()),
+            queue: None,
+            table: None,
+            file: None,
+            dfs: None,
+            web: None,
+        });
+        let connection_string = key.connection_string(&storage_account);
+        let parsed = ConnectionString::new(&connection_string).unwrap();
+        assert_eq!(
+            parsed.blob_endpoint,
+            Some("https://myaccount.blob.core.chinacloudapi.cn/")
+        );
+        assert_eq!(
+            parsed.blob_secondary_endpoint,
+            Some("https://myaccount-secondary.blob.core.chinacloudapi.cn/")
+        );
+        assert_eq!(
+            parsed.table_endpoint,
+            Some("https://myaccount.table.core.chinacloudapi.cn/")
+        );
+        assert_eq!(parsed.queue_endpoint, None);
+    }
+}
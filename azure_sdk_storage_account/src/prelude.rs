@@ -1 +1,6 @@
+pub use crate::account::{
+    BlobServiceProperties, BlobServicePropertiesRequired, BlobServicePropertiesSupport, CorsRule,
+    GeoReplication, GeoReplicationStatus, Logging, Metrics, RetentionPolicy, StaticWebsite,
+};
+pub use crate::management::{ManagementClient, NetworkRuleSet};
 pub use crate::Account as AccountTrait;
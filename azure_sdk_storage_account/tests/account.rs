@@ -15,3 +15,71 @@ async fn get_account_information() {
     // values depend on the Azure storage account
     client.get_account_information().finalize().await.unwrap();
 }
+
+#[tokio::test]
+async fn blob_service_properties() {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let client = Client::new(&account, &master_key).unwrap();
+
+    let original = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap()
+        .blob_service_properties;
+
+    // only the delete retention policy is changed, the other properties
+    // are left as they are
+    let mut properties = BlobServiceProperties::new();
+    properties.delete_retention_policy = Some(RetentionPolicy::days(3));
+    client
+        .set_blob_service_properties()
+        .with_blob_service_properties(&properties)
+        .finalize()
+        .await
+        .unwrap();
+
+    let updated = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap()
+        .blob_service_properties;
+    assert_eq!(
+        updated.delete_retention_policy,
+        Some(RetentionPolicy::days(3))
+    );
+    assert_eq!(updated.cors, original.cors);
+
+    client
+        .set_blob_service_properties()
+        .with_blob_service_properties(&original)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn blob_service_stats() {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    // the account must be read-access geo-redundant (RA-GRS or RA-GZRS)
+    let client = Client::new(&account, &master_key)
+        .unwrap()
+        .with_location_mode(LocationMode::PrimaryThenSecondary);
+
+    let stats = client.get_blob_service_stats().finalize().await.unwrap();
+    if stats.geo_replication.status == GeoReplicationStatus::Live {
+        assert!(stats.geo_replication.last_sync_time.is_some());
+    }
+
+    // reads are served by either location
+    client.get_account_information().finalize().await.unwrap();
+}
@@ -0,0 +1,15 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_storage_account::prelude::*;
+
+#[tokio::test]
+async fn list_storage_accounts() {
+    let subscription_id =
+        std::env::var("SUBSCRIPTION_ID").expect("Set env variable SUBSCRIPTION_ID first!");
+    let access_token = std::env::var("ACCESS_TOKEN").expect("Set env variable ACCESS_TOKEN first!");
+
+    let client = ManagementClient::new(&subscription_id, &access_token);
+
+    // we just test the call works, we don't check the return value since the
+    // values depend on the Azure subscription
+    client.list_storage_accounts().finalize().await.unwrap();
+}
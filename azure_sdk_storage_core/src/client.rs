@@ -1,5 +1,6 @@
-use crate::rest_client::{perform_request, ServiceType};
-use crate::{ClientEndpoint, ConnectionString, HyperClientEndpoint};
+use crate::location_mode::{is_read, send_with_failover, to_secondary_uri, with_uri};
+use crate::rest_client::{build_request, ServiceType};
+use crate::{ClientEndpoint, ConnectionString, HyperClientEndpoint, LocationMode, ResponseFuture};
 use azure_sdk_core::errors::AzureError;
 use hyper::{self, Method};
 use hyper_rustls::HttpsConnector;
@@ -13,6 +14,9 @@ pub struct Client {
     hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
     blob_uri: String,
     table_uri: String,
+    blob_secondary_uri: Option<String>,
+    table_secondary_uri: Option<String>,
+    location_mode: LocationMode,
 }
 
 impl Client {
@@ -31,6 +35,15 @@ impl Client {
             hc: client,
             blob_uri: format!("https://{}.blob.core.windows.net", account),
             table_uri: format!("https://{}.table.core.windows.net", account),
+            blob_secondary_uri: Some(format!(
+                "https://{}-secondary.blob.core.windows.net",
+                account
+            )),
+            table_secondary_uri: Some(format!(
+                "https://{}-secondary.table.core.windows.net",
+                account
+            )),
+            location_mode: LocationMode::PrimaryOnly,
         })
     }
 
@@ -44,13 +57,26 @@ impl Client {
             hc: client,
             blob_uri: format!("https://{}.blob.core.windows.net", account),
             table_uri: format!("https://{}.table.core.windows.net", account),
+            blob_secondary_uri: Some(format!(
+                "https://{}-secondary.blob.core.windows.net",
+                account
+            )),
+            table_secondary_uri: Some(format!(
+                "https://{}-secondary.table.core.windows.net",
+                account
+            )),
+            location_mode: LocationMode::PrimaryOnly,
         })
     }
 
+    /// Uses the `BlobEndpoint`, `TableEndpoint` and their secondary
+    /// counterparts of the connection string when present, the default
+    /// endpoints of the account otherwise.
     pub fn from_connection_string(connection_string: &str) -> Result<Self, AzureError> {
         let client = hyper::Client::builder().build(HttpsConnector::new());
+        let connection_string = ConnectionString::new(connection_string)?;
 
-        match ConnectionString::new(connection_string)? {
+        let (account, key, sas_token) = match connection_string {
             ConnectionString {
                 account_name: Some(account),
                 account_key: Some(_),
@@ -58,46 +84,61 @@ impl Client {
                 ..
             } => {
                 log::warn!("Both account key and SAS defined in connection string. Using only the provided SAS.");
-                Ok(Client {
-                    account: account.to_owned(),
-                    key: String::new(),
-                    sas_token: Some(Client::get_sas_token_parms(sas_token)),
-                    hc: client,
-                    blob_uri: format!("https://{}.blob.core.windows.net", account),
-                    table_uri: format!("https://{}.table.core.windows.net", account), 
-                })
+                (account, String::new(), Some(Client::get_sas_token_parms(sas_token)))
             }
             ConnectionString {
                 account_name: Some(account),
                 sas: Some(sas_token),
                 ..
-            } => Ok(Client {
-                account: account.to_owned(),
-                key: String::new(),
-                sas_token: Some(Client::get_sas_token_parms(sas_token)),
-                hc: client,
-                blob_uri: format!("https://{}.blob.core.windows.net", account),
-                table_uri: format!("https://{}.table.core.windows.net", account), 
-            }),
+            } => (account, String::new(), Some(Client::get_sas_token_parms(sas_token))),
             ConnectionString {
                 account_name: Some(account),
                 account_key: Some(key),
                 ..
-            } => Ok(Client {
-                account: account.to_owned(),
-                key: key.to_owned(),
-                sas_token: None,
-                hc: client,
-                blob_uri: format!("https://{}.blob.core.windows.net", account),
-                table_uri: format!("https://{}.table.core.windows.net", account), 
-            }),
+            } => (account, key.to_owned(), None),
             _ => {
                 return Err(AzureError::GenericErrorWithText(
                     "Could not create a storage client from the provided connection string. Please validate that you have specified the account name and means of authentication (key, SAS, etc.)."
                         .to_owned(),
                 ))
             }
-        }
+        };
+
+        // The secondary endpoints can only be guessed for the default
+        // endpoints.
+        let endpoint = |custom: Option<&str>, service: &str| match custom {
+            Some(custom) => custom.trim_end_matches('/').to_owned(),
+            None => format!("https://{}.{}.core.windows.net", account, service),
+        };
+        let secondary_endpoint =
+            |primary: Option<&str>, custom: Option<&str>, service: &str| match (primary, custom) {
+                (_, Some(custom)) => Some(custom.trim_end_matches('/').to_owned()),
+                (None, None) => Some(format!(
+                    "https://{}-secondary.{}.core.windows.net",
+                    account, service
+                )),
+                (Some(_), None) => None,
+            };
+
+        Ok(Client {
+            account: account.to_owned(),
+            key,
+            sas_token,
+            hc: client,
+            blob_uri: endpoint(connection_string.blob_endpoint, "blob"),
+            table_uri: endpoint(connection_string.table_endpoint, "table"),
+            blob_secondary_uri: secondary_endpoint(
+                connection_string.blob_endpoint,
+                connection_string.blob_secondary_endpoint,
+                "blob",
+            ),
+            table_secondary_uri: secondary_endpoint(
+                connection_string.table_endpoint,
+                connection_string.table_secondary_endpoint,
+                "table",
+            ),
+            location_mode: LocationMode::PrimaryOnly,
+        })
     }
 
     pub fn emulator(blob_storage_url: &Url, table_storage_url: &Url) -> Result<Client, AzureError> {
@@ -107,6 +148,8 @@ impl Client {
         debug!("blob_uri == {}", blob_uri);
         let table_uri = format!("{}devstoreaccount1", table_storage_url.as_str());
         debug!("table_uri == {}", table_uri);
+        let blob_secondary_uri = format!("{}-secondary", blob_uri);
+        let table_secondary_uri = format!("{}-secondary", table_uri);
 
         Ok(Client {
             account: "devstoreaccount1".to_owned(),
@@ -115,9 +158,20 @@ impl Client {
             hc: client,
             blob_uri,
             table_uri,
+            blob_secondary_uri: Some(blob_secondary_uri),
+            table_secondary_uri: Some(table_secondary_uri),
+            location_mode: LocationMode::PrimaryOnly,
         })
     }
 
+    /// `LocationMode::PrimaryOnly` by default.
+    pub fn with_location_mode(self, location_mode: LocationMode) -> Client {
+        Client {
+            location_mode,
+            ..self
+        }
+    }
+
     #[inline]
     pub fn blob_uri(&self) -> &str {
         &self.blob_uri
@@ -128,6 +182,21 @@ impl Client {
         &self.table_uri
     }
 
+    #[inline]
+    pub fn blob_secondary_uri(&self) -> Option<&str> {
+        self.blob_secondary_uri.as_deref()
+    }
+
+    #[inline]
+    pub fn table_secondary_uri(&self) -> Option<&str> {
+        self.table_secondary_uri.as_deref()
+    }
+
+    #[inline]
+    pub fn location_mode(&self) -> LocationMode {
+        self.location_mode
+    }
+
     fn add_sas_token_to_uri(&self, uri: &str) -> String {
         match &self.sas_token {
             Some(token) => Url::parse_with_params(uri, token).unwrap().to_string(),
@@ -153,20 +222,13 @@ impl Client {
         method: &Method,
         headers_func: F,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>
+    ) -> Result<ResponseFuture, AzureError>
     where
         F: FnOnce(::http::request::Builder) -> ::http::request::Builder,
     {
         let uri = self.add_sas_token_to_uri(uri);
 
-        perform_request(
-            self,
-            &uri,
-            method,
-            headers_func,
-            request_body,
-            ServiceType::Blob,
-        )
+        self.send(&uri, method, headers_func, request_body, ServiceType::Blob)
     }
 
     pub fn perform_table_request<F>(
@@ -175,7 +237,7 @@ impl Client {
         method: &Method,
         headers_func: F,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>
+    ) -> Result<ResponseFuture, AzureError>
     where
         F: FnOnce(::http::request::Builder) -> ::http::request::Builder,
     {
@@ -184,14 +246,52 @@ impl Client {
         let uri =
             self.add_sas_token_to_uri((self.get_uri_prefix(ServiceType::Table) + segment).as_str());
 
-        perform_request(
-            self,
-            &uri,
-            method,
-            headers_func,
-            request_str,
-            ServiceType::Table,
-        )
+        self.send(&uri, method, headers_func, request_str, ServiceType::Table)
+    }
+
+    /// Sends the reads where the location mode says, the writes to the
+    /// primary location.
+    fn send<F>(
+        &self,
+        uri: &str,
+        method: &Method,
+        headers_func: F,
+        request_body: Option<&[u8]>,
+        service_type: ServiceType,
+    ) -> Result<ResponseFuture, AzureError>
+    where
+        F: FnOnce(::http::request::Builder) -> ::http::request::Builder,
+    {
+        let (primary_uri, secondary_uri) = match service_type {
+            ServiceType::Blob => (&self.blob_uri, &self.blob_secondary_uri),
+            ServiceType::Table => (&self.table_uri, &self.table_secondary_uri),
+        };
+        let secondary_uri = match (self.location_mode, secondary_uri) {
+            (LocationMode::PrimaryOnly, _) => None,
+            (_, _) if !is_read(method) => None,
+            (_, Some(secondary_uri)) => to_secondary_uri(uri, primary_uri, secondary_uri),
+            (LocationMode::SecondaryOnly, None) => {
+                return Err(AzureError::GenericErrorWithText(
+                    "The secondary location is unknown: specify it in the connection string to read from it."
+                        .to_owned(),
+                ))
+            }
+            (LocationMode::PrimaryThenSecondary, None) => None,
+        };
+
+        let request = build_request(self, uri, method, headers_func, request_body, service_type)?;
+
+        match (self.location_mode, secondary_uri) {
+            (LocationMode::SecondaryOnly, Some(secondary_uri)) => {
+                let secondary = with_uri(&request, &secondary_uri, request_body)?;
+                Ok(Box::pin(self.hc.request(secondary)))
+            }
+            (LocationMode::PrimaryThenSecondary, Some(secondary_uri)) => {
+                let secondary = with_uri(&request, &secondary_uri, request_body)?;
+                Ok(send_with_failover(self.hc.clone(), request, secondary))
+            }
+            _ => Ok(Box::pin(self.hc.request(request))),
+        }
     }
 
     /// Uri scheme + authority e.g. http://myaccount.table.core.windows.net/
@@ -218,3 +318,50 @@ impl HyperClientEndpoint for Client {
         &self.hc
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn default_secondary_endpoints() {
+        let client = Client::from_connection_string(
+            "AccountName=myaccount;AccountKey=c2VjcmV0;EndpointSuffix=core.windows.net",
+        )
+        .unwrap();
+        assert_eq!(client.blob_uri(), "https://myaccount.blob.core.windows.net");
+        assert_eq!(
+            client.blob_secondary_uri(),
+            Some("https://myaccount-secondary.blob.core.windows.net")
+        );
+        assert_eq!(
+            client.table_secondary_uri(),
+            Some("https://myaccount-secondary.table.core.windows.net")
+        );
+        assert_eq!(client.location_mode(), LocationMode::PrimaryOnly);
+    }
+
+    #[test]
+    fn custom_endpoints() {
+        let client = Client::from_connection_string(
+            "AccountName=myaccount;AccountKey=c2VjcmV0;\
+             BlobEndpoint=https://blob.contoso.com/;\
+             BlobSecondaryEndpoint=https://blob-secondary.contoso.com/;\
+             TableEndpoint=https://table.contoso.com/",
+        )
+        .unwrap()
+        .with_location_mode(LocationMode::SecondaryOnly);
+        assert_eq!(client.blob_uri(), "https://blob.contoso.com");
+        assert_eq!(
+            client.blob_secondary_uri(),
+            Some("https://blob-secondary.contoso.com")
+        );
+        assert_eq!(client.table_uri(), "https://table.contoso.com");
+        assert_eq!(client.table_secondary_uri(), None);
+
+        // the secondary table location is unknown
+        assert!(client
+            .perform_table_request("Tables", &Method::GET, |request| request, None)
+            .is_err());
+    }
+}
@@ -32,171 +32,137 @@ impl<'a> ConnectionStringBuilder<'a> {
             ));
         }
         if let Some(endpoint_suffix) = self.0.endpoint_suffix {
-            kv_pairs.push(format!(
-                "{}={}",
-                ENDPOINT_SUFFIX_KEY_NAME,
-                endpoint_suffix
-            ));
+            kv_pairs.push(format!("{}={}", ENDPOINT_SUFFIX_KEY_NAME, endpoint_suffix));
         }
         if let Some(default_endpoints_protocol) = self.0.default_endpoints_protocol.as_ref() {
             kv_pairs.push(format!(
                 "{}={}",
-                DEFAULT_ENDPOINTS_PROTOCOL_KEY_NAME,
-                default_endpoints_protocol
+                DEFAULT_ENDPOINTS_PROTOCOL_KEY_NAME, default_endpoints_protocol
             ));
         }
         if let Some(blob_endpoint) = self.0.blob_endpoint {
-            kv_pairs.push(format!(
-                "{}={}",
-                BLOB_ENDPOINT_KEY_NAME,
-                blob_endpoint
-            ));
+            kv_pairs.push(format!("{}={}", BLOB_ENDPOINT_KEY_NAME, blob_endpoint));
         }
         if let Some(blob_secondary_endpoint) = self.0.blob_secondary_endpoint {
             kv_pairs.push(format!(
                 "{}={}",
-                BLOB_SECONDARY_ENDPOINT_KEY_NAME,
-                blob_secondary_endpoint
+                BLOB_SECONDARY_ENDPOINT_KEY_NAME, blob_secondary_endpoint
             ));
         }
         if let Some(table_endpoint) = self.0.table_endpoint {
-            kv_pairs.push(format!(
-                "{}={}",
-                TABLE_ENDPOINT_KEY_NAME,
-                table_endpoint
-            ));
+            kv_pairs.push(format!("{}={}", TABLE_ENDPOINT_KEY_NAME, table_endpoint));
         }
         if let Some(table_secondary_endpoint) = self.0.table_secondary_endpoint {
             kv_pairs.push(format!(
                 "{}={}",
-                TABLE_SECONDARY_ENDPOINT_KEY_NAME,
-                table_secondary_endpoint
+                TABLE_SECONDARY_ENDPOINT_KEY_NAME, table_secondary_endpoint
             ));
         }
         if let Some(queue_endpoint) = self.0.queue_endpoint {
-            kv_pairs.push(format!(
-                "{}={}",
-                QUEUE_ENDPOINT_KEY_NAME,
-                queue_endpoint
-            ));
+            kv_pairs.push(format!("{}={}", QUEUE_ENDPOINT_KEY_NAME, queue_endpoint));
         }
         if let Some(queue_secondary_endpoint) = self.0.queue_secondary_endpoint {
             kv_pairs.push(format!(
                 "{}={}",
-                QUEUE_SECONDARY_ENDPOINT_KEY_NAME,
-                queue_secondary_endpoint
+                QUEUE_SECONDARY_ENDPOINT_KEY_NAME, queue_secondary_endpoint
             ));
         }
         if let Some(file_endpoint) = self.0.file_endpoint {
-            kv_pairs.push(format!(
-                "{}={}",
-                FILE_ENDPOINT_KEY_NAME,
-                file_endpoint
-            ));
+            kv_pairs.push(format!("{}={}", FILE_ENDPOINT_KEY_NAME, file_endpoint));
         }
         if let Some(file_secondary_endpoint) = self.0.file_secondary_endpoint {
             kv_pairs.push(format!(
                 "{}={}",
-                FILE_SECONDARY_ENDPOINT_KEY_NAME,
-                file_secondary_endpoint
+                FILE_SECONDARY_ENDPOINT_KEY_NAME, file_secondary_endpoint
             ));
         }
 
         kv_pairs.join(";")
     }
 
-    pub fn with_account_name(&'a mut self, account_name: &'a str) -> &'a mut Self {
+    pub fn with_account_name(&mut self, account_name: &'a str) -> &mut Self {
         self.0.account_name = Some(account_name);
         self
     }
 
-    pub fn with_account_key(&'a mut self, account_key: &'a str) -> &'a mut Self {
+    pub fn with_account_key(&mut self, account_key: &'a str) -> &mut Self {
         self.0.account_key = Some(account_key);
         self
     }
 
-    pub fn with_sas(&'a mut self, sas: &'a str) -> &'a mut Self {
+    pub fn with_sas(&mut self, sas: &'a str) -> &mut Self {
         self.0.sas = Some(sas);
         self
     }
 
-    pub fn with_endpoint_suffix(&'a mut self, endpoint_suffix: &'a str) -> &'a mut Self {
+    pub fn with_endpoint_suffix(&mut self, endpoint_suffix: &'a str) -> &mut Self {
         self.0.endpoint_suffix = Some(endpoint_suffix);
         self
     }
 
     pub fn with_default_endpoints_protocol(
-        &'a mut self,
+        &mut self,
         default_endpoints_protocol: EndpointProtocol,
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.0.default_endpoints_protocol = Some(default_endpoints_protocol);
         self
     }
 
-    pub fn with_use_development_storage(
-        &'a mut self,
-        use_development_storage: bool,
-    ) -> &'a mut Self {
+    pub fn with_use_development_storage(&mut self, use_development_storage: bool) -> &mut Self {
         self.0.use_development_storage = Some(use_development_storage);
         self
     }
 
     pub fn with_development_storage_proxy_uri(
-        &'a mut self,
+        &mut self,
         development_storage_proxy_uri: &'a str,
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.0.development_storage_proxy_uri = Some(development_storage_proxy_uri);
         self
     }
 
-    pub fn with_blob_endpoint(&'a mut self, blob_endpoint: &'a str) -> &'a mut Self {
+    pub fn with_blob_endpoint(&mut self, blob_endpoint: &'a str) -> &mut Self {
         self.0.blob_endpoint = Some(blob_endpoint);
         self
     }
 
-    pub fn with_blob_secondary_endpoint(
-        &'a mut self,
-        blob_secondary_endpoint: &'a str,
-    ) -> &'a mut Self {
+    pub fn with_blob_secondary_endpoint(&mut self, blob_secondary_endpoint: &'a str) -> &mut Self {
         self.0.blob_secondary_endpoint = Some(blob_secondary_endpoint);
         self
     }
 
-    pub fn with_table_endpoint(&'a mut self, table_endpoint: &'a str) -> &'a mut Self {
+    pub fn with_table_endpoint(&mut self, table_endpoint: &'a str) -> &mut Self {
         self.0.table_endpoint = Some(table_endpoint);
         self
     }
 
     pub fn with_table_secondary_endpoint(
-        &'a mut self,
+        &mut self,
         table_secondary_endpoint: &'a str,
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.0.table_secondary_endpoint = Some(table_secondary_endpoint);
         self
     }
 
-    pub fn with_queue_endpoint(&'a mut self, queue_endpoint: &'a str) -> &'a mut Self {
+    pub fn with_queue_endpoint(&mut self, queue_endpoint: &'a str) -> &mut Self {
         self.0.queue_endpoint = Some(queue_endpoint);
         self
     }
 
     pub fn with_queue_secondary_endpoint(
-        &'a mut self,
+        &mut self,
         queue_secondary_endpoint: &'a str,
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.0.queue_secondary_endpoint = Some(queue_secondary_endpoint);
         self
     }
 
-    pub fn with_file_endpoint(&'a mut self, file_endpoint: &'a str) -> &'a mut Self {
+    pub fn with_file_endpoint(&mut self, file_endpoint: &'a str) -> &mut Self {
         self.0.file_endpoint = Some(file_endpoint);
         self
     }
 
-    pub fn with_file_secondary_endpoint(
-        &'a mut self,
-        file_secondary_endpoint: &'a str,
-    ) -> &'a mut Self {
+    pub fn with_file_secondary_endpoint(&mut self, file_secondary_endpoint: &'a str) -> &mut Self {
         self.0.file_secondary_endpoint = Some(file_secondary_endpoint);
         self
     }
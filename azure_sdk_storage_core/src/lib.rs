@@ -29,6 +29,8 @@ mod hyper_client_endpoint;
 pub mod shared_access_signature;
 pub use client_endpoint::ClientEndpoint;
 pub use hyper_client_endpoint::HyperClientEndpoint;
+mod location_mode;
+pub use self::location_mode::{LocationMode, ResponseFuture};
 
 pub trait ClientRequired<'a> {
     fn client(&self) -> &'a Client;
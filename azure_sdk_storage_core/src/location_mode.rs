@@ -0,0 +1,280 @@
+use azure_sdk_core::errors::AzureError;
+use futures::Future;
+use hyper::{self, Body, Method, Request, Response, StatusCode};
+use hyper_rustls::HttpsConnector;
+use std::pin::Pin;
+
+/// The future of a storage request. Depending on the `LocationMode` of the
+/// `Client`, it may send the request twice.
+pub type ResponseFuture =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, hyper::Error>> + Send + 'static>>;
+
+/// Where the reads of a `Client` are sent. The secondary location is the
+/// read-only replica of an account with read-access geo-redundant storage
+/// (RA-GRS or RA-GZRS). Writes always go to the primary location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocationMode {
+    PrimaryOnly,
+    /// Reads go to the primary location and are sent again to the secondary
+    /// one if the primary cannot be reached or answers with a retriable
+    /// status code.
+    PrimaryThenSecondary,
+    SecondaryOnly,
+}
+
+/// Only reads can be served by the secondary location.
+pub(crate) fn is_read(method: &Method) -> bool {
+    *method == Method::GET || *method == Method::HEAD
+}
+
+/// Whether a failed read is worth sending to the other location.
+pub(crate) fn is_retriable(result: &Result<Response<Body>, hyper::Error>) -> bool {
+    match result {
+        Ok(response) => matches!(
+            response.status(),
+            StatusCode::REQUEST_TIMEOUT
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        ),
+        Err(error) => error.is_connect() || error.is_closed() || error.is_incomplete_message(),
+    }
+}
+
+/// `uri` pointed to `secondary_uri` instead of `primary_uri`, if it starts
+/// with it. The prefix must end on a path boundary: the emulator puts the
+/// account in the path, and `devstoreaccount1-secondary` must not be taken
+/// for `devstoreaccount1`.
+pub(crate) fn to_secondary_uri(
+    uri: &str,
+    primary_uri: &str,
+    secondary_uri: &str,
+) -> Option<String> {
+    uri.strip_prefix(primary_uri)
+        .filter(|path| path.is_empty() || path.starts_with('/') || path.starts_with('?'))
+        .map(|path| format!("{}{}", secondary_uri, path))
+}
+
+/// A copy of an already signed request, sent to another uri. The signature
+/// stays valid since it is computed with the primary account name even for
+/// the secondary location.
+pub(crate) fn with_uri(
+    request: &Request<Body>,
+    uri: &str,
+    request_body: Option<&[u8]>,
+) -> Result<Request<Body>, AzureError> {
+    let mut copy = Request::builder()
+        .method(request.method().clone())
+        .uri(uri)
+        .version(request.version())
+        .body(
+            request_body
+                .map(|b| Vec::from(b).into())
+                .unwrap_or_else(Body::empty),
+        )?;
+    *copy.headers_mut() = request.headers().clone();
+    Ok(copy)
+}
+
+pub(crate) fn send_with_failover(
+    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    primary: Request<Body>,
+    secondary: Request<Body>,
+) -> ResponseFuture {
+    Box::pin(async move {
+        let result = hc.request(primary).await;
+        if is_retriable(&result) {
+            match &result {
+                Ok(response) => warn!(
+                    "primary location answered {}, reading from {}",
+                    response.status(),
+                    secondary.uri()
+                ),
+                Err(error) => warn!(
+                    "primary location failed ({}), reading from {}",
+                    error,
+                    secondary.uri()
+                ),
+            }
+            hc.request(secondary).await
+        } else {
+            result
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Answers a single request with an empty body and the given status
+    // code, then returns the request line.
+    fn answer_once(status: u16) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {} X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                status
+            )
+            .unwrap();
+            request_line
+        });
+
+        (uri, handle)
+    }
+
+    #[test]
+    fn secondary_uri() {
+        assert_eq!(
+            to_secondary_uri(
+                "https://myaccount.blob.core.windows.net/mycontainer/myblob?comp=metadata",
+                "https://myaccount.blob.core.windows.net",
+                "https://myaccount-secondary.blob.core.windows.net"
+            ),
+            Some(
+                "https://myaccount-secondary.blob.core.windows.net/mycontainer/myblob?comp=metadata"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            to_secondary_uri(
+                "https://other.blob.core.windows.net/mycontainer",
+                "https://myaccount.blob.core.windows.net",
+                "https://myaccount-secondary.blob.core.windows.net"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn emulator_secondary_uri() {
+        let primary_uri = "http://127.0.0.1:10000/devstoreaccount1";
+        let secondary_uri = "http://127.0.0.1:10000/devstoreaccount1-secondary";
+
+        assert_eq!(
+            to_secondary_uri(
+                "http://127.0.0.1:10000/devstoreaccount1/mycontainer",
+                primary_uri,
+                secondary_uri
+            ),
+            Some("http://127.0.0.1:10000/devstoreaccount1-secondary/mycontainer".to_owned())
+        );
+        assert_eq!(
+            to_secondary_uri(
+                "http://127.0.0.1:10000/devstoreaccount1?comp=list",
+                primary_uri,
+                secondary_uri
+            ),
+            Some("http://127.0.0.1:10000/devstoreaccount1-secondary?comp=list".to_owned())
+        );
+        assert_eq!(
+            to_secondary_uri(
+                "http://127.0.0.1:10000/devstoreaccount1-secondary/mycontainer",
+                primary_uri,
+                secondary_uri
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn retriable_status_codes() {
+        let response = |status: StatusCode| {
+            Ok(Response::builder()
+                .status(status)
+                .body(Body::empty())
+                .unwrap())
+        };
+
+        assert!(is_retriable(&response(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(is_retriable(&response(StatusCode::INTERNAL_SERVER_ERROR)));
+        assert!(!is_retriable(&response(StatusCode::OK)));
+        assert!(!is_retriable(&response(StatusCode::NOT_FOUND)));
+        assert!(!is_retriable(&response(StatusCode::FORBIDDEN)));
+    }
+
+    #[test]
+    fn copied_request_keeps_signature() {
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri("https://myaccount.blob.core.windows.net/mycontainer")
+            .header(hyper::header::AUTHORIZATION, "SharedKey myaccount:c2ln")
+            .body(Body::empty())
+            .unwrap();
+
+        let copy = with_uri(
+            &request,
+            "https://myaccount-secondary.blob.core.windows.net/mycontainer",
+            None,
+        )
+        .unwrap();
+        assert_eq!(copy.method(), Method::GET);
+        assert_eq!(
+            copy.uri(),
+            "https://myaccount-secondary.blob.core.windows.net/mycontainer"
+        );
+        assert_eq!(
+            copy.headers()[hyper::header::AUTHORIZATION],
+            "SharedKey myaccount:c2ln"
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_from_secondary_when_primary_is_unavailable() {
+        let (primary_uri, primary) = answer_once(503);
+        let (secondary_uri, secondary) = answer_once(200);
+
+        let request = Request::builder()
+            .method(Method::GET)
+            .uri(format!("{}/mycontainer?restype=container", primary_uri))
+            .header(hyper::header::AUTHORIZATION, "SharedKey myaccount:c2ln")
+            .body(Body::empty())
+            .unwrap();
+        let secondary_request = with_uri(
+            &request,
+            &to_secondary_uri(
+                request.uri().to_string().as_str(),
+                &primary_uri,
+                &secondary_uri,
+            )
+            .unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let hc = hyper::Client::builder().build(HttpsConnector::new());
+        let response = send_with_failover(hc, request, secondary_request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            primary.join().unwrap(),
+            "GET /mycontainer?restype=container HTTP/1.1\r\n"
+        );
+        assert_eq!(
+            secondary.join().unwrap(),
+            "GET /mycontainer?restype=container HTTP/1.1\r\n"
+        );
+    }
+}
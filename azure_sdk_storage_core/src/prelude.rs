@@ -2,6 +2,7 @@ pub use crate::blob_sas_builder::BlobSASBuilder;
 pub use crate::client::Client;
 pub use crate::container_sas_builder::ContainerSASBuilder;
 pub use crate::ClientRequired;
+pub use crate::LocationMode;
 pub use crate::{CopyId, IPRange};
 
 pub use crate::SharedAccessSignatureSupport;
@@ -502,6 +502,30 @@ pub fn perform_request<F, HCE: HyperClientEndpoint>(
     request_body: Option<&[u8]>,
     service_type: ServiceType,
 ) -> Result<hyper::client::ResponseFuture, AzureError>
+where
+    F: FnOnce(::http::request::Builder) -> ::http::request::Builder,
+{
+    let request = build_request(
+        hyper_client_endpoint,
+        uri,
+        http_method,
+        headers_func,
+        request_body,
+        service_type,
+    )?;
+
+    Ok(hyper_client_endpoint.hyper_client().request(request))
+}
+
+/// The signed request `perform_request` sends.
+pub(crate) fn build_request<F, CE: ClientEndpoint>(
+    client_endpoint: &CE,
+    uri: &str,
+    http_method: &Method,
+    headers_func: F,
+    request_body: Option<&[u8]>,
+    service_type: ServiceType,
+) -> Result<hyper::Request<hyper::Body>, AzureError>
 where
     F: FnOnce(::http::request::Builder) -> ::http::request::Builder,
 {
@@ -543,7 +567,7 @@ where
     // SAS token for example)
     if url.query_pairs().find(|p| p.0 == "sig").is_none() {
         let auth = generate_authorization(
-            hyper_client_endpoint,
+            client_endpoint,
             request.headers(),
             &url,
             http_method,
@@ -554,7 +578,7 @@ where
             .insert(header::AUTHORIZATION, format_header_value(auth)?);
     }
 
-    Ok(hyper_client_endpoint.hyper_client().request(request))
+    Ok(request)
 }
 
 #[inline]
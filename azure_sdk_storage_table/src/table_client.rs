@@ -2,12 +2,9 @@ use azure_sdk_core::errors::{check_status_extract_body, AzureError};
 use azure_sdk_storage_core::client::Client;
 use azure_sdk_storage_core::{
     get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata, ConnectionString,
-    ServiceType,
-};
-use hyper::{
-    client::ResponseFuture,
-    header::{self, HeaderValue},
+    ResponseFuture, ServiceType,
 };
+use hyper::header::{self, HeaderValue};
 use hyper::{Method, StatusCode};
 use log;
 use serde_json;
@@ -11,4 +11,7 @@ for i in azure_sdk* ; do
 	cd ..
 done
 
+# the optional features must build together too
+cargo build --workspace --all-features --all-targets
+
 